@@ -2,11 +2,53 @@ use criterion::{Criterion, criterion_group, criterion_main};
 use obsidian_parser::prelude::*;
 use rand::RngExt;
 use serde::{Deserialize, Serialize};
+use std::alloc::{GlobalAlloc, Layout, System};
 use std::hint::black_box;
 use std::io::Write;
 use std::path::Path;
+use std::sync::atomic::{AtomicUsize, Ordering};
 use tempfile::TempDir;
 
+/// System allocator, which tracks peak of allocated bytes (see [`measure_peak_memory`])
+struct PeakAllocator;
+
+static ALLOCATED: AtomicUsize = AtomicUsize::new(0);
+static PEAK: AtomicUsize = AtomicUsize::new(0);
+
+unsafe impl GlobalAlloc for PeakAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        let ptr = unsafe { System.alloc(layout) };
+
+        if !ptr.is_null() {
+            let allocated = ALLOCATED.fetch_add(layout.size(), Ordering::Relaxed) + layout.size();
+            PEAK.fetch_max(allocated, Ordering::Relaxed);
+        }
+
+        ptr
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        unsafe { System.dealloc(ptr, layout) };
+        ALLOCATED.fetch_sub(layout.size(), Ordering::Relaxed);
+    }
+}
+
+#[global_allocator]
+static GLOBAL: PeakAllocator = PeakAllocator;
+
+/// Peak of heap memory (in bytes) allocated by `f` above memory allocated before call
+#[cfg(feature = "rayon")]
+fn measure_peak_memory<T>(f: impl FnOnce() -> T) -> usize {
+    let before = ALLOCATED.load(Ordering::Relaxed);
+    PEAK.store(before, Ordering::Relaxed);
+
+    let result = f();
+    let peak = PEAK.load(Ordering::Relaxed);
+    drop(result);
+
+    peak.saturating_sub(before)
+}
+
 #[derive(Default, Debug, Clone, Deserialize, Serialize)]
 struct NoteProperties {
     id: usize,
@@ -186,6 +228,61 @@ fn vault_open_benchmark(c: &mut Criterion) {
     }
 }
 
+#[cfg(feature = "rayon")]
+fn huge_vault_open_benchmark(c: &mut Criterion) {
+    use rayon::prelude::*;
+
+    // Paths are streamed into rayon, so opening doesn't hold all 50k paths at once
+    let num_files = 50_000;
+    let links_per_file = 1;
+
+    let temp_dir = generate_test_vault(num_files, links_per_file);
+    let path = temp_dir.path();
+
+    // Criterion measures only time, so peak memory is compared once here
+    let options = VaultOptions::new(path);
+    let streamed = measure_peak_memory(|| {
+        let vault: VaultOnDisk<NoteProperties> = VaultBuilder::new(&options)
+            .include_hidden(true)
+            .into_par_iter()
+            .map(|note| note.unwrap())
+            .build_vault(&options);
+
+        vault
+    });
+    let collected = measure_peak_memory(|| {
+        let paths: Vec<_> = std::fs::read_dir(path)
+            .unwrap()
+            .map(|entry| entry.unwrap().path())
+            .collect();
+
+        let vault: VaultOnDisk<NoteProperties> = paths
+            .into_par_iter()
+            .map(|path| NoteOnDisk::<NoteProperties>::from_file(path).unwrap())
+            .build_vault(&options);
+
+        vault
+    });
+    println!(
+        "Peak memory of parallel vault_open 50k files: {} KiB streamed, {} KiB with collected paths",
+        streamed / 1024,
+        collected / 1024
+    );
+
+    c.bench_function("Parallel vault_open 50k files (VaultOnDisk)", |b| {
+        b.iter(|| {
+            let options = VaultOptions::new(black_box(path));
+            let vault: VaultOnDisk<NoteProperties> = VaultBuilder::new(&options)
+                .include_hidden(true)
+                .into_par_iter()
+                .map(|note| note.unwrap())
+                .build_vault(&options);
+
+            black_box(vault);
+        })
+    });
+}
+
 #[cfg(feature = "petgraph")]
 fn graph_build_benchmark(c: &mut Criterion) {
     let num_files = 1000;
@@ -293,6 +390,68 @@ fn get_duplicates_by_name_benchmark(c: &mut Criterion) {
     });
 }
 
+fn read_content_benchmark(c: &mut Criterion) {
+    let num_files = 100;
+    let links_per_file = 100;
+
+    let temp_dir = generate_test_vault(num_files, links_per_file);
+    let vault_on_disk: VaultOnDisk<NoteProperties> = create_vault(temp_dir.path());
+
+    c.bench_function("content (VaultOnDisk)", |b| {
+        b.iter(|| {
+            for note in &vault_on_disk {
+                black_box(note.content().unwrap());
+            }
+        })
+    });
+
+    c.bench_function("read_content_into (VaultOnDisk)", |b| {
+        let mut buf = String::new();
+
+        b.iter(|| {
+            for note in &vault_on_disk {
+                let range = note.read_content_into(&mut buf).unwrap();
+                black_box(&buf[range]);
+            }
+        })
+    });
+}
+
+fn link_analysis_benchmark(c: &mut Criterion) {
+    let num_files = 10_000;
+    let links_per_file = 2;
+
+    let temp_dir = generate_test_vault(num_files, links_per_file);
+    let vault_on_disk: VaultOnDisk<NoteProperties> = create_vault(temp_dir.path());
+
+    c.bench_function("broken_links (VaultOnDisk)", |b| {
+        b.iter(|| {
+            black_box(vault_on_disk.broken_links().unwrap());
+        })
+    });
+
+    c.bench_function("backlinks (VaultOnDisk)", |b| {
+        b.iter(|| {
+            black_box(vault_on_disk.backlinks("note_0").unwrap());
+        })
+    });
+
+    #[cfg(feature = "rayon")]
+    {
+        c.bench_function("Parallel broken_links (VaultOnDisk)", |b| {
+            b.iter(|| {
+                black_box(vault_on_disk.par_broken_links().unwrap());
+            })
+        });
+
+        c.bench_function("Parallel backlinks (VaultOnDisk)", |b| {
+            b.iter(|| {
+                black_box(vault_on_disk.par_backlinks("note_0").unwrap());
+            })
+        });
+    }
+}
+
 #[cfg(feature = "digest")]
 fn get_duplicates_by_content_benchmark(c: &mut Criterion) {
     use sha2::Sha256;
@@ -366,7 +525,7 @@ criterion_group! {
     config = Criterion::default()
         .sample_size(20)
         .warm_up_time(std::time::Duration::from_secs(1));
-    targets = vault_open_benchmark, graph_build_benchmark, get_duplicates_by_name_benchmark, get_duplicates_by_content_benchmark
+    targets = vault_open_benchmark, huge_vault_open_benchmark, graph_build_benchmark, get_duplicates_by_name_benchmark, read_content_benchmark, link_analysis_benchmark, get_duplicates_by_content_benchmark
 }
 
 criterion_main!(benches);
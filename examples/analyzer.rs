@@ -34,13 +34,8 @@ fn main() {
     let options = VaultOptions::new(&args.path);
     let files = VaultBuilder::new(&options)
         .include_hidden(false)
-        .into_par_iter::<NoteOnceLock>()
-        .filter_map(|note| match note {
-            Ok(note) => Some(note),
-            Err(error) => {
-                eprintln!("Parsed error: {}", error);
-                None
-            }
+        .par_on_error::<NoteOnceLock>(|path, error| {
+            eprintln!("Parsed error in {}: {}", path.display(), error);
         })
         .filter(|note| {
             let content = note.content().unwrap();
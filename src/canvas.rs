@@ -0,0 +1,196 @@
+//! Obsidian Canvas (`.canvas`) files
+//!
+//! Canvas is JSON with `nodes` and `edges` (see [JSON Canvas](https://jsoncanvas.org/spec/1.0/)).
+//! Open canvases with notes by [`VaultBuilder::extensions`] and get them
+//! by [`Vault::canvases`](crate::vault::Vault::canvases)
+//!
+//! [`VaultBuilder::extensions`]: crate::vault::vault_open::VaultBuilder::extensions
+
+use serde::{Deserialize, Serialize};
+use std::str::FromStr;
+
+/// Parsed canvas file
+///
+/// # Example
+/// ```
+/// use obsidian_parser::canvas::{Canvas, CanvasNodeKind};
+///
+/// let canvas: Canvas = r#"{
+///     "nodes": [
+///         {"id": "1", "type": "file", "file": "dir/note.md", "x": 0, "y": 0, "width": 400, "height": 400},
+///         {"id": "2", "type": "text", "text": "Idea", "x": 500, "y": 0, "width": 250, "height": 60}
+///     ],
+///     "edges": [{"id": "3", "fromNode": "2", "toNode": "1"}]
+/// }"#
+/// .parse()
+/// .unwrap();
+///
+/// assert_eq!(canvas.files().collect::<Vec<_>>(), ["dir/note.md"]);
+/// assert_eq!(canvas.edges[0].from_node, "2");
+/// ```
+#[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Canvas {
+    /// Cards of canvas
+    #[serde(default)]
+    pub nodes: Vec<CanvasNode>,
+
+    /// Arrows between [`Canvas::nodes`]
+    #[serde(default)]
+    pub edges: Vec<CanvasEdge>,
+}
+
+/// Card of [`Canvas`]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct CanvasNode {
+    /// Unique id of node
+    pub id: String,
+
+    /// Type of node with its data
+    #[serde(flatten)]
+    pub kind: CanvasNodeKind,
+
+    /// Position by X
+    pub x: f64,
+
+    /// Position by Y
+    pub y: f64,
+
+    /// Width of node
+    pub width: f64,
+
+    /// Height of node
+    pub height: f64,
+
+    /// Color: number of preset (like `"1"`) or hex (like `"#FF0000"`)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub color: Option<String>,
+}
+
+/// Type of [`CanvasNode`]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "lowercase")]
+pub enum CanvasNodeKind {
+    /// Markdown text
+    Text {
+        /// Text of card
+        text: String,
+    },
+
+    /// Note or other file of vault
+    File {
+        /// Path to file relative to vault (like `dir/note.md`)
+        file: String,
+
+        /// Heading or block in file (like `#Heading`)
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        subpath: Option<String>,
+    },
+
+    /// Web page
+    Link {
+        /// URL of page
+        url: String,
+    },
+
+    /// Group of other nodes
+    Group {
+        /// Title of group
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        label: Option<String>,
+    },
+}
+
+/// Arrow between two [`CanvasNode`]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CanvasEdge {
+    /// Unique id of edge
+    pub id: String,
+
+    /// [`CanvasNode::id`] of start node
+    pub from_node: String,
+
+    /// [`CanvasNode::id`] of end node
+    pub to_node: String,
+
+    /// Side of start node (`top`, `right`, `bottom` or `left`)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub from_side: Option<String>,
+
+    /// Side of end node (`top`, `right`, `bottom` or `left`)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub to_side: Option<String>,
+
+    /// Color like in [`CanvasNode::color`]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub color: Option<String>,
+
+    /// Text on edge
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub label: Option<String>,
+}
+
+impl Canvas {
+    /// Paths of files from nodes with type `file` (relative to vault, like `dir/note.md`)
+    pub fn files(&self) -> impl Iterator<Item = &str> {
+        self.nodes.iter().filter_map(|node| match &node.kind {
+            CanvasNodeKind::File { file, .. } => Some(file.as_str()),
+            _ => None,
+        })
+    }
+}
+
+impl FromStr for Canvas {
+    type Err = serde_json::Error;
+
+    /// Parse canvas from JSON
+    fn from_str(json: &str) -> Result<Self, Self::Err> {
+        serde_json::from_str(json)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[cfg_attr(feature = "tracing", tracing_test::traced_test)]
+    #[test]
+    fn parse_all_kinds() {
+        let canvas: Canvas = r##"{
+            "nodes": [
+                {"id": "a", "type": "text", "text": "Idea", "x": -10, "y": 0.5, "width": 250, "height": 60, "color": "1"},
+                {"id": "b", "type": "file", "file": "note.md", "subpath": "#Heading", "x": 0, "y": 0, "width": 400, "height": 400},
+                {"id": "c", "type": "link", "url": "https://obsidian.md", "x": 0, "y": 0, "width": 400, "height": 400},
+                {"id": "d", "type": "group", "x": 0, "y": 0, "width": 1000, "height": 1000, "background": "img.png"}
+            ],
+            "edges": [{"id": "e", "fromNode": "a", "fromSide": "right", "toNode": "b", "toSide": "left", "label": "see"}]
+        }"##
+        .parse()
+        .unwrap();
+
+        assert_eq!(canvas.nodes.len(), 4);
+        assert_eq!(canvas.nodes[0].color.as_deref(), Some("1"));
+        assert_eq!(
+            canvas.nodes[1].kind,
+            CanvasNodeKind::File {
+                file: "note.md".to_string(),
+                subpath: Some("#Heading".to_string())
+            }
+        );
+        assert_eq!(canvas.nodes[3].kind, CanvasNodeKind::Group { label: None });
+        assert_eq!(canvas.files().collect::<Vec<_>>(), ["note.md"]);
+
+        assert_eq!(canvas.edges[0].to_node, "b");
+        assert_eq!(canvas.edges[0].label.as_deref(), Some("see"));
+
+        let json = serde_json::to_string(&canvas).unwrap();
+        assert_eq!(json.parse::<Canvas>().unwrap(), canvas);
+    }
+
+    #[cfg_attr(feature = "tracing", tracing_test::traced_test)]
+    #[test]
+    fn parse_empty() {
+        assert_eq!("{}".parse::<Canvas>().unwrap(), Canvas::default());
+        assert!("[[note]]".parse::<Canvas>().is_err());
+    }
+}
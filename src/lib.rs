@@ -101,6 +101,11 @@
 //! - 💾 Peak memory: 900KB per 1000 notes
 //!
 //! Parallel processing via Rayon (enable `rayon` feature)
+//!
+//! ## Logging
+//! - `tracing` feature: spans and events via [`tracing`](https://docs.rs/tracing/latest/tracing)
+//! - `log` feature: the same events are also emitted as [`log`](https://docs.rs/log/latest/log)
+//!   records while no `tracing` subscriber is set, so `env_logger` and friends work out of the box
 
 #![forbid(unsafe_code)]
 #![warn(missing_docs)]
@@ -118,6 +123,10 @@
 #![allow(clippy::missing_errors_doc)]
 #![cfg_attr(docsrs, feature(doc_cfg))]
 
+#[cfg(feature = "canvas")]
+#[cfg_attr(docsrs, doc(cfg(feature = "canvas")))]
+pub mod canvas;
+
 pub mod note;
 pub mod prelude;
 pub mod vault;
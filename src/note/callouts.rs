@@ -0,0 +1,186 @@
+//! Obsidian callouts (`> [!note] Title`) of note
+
+use super::code_block::{lines_outside_code_blocks, strip_indent};
+
+/// Callout of note, like:
+///
+/// ```text
+/// > [!warning]- Title
+/// > Body
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Callout {
+    /// Kind of callout as written: `warning` for `[!warning]`
+    pub kind: String,
+
+    /// Title after `[!kind]`. [`None`] if title is empty
+    pub title: Option<String>,
+
+    /// Lines after first line without `>` prefix, joined with `\n`
+    pub body: String,
+
+    /// Fold marker:
+    /// * [`None`] - callout is not foldable
+    /// * `Some(true)` - `+`, foldable and expanded by default
+    /// * `Some(false)` - `-`, foldable and collapsed by default
+    pub foldable: Option<bool>,
+}
+
+/// Strip `>` and one optional space from blockquote line
+fn strip_quote(line: &str) -> Option<&str> {
+    let line = strip_indent(line)?.strip_prefix('>')?;
+    Some(line.strip_prefix(' ').unwrap_or(line))
+}
+
+/// Parse first line of callout like `> [!kind]+ Title`
+fn parse_callout_header(line: &str) -> Option<Callout> {
+    let line = strip_quote(line)?.trim_start().strip_prefix("[!")?;
+    let (kind, rest) = line.split_once(']')?;
+
+    if kind.is_empty() {
+        return None;
+    }
+
+    let (foldable, rest) = match rest.as_bytes().first() {
+        Some(b'+') => (Some(true), &rest[1..]),
+        Some(b'-') => (Some(false), &rest[1..]),
+        _ => (None, rest),
+    };
+
+    let title = rest.trim();
+    Some(Callout {
+        kind: kind.to_string(),
+        title: (!title.is_empty()).then(|| title.to_string()),
+        body: String::new(),
+        foldable,
+    })
+}
+
+/// Parse callouts from content of note
+///
+/// Callout starts on first line of blockquote and ends on first line without `>` prefix.
+/// Callouts inside fenced code blocks are ignored
+///
+/// # Nested callouts
+/// Nested callouts are **not** parsed separately: they are kept in [`Callout::body`]
+/// of outer callout as text (like `> [!tip] Inner`)
+///
+/// # Example
+/// ```
+/// use obsidian_parser::note::callouts::parse_callouts;
+///
+/// let content = "> [!note]- My title\n> First line\n> Second line\n\nText";
+/// let callouts = parse_callouts(content);
+///
+/// assert_eq!(callouts.len(), 1);
+/// assert_eq!(callouts[0].kind, "note");
+/// assert_eq!(callouts[0].title.as_deref(), Some("My title"));
+/// assert_eq!(callouts[0].body, "First line\nSecond line");
+/// assert_eq!(callouts[0].foldable, Some(false));
+/// ```
+#[must_use]
+pub fn parse_callouts(content: &str) -> Vec<Callout> {
+    let mut callouts = Vec::new();
+    let mut current: Option<(Callout, Vec<&str>)> = None;
+    let mut last_quote_index = None;
+
+    for (index, line) in lines_outside_code_blocks(content) {
+        let in_quote = last_quote_index.is_some_and(|last| last + 1 == index);
+        if strip_quote(line).is_some() {
+            last_quote_index = Some(index);
+        }
+
+        if in_quote
+            && let Some((_, body)) = current.as_mut()
+            && let Some(body_line) = strip_quote(line)
+        {
+            body.push(body_line);
+            continue;
+        }
+
+        if let Some((callout, body)) = current.take() {
+            callouts.push(Callout {
+                body: body.join("\n"),
+                ..callout
+            });
+        }
+
+        // Callout must be first line of blockquote
+        if !in_quote {
+            current = parse_callout_header(line).map(|callout| (callout, Vec::new()));
+        }
+    }
+
+    if let Some((callout, body)) = current {
+        callouts.push(Callout {
+            body: body.join("\n"),
+            ..callout
+        });
+    }
+
+    callouts
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn callout(kind: &str, title: Option<&str>, body: &str, foldable: Option<bool>) -> Callout {
+        Callout {
+            kind: kind.to_string(),
+            title: title.map(ToString::to_string),
+            body: body.to_string(),
+            foldable,
+        }
+    }
+
+    #[cfg_attr(feature = "tracing", tracing_test::traced_test)]
+    #[test]
+    fn parse() {
+        let content = "> [!note]\n> Body\n>\n> End\n\n>[!TIP]+ Title here\n> Tip\nText\n> [!info]";
+
+        assert_eq!(
+            parse_callouts(content),
+            [
+                callout("note", None, "Body\n\nEnd", None),
+                callout("TIP", Some("Title here"), "Tip", Some(true)),
+                callout("info", None, "", None),
+            ]
+        );
+    }
+
+    #[cfg_attr(feature = "tracing", tracing_test::traced_test)]
+    #[test]
+    fn not_callouts() {
+        let content = "> Quote\n> [!note] in quote\n[!note] Without quote\n> [!] Empty kind";
+
+        assert!(parse_callouts(content).is_empty());
+    }
+
+    #[cfg_attr(feature = "tracing", tracing_test::traced_test)]
+    #[test]
+    fn nested_callouts_flattened() {
+        let content = "> [!note] Outer\n> Text\n> > [!tip] Inner\n> > Inner text";
+
+        assert_eq!(
+            parse_callouts(content),
+            [callout(
+                "note",
+                Some("Outer"),
+                "Text\n> [!tip] Inner\n> Inner text",
+                None
+            )]
+        );
+    }
+
+    #[cfg_attr(feature = "tracing", tracing_test::traced_test)]
+    #[test]
+    fn ignore_code_blocks() {
+        let content = "```\n> [!note] Code\n```\n> [!warning]- After\n> Body\n```\n> code\n```";
+
+        assert_eq!(
+            parse_callouts(content),
+            [callout("warning", Some("After"), "Body", Some(false))]
+        );
+    }
+}
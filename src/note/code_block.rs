@@ -0,0 +1,233 @@
+//! Fenced code blocks (```` ``` ```` and `~~~`) and inline code (`` `code` ``) in content of note
+
+use std::ops::Range;
+
+/// Fence of code block, like ```` ``` ```` or `~~~`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct Fence {
+    symbol: char,
+    len: usize,
+}
+
+impl Fence {
+    /// Parse opening or closing fence of code block
+    ///
+    /// Like in `CommonMark`, info string after backticks can't contain backticks,
+    /// so ```` ```code``` ```` is inline code, not fence
+    fn parse(line: &str) -> Option<Self> {
+        let line = strip_indent(line)?;
+        let symbol = line.chars().next().filter(|c| *c == '`' || *c == '~')?;
+        let len = line.chars().take_while(|c| *c == symbol).count();
+        let info = &line[len * symbol.len_utf8()..];
+
+        (len >= 3 && !(symbol == '`' && info.contains('`'))).then_some(Self { symbol, len })
+    }
+
+    /// `other` closes code block opened with `self`
+    fn is_closed_by(self, other: Self, line: &str) -> bool {
+        let rest = line.trim().trim_start_matches(self.symbol);
+
+        other.symbol == self.symbol && other.len >= self.len && rest.is_empty()
+    }
+}
+
+/// Strip up to 3 spaces of indentation. Returns [`None`] for indented code block
+pub fn strip_indent(line: &str) -> Option<&str> {
+    let stripped = line.trim_start_matches(' ');
+
+    (line.len() - stripped.len() <= 3).then_some(stripped)
+}
+
+/// Iterate over lines with fenced code blocks info
+///
+/// Returns `(index of line, byte offset of line, line, line is outside code block)`.
+/// Fences themselves are inside code block. Unclosed code block lasts until the end of content
+fn classify_lines(content: &str) -> impl Iterator<Item = (usize, usize, &str, bool)> {
+    let mut open_fence: Option<Fence> = None;
+    let mut offset = 0;
+
+    content
+        .split_inclusive('\n')
+        .enumerate()
+        .map(move |(i, raw_line)| {
+            let line_offset = offset;
+            offset += raw_line.len();
+
+            let line = raw_line.strip_suffix('\n').unwrap_or(raw_line);
+            let line = line.strip_suffix('\r').unwrap_or(line);
+
+            let is_outside = match (open_fence, Fence::parse(line)) {
+                (Some(open), Some(close)) if open.is_closed_by(close, line) => {
+                    open_fence = None;
+                    false
+                }
+                (Some(_), _) => false,
+                (None, Some(fence)) => {
+                    open_fence = Some(fence);
+                    false
+                }
+                (None, None) => true,
+            };
+
+            (i, line_offset, line, is_outside)
+        })
+}
+
+/// Iterate over lines outside fenced code blocks
+///
+/// Returns `(index of line, line)`. Fences themselves are skipped too.
+/// Unclosed code block lasts until the end of content
+pub fn lines_outside_code_blocks(content: &str) -> impl Iterator<Item = (usize, &str)> {
+    classify_lines(content)
+        .filter(|(_, _, _, is_outside)| *is_outside)
+        .map(|(i, _, line, _)| (i, line))
+}
+
+/// Iterate over lines with byte offsets
+///
+/// Returns `(byte offset of line, line, line is outside code block)`.
+/// Fences themselves are inside code block. Unclosed code block lasts until the end of content
+pub fn lines_with_offsets(content: &str) -> impl Iterator<Item = (usize, &str, bool)> {
+    classify_lines(content).map(|(_, offset, line, is_outside)| (offset, line, is_outside))
+}
+
+/// Find byte ranges of inline code spans (`` `code` ``) in `line`
+///
+/// Span is opened by run of backticks and closed by run with the same length.
+/// Unmatched backticks are just text
+fn inline_code_ranges(line: &str) -> Vec<Range<usize>> {
+    let run_len = |start: usize| line[start..].bytes().take_while(|c| *c == b'`').count();
+
+    let mut ranges = Vec::new();
+    let mut pos = 0;
+    while let Some(found) = line[pos..].find('`') {
+        let start = pos + found;
+        let len = run_len(start);
+
+        let mut search = start + len;
+        let end = loop {
+            let Some(found) = line[search..].find('`') else {
+                break None;
+            };
+
+            let close = search + found;
+            let close_len = run_len(close);
+            if close_len == len {
+                break Some(close + close_len);
+            }
+
+            search = close + close_len;
+        };
+
+        match end {
+            Some(end) => {
+                ranges.push(start..end);
+                pos = end;
+            }
+            None => pos = start + len,
+        }
+    }
+
+    ranges
+}
+
+/// Find sorted byte ranges of code in `content`: fenced code blocks and inline code spans
+pub fn code_ranges(content: &str) -> Vec<Range<usize>> {
+    let mut ranges = Vec::new();
+
+    for (_, offset, line, is_outside) in classify_lines(content) {
+        if is_outside {
+            ranges.extend(
+                inline_code_ranges(line)
+                    .into_iter()
+                    .map(|range| offset + range.start..offset + range.end),
+            );
+        } else {
+            ranges.push(offset..offset + line.len());
+        }
+    }
+
+    ranges
+}
+
+/// `pos` is inside one of sorted `ranges`?
+pub fn is_in_ranges(ranges: &[Range<usize>], pos: usize) -> bool {
+    let index = ranges.partition_point(|range| range.end <= pos);
+
+    ranges.get(index).is_some_and(|range| range.contains(&pos))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn outside(content: &str) -> Vec<&str> {
+        lines_outside_code_blocks(content)
+            .map(|(_, line)| line)
+            .collect()
+    }
+
+    #[cfg_attr(feature = "tracing", tracing_test::traced_test)]
+    #[test]
+    fn skip_code_blocks() {
+        let content = "a\n```rust\ncode\n```\nb\n~~~~\ncode\n~~~\n```\nstill code\n~~~~\nc";
+
+        assert_eq!(outside(content), ["a", "b", "c"]);
+    }
+
+    #[cfg_attr(feature = "tracing", tracing_test::traced_test)]
+    #[test]
+    fn unclosed_code_block() {
+        assert_eq!(outside("a\n```\ncode\n# code"), ["a"]);
+    }
+
+    #[cfg_attr(feature = "tracing", tracing_test::traced_test)]
+    #[test]
+    fn not_fences() {
+        let content = "``inline``\n    ```\nindented";
+
+        assert_eq!(outside(content), ["``inline``", "    ```", "indented"]);
+    }
+
+    #[cfg_attr(feature = "tracing", tracing_test::traced_test)]
+    #[test]
+    fn backticks_in_info_string() {
+        let content = "```code```\n[[link]]\n~~~info```\ncode\n~~~";
+
+        assert_eq!(outside(content), ["```code```", "[[link]]"]);
+    }
+
+    #[cfg_attr(feature = "tracing", tracing_test::traced_test)]
+    #[test]
+    fn line_indices() {
+        let indices: Vec<_> = lines_outside_code_blocks("a\n```\ncode\n```\nb")
+            .map(|(i, _)| i)
+            .collect();
+
+        assert_eq!(indices, [0, 4]);
+    }
+
+    #[cfg_attr(feature = "tracing", tracing_test::traced_test)]
+    #[test]
+    fn inline_code() {
+        let line = "a `b` c ``d ` e`` f `unclosed";
+        let ranges = inline_code_ranges(line);
+
+        let spans: Vec<_> = ranges.iter().map(|range| &line[range.clone()]).collect();
+        assert_eq!(spans, ["`b`", "``d ` e``"]);
+    }
+
+    #[cfg_attr(feature = "tracing", tracing_test::traced_test)]
+    #[test]
+    fn code_ranges_with_offsets() {
+        let content = "a `b`\r\n```\ncode\n```\nc `d`";
+        let ranges = code_ranges(content);
+
+        let spans: Vec<_> = ranges.iter().map(|range| &content[range.clone()]).collect();
+        assert_eq!(spans, ["`b`", "```", "code", "```", "`d`"]);
+
+        assert!(is_in_ranges(&ranges, content.find("code").unwrap()));
+        assert!(!is_in_ranges(&ranges, 0));
+        assert!(!is_in_ranges(&ranges, content.len()));
+    }
+}
@@ -0,0 +1,145 @@
+//! Markdown headings (`# Heading`) of note
+
+use super::code_block::{lines_outside_code_blocks, strip_indent};
+
+/// Heading of note, like `## Text`
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Heading {
+    /// Level of heading: `1` for `#`, ..., `6` for `######`
+    pub level: u8,
+
+    /// Text of heading without `#`
+    pub text: String,
+}
+
+/// Max level of heading (`######`)
+const MAX_LEVEL: usize = 6;
+
+/// Parse line like `## Heading ##`
+fn parse_heading(line: &str) -> Option<Heading> {
+    let line = strip_indent(line)?;
+    let level = line.chars().take_while(|c| *c == '#').count();
+
+    if level == 0 || level > MAX_LEVEL {
+        return None;
+    }
+
+    // `#tag` is not heading
+    let rest = &line[level..];
+    if !rest.starts_with([' ', '\t']) {
+        return None;
+    }
+
+    // Optional closing sequence: `## Heading ##`
+    let text = rest.trim();
+    let text = match text.trim_end_matches('#') {
+        without_closing if without_closing.is_empty() => without_closing,
+        without_closing if without_closing.ends_with([' ', '\t']) => without_closing.trim_end(),
+        _ => text,
+    };
+
+    Some(Heading {
+        level: u8::try_from(level).ok()?,
+        text: text.to_string(),
+    })
+}
+
+/// Parse headings from content of note
+///
+/// Headings inside fenced code blocks (```` ``` ```` and `~~~`) are ignored.
+/// `#` must be followed by space, so `#tag` is not heading
+///
+/// # Example
+/// ```
+/// use obsidian_parser::note::headings::{Heading, parse_headings};
+///
+/// let content = "# Title\n#tag\n```\n# comment\n```\n## Section";
+/// let headings = parse_headings(content);
+///
+/// assert_eq!(
+///     headings,
+///     [
+///         Heading { level: 1, text: "Title".to_string() },
+///         Heading { level: 2, text: "Section".to_string() },
+///     ]
+/// );
+/// ```
+#[must_use]
+pub fn parse_headings(content: &str) -> Vec<Heading> {
+    lines_outside_code_blocks(content)
+        .filter_map(|(_, line)| parse_heading(line))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn heading(level: u8, text: &str) -> Heading {
+        Heading {
+            level,
+            text: text.to_string(),
+        }
+    }
+
+    #[cfg_attr(feature = "tracing", tracing_test::traced_test)]
+    #[test]
+    fn levels() {
+        let content = "# One\n## Two\n### Three\n#### Four\n##### Five\n###### Six\n####### Seven";
+
+        assert_eq!(
+            parse_headings(content),
+            [
+                heading(1, "One"),
+                heading(2, "Two"),
+                heading(3, "Three"),
+                heading(4, "Four"),
+                heading(5, "Five"),
+                heading(6, "Six"),
+            ]
+        );
+    }
+
+    #[cfg_attr(feature = "tracing", tracing_test::traced_test)]
+    #[test]
+    fn ignore_tags() {
+        let content = "#tag\n##not_heading\n#\n# Heading #tag\ntext # not heading";
+
+        assert_eq!(parse_headings(content), [heading(1, "Heading #tag")]);
+    }
+
+    #[cfg_attr(feature = "tracing", tracing_test::traced_test)]
+    #[test]
+    fn ignore_code_blocks() {
+        let content = "# Before\n```bash\n# comment\n```\n~~~~\n# comment\n~~~\n## Still code\n~~~~\n## After";
+
+        assert_eq!(
+            parse_headings(content),
+            [heading(1, "Before"), heading(2, "After")]
+        );
+    }
+
+    #[cfg_attr(feature = "tracing", tracing_test::traced_test)]
+    #[test]
+    fn unclosed_code_block() {
+        let content = "# Before\n```\n# comment";
+
+        assert_eq!(parse_headings(content), [heading(1, "Before")]);
+    }
+
+    #[cfg_attr(feature = "tracing", tracing_test::traced_test)]
+    #[test]
+    fn closing_sequence_and_indent() {
+        let content = "## Title ##\n   # Indented\n    # Code\n# C#\n#\tTab";
+
+        assert_eq!(
+            parse_headings(content),
+            [
+                heading(2, "Title"),
+                heading(1, "Indented"),
+                heading(1, "C#"),
+                heading(1, "Tab")
+            ]
+        );
+    }
+}
@@ -1,21 +1,41 @@
 //! Represents an Obsidian note file with frontmatter properties and content
 
+pub mod callouts;
+mod code_block;
+pub mod headings;
 pub mod note_aliases;
+pub mod note_any;
+pub mod note_arc;
 pub mod note_default;
 pub mod note_in_memory;
 pub mod note_is_todo;
+pub mod note_name;
 pub mod note_on_disk;
 pub mod note_once_cell;
 pub mod note_once_lock;
+pub mod note_properties_mapping;
 pub mod note_read;
 pub mod note_tags;
 pub mod parser;
+pub mod tasks;
+mod word_count;
 
 #[cfg(not(target_family = "wasm"))]
 pub mod note_write;
 
+#[cfg(feature = "jsonschema")]
+#[cfg_attr(docsrs, doc(cfg(feature = "jsonschema")))]
+pub mod note_validate;
+
+#[cfg(feature = "tracing")]
+#[cfg_attr(docsrs, doc(cfg(feature = "tracing")))]
+pub mod read_stats;
+
 use std::{borrow::Cow, collections::HashMap, fs::OpenOptions, path::Path};
 
+#[cfg(not(target_family = "wasm"))]
+use std::time::SystemTime;
+
 pub use note_default::NoteDefault;
 pub use note_read::{NoteFromReader, NoteFromString};
 
@@ -60,9 +80,31 @@ pub trait Note: Sized {
 
     /// Returns the parsed properties of frontmatter
     ///
-    /// Returns [`None`] if the note has no properties
+    /// Returns [`None`] if the note has no properties. Empty frontmatter (`---\n---`)
+    /// is deserialized from empty mapping, see
+    /// [`NotePropertiesMapping::non_empty_properties`](note_properties_mapping::NotePropertiesMapping::non_empty_properties)
     fn properties(&self) -> Result<Option<Cow<'_, Self::Properties>>, Self::Error>;
 
+    /// Returns the parsed properties of frontmatter or [`Default::default`]
+    ///
+    /// Same as `properties()?.unwrap_or_default()`
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use obsidian_parser::prelude::*;
+    ///
+    /// let note = NoteInMemory::from_string_default("Without frontmatter").unwrap();
+    ///
+    /// assert!(note.properties_or_default().unwrap().is_empty());
+    /// ```
+    fn properties_or_default(&self) -> Result<Cow<'_, Self::Properties>, Self::Error>
+    where
+        Self::Properties: Default,
+    {
+        Ok(self.properties()?.unwrap_or_default())
+    }
+
     /// Returns the main content body of the note (excluding frontmatter)
     ///
     /// # Implementation Notes
@@ -70,19 +112,206 @@ pub trait Note: Sized {
     /// - Preserves original formatting and whitespace
     fn content(&self) -> Result<Cow<'_, str>, Self::Error>;
 
+    /// Returns properties and content together
+    ///
+    /// Default implementation calls [`Note::properties`] and [`Note::content`].
+    /// Notes, which read file on every call (like [`NoteOnDisk`](note_on_disk::NoteOnDisk)),
+    /// override it to read and parse the file only once.
+    /// Use [`Note::parsed`] to get owned values
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use obsidian_parser::prelude::*;
+    ///
+    /// let data = "---\ntopic: life\n---\nContent";
+    /// let note = NoteInMemory::from_string_default(data).unwrap();
+    ///
+    /// let (properties, content) = note.parts().unwrap();
+    /// assert_eq!(properties.unwrap()["topic"], "life");
+    /// assert_eq!(content, "Content");
+    /// ```
+    #[allow(clippy::type_complexity)]
+    fn parts(&self) -> Result<(Option<Cow<'_, Self::Properties>>, Cow<'_, str>), Self::Error> {
+        Ok((self.properties()?, self.content()?))
+    }
+
+    /// Same as [`Note::parts`], but returns owned values
+    ///
+    /// Result doesn't borrow note, so note can be changed or dropped after it.
+    /// It is only a wrapper over [`Note::parts`]: implementations override [`Note::parts`],
+    /// not this method
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use obsidian_parser::prelude::*;
+    ///
+    /// let data = "---\ntopic: life\n---\nContent";
+    /// let note = NoteInMemory::from_string_default(data).unwrap();
+    ///
+    /// let (properties, content) = note.parsed().unwrap();
+    /// assert_eq!(properties.unwrap()["topic"], "life");
+    /// assert_eq!(content, "Content");
+    /// ```
+    fn parsed(&self) -> Result<(Option<Self::Properties>, String), Self::Error> {
+        let (properties, content) = self.parts()?;
+
+        Ok((properties.map(Cow::into_owned), content.into_owned()))
+    }
+
+    /// Returns content without Obsidian comments (`%%comment%%`)
+    ///
+    /// [`Note::content`] is not changed. See [`parser::strip_comments`]
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use obsidian_parser::prelude::*;
+    ///
+    /// let data = "---\ntopic: life\n---\nVisible %%hidden%%text";
+    /// let note = NoteInMemory::from_string_default(data).unwrap();
+    ///
+    /// assert_eq!(note.content_without_comments().unwrap(), "Visible text");
+    /// assert_eq!(note.content().unwrap(), "Visible %%hidden%%text");
+    /// ```
+    fn content_without_comments(&self) -> Result<Cow<'_, str>, Self::Error> {
+        Ok(match self.content()? {
+            Cow::Borrowed(content) => parser::strip_comments(content),
+            Cow::Owned(content) => Cow::Owned(parser::strip_comments(&content).into_owned()),
+        })
+    }
+
+    /// Returns raw frontmatter as it is written in note (without `---` or `+++` delimiters)
+    ///
+    /// Returns [`None`] if note has no frontmatter. Useful to write frontmatter back verbatim,
+    /// [`Note::properties`] is not changed.
+    ///
+    /// Default implementation returns [`None`]. Notes from this crate override it.
+    /// Without override default [`Note::raw`] has no frontmatter
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use obsidian_parser::prelude::*;
+    ///
+    /// let data = "---\ntopic: life # comment\n---\nContent";
+    /// let note = NoteInMemory::from_string_default(data).unwrap();
+    ///
+    /// assert_eq!(note.frontmatter_raw().unwrap().unwrap(), "topic: life # comment");
+    /// ```
+    fn frontmatter_raw(&self) -> Result<Option<Cow<'_, str>>, Self::Error> {
+        Ok(None)
+    }
+
+    /// Returns whole text of note with frontmatter
+    ///
+    /// Notes on disk return file as it is. Default implementation (and [`NoteInMemory`])
+    /// reconstructs text from [`Note::frontmatter_raw`] and [`Note::content`], so whitespaces
+    /// around frontmatter may differ from original, but parsing the result gives the same note
+    ///
+    /// # Implementation Notes
+    /// Default implementation writes frontmatter as YAML (`---`). It **drops** frontmatter,
+    /// if [`Note::frontmatter_raw`] is not overridden (it returns [`None`] by default):
+    /// then result is only [`Note::content`]. Override [`Note::frontmatter_raw`] or this method
+    /// to keep frontmatter
+    ///
+    /// [`NoteInMemory`]: note_in_memory::NoteInMemory
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use obsidian_parser::prelude::*;
+    ///
+    /// let data = "---\ntopic: life\n---\nContent";
+    /// let note = NoteInMemory::from_string_default(data).unwrap();
+    ///
+    /// assert_eq!(note.raw().unwrap(), data);
+    /// assert_eq!(note.content().unwrap(), "Content");
+    /// ```
+    fn raw(&self) -> Result<Cow<'_, str>, Self::Error> {
+        let content = self.content()?;
+
+        Ok(match self.frontmatter_raw()? {
+            Some(frontmatter) => Cow::Owned(join_frontmatter(
+                parser::FrontmatterFormat::Yaml,
+                &frontmatter,
+                &content,
+            )),
+            None => content,
+        })
+    }
+
     /// Returns the source file path if available
     ///
     /// Returns [`None`] for in-memory notes without physical storage
     fn path(&self) -> Option<Cow<'_, Path>>;
 
     /// Get note name
+    ///
+    /// Returns [`None`] if note has no path or path has no file name (like `..` or `/`)
     fn note_name(&self) -> Option<String> {
-        self.path().as_ref().map(|path| {
-            path.file_stem()
-                .expect("Path is not file")
-                .to_string_lossy()
-                .to_string()
-        })
+        self.path()?
+            .file_stem()
+            .map(|stem| stem.to_string_lossy().to_string())
+    }
+
+    /// Set modified time of [`Note::path`] to now without changing the file
+    ///
+    /// Ignore if path is `None`
+    ///
+    /// # Example
+    /// ```no_run
+    /// use obsidian_parser::prelude::*;
+    ///
+    /// let note = NoteOnDisk::from_file_default("note.md").unwrap();
+    /// note.touch().unwrap();
+    /// ```
+    ///
+    /// # Errors
+    /// [`std::io::Error`] if file can't be opened or its time can't be changed
+    #[cfg(not(target_family = "wasm"))]
+    fn touch(&self) -> Result<(), Self::Error>
+    where
+        Self::Error: From<std::io::Error>,
+    {
+        if let Some(path) = self.path() {
+            let file = OpenOptions::new().write(true).open(path)?;
+            file.set_modified(std::time::SystemTime::now())?;
+        }
+
+        Ok(())
+    }
+
+    /// Get modified time of [`Note::path`] from filesystem metadata
+    ///
+    /// Returns [`None`] for notes without physical storage or if platform doesn't support it
+    ///
+    /// # Example
+    /// ```no_run
+    /// use obsidian_parser::prelude::*;
+    ///
+    /// let note = NoteOnDisk::from_file_default("note.md").unwrap();
+    /// println!("Modified: {:?}", note.modified().unwrap());
+    /// ```
+    ///
+    /// # Errors
+    /// [`std::io::Error`] if metadata of file can't be read
+    #[cfg(not(target_family = "wasm"))]
+    fn modified(&self) -> Result<Option<SystemTime>, Self::Error> {
+        Ok(None)
+    }
+
+    /// Get creation time of [`Note::path`] from filesystem metadata
+    ///
+    /// Returns [`None`] for notes without physical storage or if platform (or filesystem)
+    /// doesn't support it
+    ///
+    /// # Errors
+    /// [`std::io::Error`] if metadata of file can't be read
+    #[cfg(not(target_family = "wasm"))]
+    fn created(&self) -> Result<Option<SystemTime>, Self::Error> {
+        Ok(None)
     }
 
     /// Get count words from content
@@ -102,6 +331,30 @@ pub trait Note: Sized {
         Ok(content.split_whitespace().count())
     }
 
+    /// Get count words from content, ignoring markdown syntax
+    ///
+    /// Slower than [`Note::count_words_from_content`], but closer to what reader sees:
+    /// 1. Fenced code blocks are skipped
+    /// 2. Wikilink counts as words of its display text (alias or target)
+    /// 3. Markdown link `[text](url)` counts as words of `text`
+    /// 4. Embeds (`![[...]]`), images and bare URLs are not counted
+    /// 5. Tokens without letters or digits (like `-`, `#` or `**`) are not words
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use obsidian_parser::prelude::*;
+    ///
+    /// let data = "**My** [[Some Note|super]] note https://example.com\n```\ncode\n```";
+    /// let note = NoteInMemory::from_string_default(data).unwrap();
+    ///
+    /// assert_eq!(note.count_words_markdown_aware().unwrap(), 3);
+    /// ```
+    fn count_words_markdown_aware(&self) -> Result<usize, Self::Error> {
+        let content = self.content()?;
+        Ok(word_count::count_words_markdown(&content))
+    }
+
     /// Get count symbols from content
     ///
     /// # Example
@@ -120,6 +373,277 @@ pub trait Note: Sized {
         let content = self.content()?;
         Ok(content.len())
     }
+
+    /// Split content into sections by horizontal rules (standalone `---` lines)
+    ///
+    /// Frontmatter is never included, because sections are built from [`Note::content`].
+    /// `---` inside fenced code blocks is not a rule
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use obsidian_parser::prelude::*;
+    ///
+    /// let data = "---\ntopic: life\n---\nFirst\n---\nSecond\n---\nThird";
+    /// let note = NoteInMemory::from_string_default(data).unwrap();
+    ///
+    /// assert_eq!(note.split_on_hr().unwrap(), ["First", "Second", "Third"]);
+    /// ```
+    fn split_on_hr(&self) -> Result<Vec<String>, Self::Error> {
+        let content = self.content()?;
+
+        let mut sections = Vec::new();
+        let mut section = Vec::new();
+        for (_, line, is_outside) in code_block::lines_with_offsets(&content) {
+            if is_outside && line.trim_end() == "---" {
+                sections.push(section.join("\n"));
+                section.clear();
+            } else {
+                section.push(line);
+            }
+        }
+        sections.push(section.join("\n"));
+
+        Ok(sections)
+    }
+
+    /// Get markdown headings (`# Heading`) from content
+    ///
+    /// See [`headings::parse_headings`]
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use obsidian_parser::prelude::*;
+    ///
+    /// let data = "---\ntopic: life\n---\n# Title\n#tag\n## Section";
+    /// let note = NoteInMemory::from_string_default(data).unwrap();
+    ///
+    /// let headings = note.headings().unwrap();
+    /// assert_eq!(headings.len(), 2);
+    /// assert_eq!(headings[1].level, 2);
+    /// assert_eq!(headings[1].text, "Section");
+    /// ```
+    fn headings(&self) -> Result<Vec<headings::Heading>, Self::Error> {
+        let content = self.content()?;
+        Ok(headings::parse_headings(&content))
+    }
+
+    /// Get human-readable title of note
+    ///
+    /// Precedence:
+    /// 1. `title` property from frontmatter (non-empty string)
+    /// 2. Text of first H1 heading (`# Title`) in content
+    /// 3. [`Note::note_name`]
+    /// 4. `"Untitled"`
+    ///
+    /// Frontmatter is read via [`NotePropertiesMapping`](note_properties_mapping::NotePropertiesMapping),
+    /// so it works for any [`Note::Properties`]: if properties have no `title` field, this step
+    /// is skipped
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use obsidian_parser::prelude::*;
+    ///
+    /// let note = NoteInMemory::from_string_default("---\ntitle: From YAML\n---\n# Heading").unwrap();
+    /// assert_eq!(note.title().unwrap(), "From YAML");
+    ///
+    /// let note = NoteInMemory::from_string_default("## Section\n# Heading").unwrap();
+    /// assert_eq!(note.title().unwrap(), "Heading");
+    ///
+    /// let note = NoteInMemory::from_string_default("Text").unwrap();
+    /// assert_eq!(note.title().unwrap(), "Untitled");
+    /// ```
+    fn title(&self) -> Result<String, Self::Error>
+    where
+        Self: note_properties_mapping::NotePropertiesMapping,
+    {
+        let title = self.properties_mapping()?.and_then(|mapping| {
+            mapping
+                .get("title")
+                .and_then(serde_yml::Value::as_str)
+                .map(str::trim)
+                .filter(|title| !title.is_empty())
+                .map(ToString::to_string)
+        });
+        if let Some(title) = title {
+            return Ok(title);
+        }
+
+        let heading = self
+            .headings()?
+            .into_iter()
+            .find(|heading| heading.level == 1 && !heading.text.is_empty());
+        if let Some(heading) = heading {
+            return Ok(heading.text);
+        }
+
+        Ok(self.note_name().unwrap_or_else(|| "Untitled".to_string()))
+    }
+
+    /// Get tasks (`- [ ] task`, `- [x] task`) from content
+    ///
+    /// See [`tasks::parse_tasks`]
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use obsidian_parser::prelude::*;
+    ///
+    /// let data = "---\ntopic: life\n---\n- [x] Done\n- [ ] Not done";
+    /// let note = NoteInMemory::from_string_default(data).unwrap();
+    ///
+    /// let incomplete: Vec<_> = note
+    ///     .tasks()
+    ///     .unwrap()
+    ///     .into_iter()
+    ///     .filter(|task| !task.done)
+    ///     .collect();
+    ///
+    /// assert_eq!(incomplete.len(), 1);
+    /// assert_eq!(incomplete[0].text, "Not done");
+    /// ```
+    fn tasks(&self) -> Result<Vec<tasks::Task>, Self::Error> {
+        let content = self.content()?;
+        Ok(tasks::parse_tasks(&content))
+    }
+
+    /// Get Obsidian callouts (`> [!note] Title`) from content
+    ///
+    /// Nested callouts are flattened, see [`callouts::parse_callouts`]
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use obsidian_parser::prelude::*;
+    ///
+    /// let data = "---\ntopic: life\n---\n> [!warning] Attention\n> Body";
+    /// let note = NoteInMemory::from_string_default(data).unwrap();
+    ///
+    /// let callouts = note.callouts().unwrap();
+    /// assert_eq!(callouts[0].kind, "warning");
+    /// assert_eq!(callouts[0].title.as_deref(), Some("Attention"));
+    /// assert_eq!(callouts[0].body, "Body");
+    /// ```
+    fn callouts(&self) -> Result<Vec<callouts::Callout>, Self::Error> {
+        let content = self.content()?;
+        Ok(callouts::parse_callouts(&content))
+    }
+
+    /// Get targets of all links in content (including embeds)
+    ///
+    /// Content is read once. Uses the same parser as graph in [`Vault`](crate::vault::Vault),
+    /// see [`parser::parse_links`]
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use obsidian_parser::prelude::*;
+    ///
+    /// let data = "---\ntopic: life\n---\n[[Physics#Laws]] and [[Math|Mathematics]]";
+    /// let note = NoteInMemory::from_string_default(data).unwrap();
+    ///
+    /// assert_eq!(note.outgoing_links().unwrap(), ["Physics", "Math"]);
+    /// ```
+    fn outgoing_links(&self) -> Result<Vec<String>, Self::Error> {
+        let content = self.content()?;
+
+        Ok(parser::parse_links(&content)
+            .map(ToString::to_string)
+            .collect())
+    }
+}
+
+/// Borrowed note is note too. Used by [`Vault::filtered_view`](crate::vault::Vault::filtered_view)
+impl<N> Note for &N
+where
+    N: Note,
+{
+    type Properties = N::Properties;
+    type Error = N::Error;
+
+    #[inline]
+    fn properties(&self) -> Result<Option<Cow<'_, Self::Properties>>, Self::Error> {
+        (**self).properties()
+    }
+
+    #[inline]
+    fn content(&self) -> Result<Cow<'_, str>, Self::Error> {
+        (**self).content()
+    }
+
+    #[inline]
+    fn parts(&self) -> Result<(Option<Cow<'_, Self::Properties>>, Cow<'_, str>), Self::Error> {
+        (**self).parts()
+    }
+
+    #[inline]
+    fn frontmatter_raw(&self) -> Result<Option<Cow<'_, str>>, Self::Error> {
+        (**self).frontmatter_raw()
+    }
+
+    #[inline]
+    fn raw(&self) -> Result<Cow<'_, str>, Self::Error> {
+        (**self).raw()
+    }
+
+    #[inline]
+    fn path(&self) -> Option<Cow<'_, Path>> {
+        (**self).path()
+    }
+
+    #[cfg(not(target_family = "wasm"))]
+    #[inline]
+    fn modified(&self) -> Result<Option<SystemTime>, Self::Error> {
+        (**self).modified()
+    }
+
+    #[cfg(not(target_family = "wasm"))]
+    #[inline]
+    fn created(&self) -> Result<Option<SystemTime>, Self::Error> {
+        (**self).created()
+    }
+}
+
+/// Read time from metadata of file. Unsupported time is [`None`]
+#[cfg(not(target_family = "wasm"))]
+pub(crate) fn file_time(
+    path: &Path,
+    time: impl FnOnce(&std::fs::Metadata) -> std::io::Result<SystemTime>,
+) -> std::io::Result<Option<SystemTime>> {
+    match time(&std::fs::metadata(path)?) {
+        Ok(time) => Ok(Some(time)),
+        Err(error) if error.kind() == std::io::ErrorKind::Unsupported => Ok(None),
+        Err(error) => Err(error),
+    }
+}
+
+/// Text of note from frontmatter (without delimiters) and content for [`Note::raw`]
+pub(crate) fn join_frontmatter(
+    format: parser::FrontmatterFormat,
+    frontmatter: &str,
+    content: &str,
+) -> String {
+    let delimiter = format.delimiter();
+
+    format!("{delimiter}\n{frontmatter}\n{delimiter}\n{content}")
+}
+
+/// Read raw frontmatter from file for [`Note::frontmatter_raw`]
+pub(crate) fn read_frontmatter_raw<E>(path: &Path) -> Result<Option<String>, E>
+where
+    E: From<std::io::Error> + From<parser::Error>,
+{
+    #[cfg(feature = "tracing")]
+    read_stats::record(read_stats::ReadKind::Properties);
+
+    let raw_text = std::fs::read_to_string(path)?;
+
+    match parser::parse_note(&raw_text)? {
+        parser::ResultParse::WithProperties { properties, .. } => Ok(Some(properties.to_string())),
+        parser::ResultParse::WithoutProperties => Ok(None),
+    }
 }
 
 #[cfg(test)]
@@ -136,3 +660,188 @@ pub(crate) mod impl_tests {
 
     pub(crate) use impl_test_for_note;
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::note::DefaultProperties;
+    use crate::prelude::*;
+    use std::borrow::Cow;
+    use std::path::{Path, PathBuf};
+
+    #[cfg_attr(feature = "tracing", tracing_test::traced_test)]
+    #[test]
+    fn raw_round_trip() {
+        for data in [
+            "---\ntopic: life\ntags: [a, b]\n---\n# Title\nText",
+            "---\ntopic: life\n---\n",
+            "Without frontmatter\n---\nText",
+            "",
+        ] {
+            let note = NoteInMemory::from_string_default(data).unwrap();
+            let raw = note.raw().unwrap();
+            assert_eq!(raw, data);
+
+            let parsed = NoteInMemory::from_string_default(&raw).unwrap();
+            assert_eq!(parsed, note);
+        }
+
+        let note = NoteInMemory::from_string_default("---\n topic: life \n---\n\nText\n").unwrap();
+        assert_eq!(note.raw().unwrap(), "---\ntopic: life\n---\nText");
+        assert_eq!(
+            NoteInMemory::from_string_default(note.raw().unwrap()).unwrap(),
+            note
+        );
+    }
+
+    /// Minimal [`Note`] without [`Note::frontmatter_raw`]
+    #[derive(Debug)]
+    struct NoteWithoutFrontmatterRaw;
+
+    impl Note for NoteWithoutFrontmatterRaw {
+        type Properties = DefaultProperties;
+        type Error = std::io::Error;
+
+        fn properties(&self) -> Result<Option<Cow<'_, Self::Properties>>, Self::Error> {
+            Ok(Some(Cow::Owned(DefaultProperties::default())))
+        }
+
+        fn content(&self) -> Result<Cow<'_, str>, Self::Error> {
+            Ok(Cow::Borrowed("Content"))
+        }
+
+        fn path(&self) -> Option<Cow<'_, Path>> {
+            None
+        }
+    }
+
+    #[cfg_attr(feature = "tracing", tracing_test::traced_test)]
+    #[test]
+    fn default_raw_drops_frontmatter() {
+        let note = NoteWithoutFrontmatterRaw;
+
+        assert!(note.properties().unwrap().is_some());
+        assert_eq!(note.raw().unwrap(), "Content");
+    }
+
+    #[cfg_attr(feature = "tracing", tracing_test::traced_test)]
+    #[test]
+    fn note_name_without_file_stem() {
+        let mut note = NoteInMemory::from_string_default("Text").unwrap();
+        assert_eq!(note.note_name(), None);
+
+        for path in ["..", "/", "dir/.."] {
+            note.set_path(Some(PathBuf::from(path)));
+            assert_eq!(note.note_name(), None, "{path}");
+        }
+
+        note.set_path(Some(PathBuf::from("dir/note.md")));
+        assert_eq!(note.note_name().unwrap(), "note");
+    }
+
+    #[cfg_attr(feature = "tracing", tracing_test::traced_test)]
+    #[test]
+    fn split_on_hr() {
+        let data = "---\ntopic: life\n---\nTest data\n---\nTwo test data\n\n---  \r\n---\nEnd";
+        let note = NoteInMemory::from_string_default(data).unwrap();
+
+        assert_eq!(
+            note.split_on_hr().unwrap(),
+            ["Test data", "Two test data\n", "", "End"]
+        );
+    }
+
+    #[cfg_attr(feature = "tracing", tracing_test::traced_test)]
+    #[test]
+    fn split_on_hr_without_rules() {
+        let data = "Test data\n--- not rule";
+        let note = NoteInMemory::from_string_default(data).unwrap();
+
+        assert_eq!(note.split_on_hr().unwrap(), [data]);
+    }
+
+    #[cfg_attr(feature = "tracing", tracing_test::traced_test)]
+    #[test]
+    fn split_on_hr_skips_code_blocks() {
+        let data = "First
+```yaml
+---
+key: value
+---
+```
+---
+Second";
+        let note = NoteInMemory::from_string_default(data).unwrap();
+
+        assert_eq!(
+            note.split_on_hr().unwrap(),
+            [
+                "First
+```yaml
+---
+key: value
+---
+```",
+                "Second"
+            ]
+        );
+    }
+
+    #[cfg_attr(feature = "tracing", tracing_test::traced_test)]
+    #[test]
+    fn outgoing_links() {
+        let data = "---\ntopic: life\n---\n[[One]] ![[image.png]] [[dir/Two^block|Alias]]";
+        let note = NoteInMemory::from_string_default(data).unwrap();
+
+        assert_eq!(
+            note.outgoing_links().unwrap(),
+            ["One", "image.png", "dir/Two"]
+        );
+    }
+
+    #[cfg_attr(feature = "tracing", tracing_test::traced_test)]
+    #[test]
+    fn outgoing_links_without_links() {
+        let note = NoteInMemory::from_string_default("Text [not link]").unwrap();
+
+        assert!(note.outgoing_links().unwrap().is_empty());
+    }
+
+    #[cfg_attr(feature = "tracing", tracing_test::traced_test)]
+    #[test]
+    fn title() {
+        let note = NoteInMemory::from_string_default("---\ntitle: \"  \"\n---\n# Heading").unwrap();
+        assert_eq!(note.title().unwrap(), "Heading");
+
+        let note = NoteInMemory::from_string_default("```\n# Code\n```\n#tag\n# Real").unwrap();
+        assert_eq!(note.title().unwrap(), "Real");
+
+        let mut file = tempfile::Builder::new().suffix(".md").tempfile().unwrap();
+        std::io::Write::write_all(&mut file, b"---\ntitle: 5\n---\nText").unwrap();
+        let note = NoteOnDisk::from_file_default(file.path()).unwrap();
+        assert_eq!(note.title().unwrap(), note.note_name().unwrap());
+    }
+
+    #[cfg_attr(feature = "tracing", tracing_test::traced_test)]
+    #[test]
+    fn title_with_custom_properties() {
+        #[derive(Clone, serde::Deserialize)]
+        struct Properties {
+            #[allow(dead_code)]
+            topic: String,
+        }
+
+        let note: NoteInMemory<Properties> =
+            NoteInMemory::from_string("---\ntopic: life\n---\n# Heading").unwrap();
+        assert_eq!(note.title().unwrap(), "Heading");
+    }
+
+    #[cfg_attr(feature = "tracing", tracing_test::traced_test)]
+    #[test]
+    fn properties_or_default() {
+        let note = NoteInMemory::from_string_default("Without frontmatter").unwrap();
+        assert!(note.properties_or_default().unwrap().is_empty());
+
+        let note = NoteInMemory::from_string_default("---\ntopic: life\n---\nData").unwrap();
+        assert_eq!(note.properties_or_default().unwrap()["topic"], "life");
+    }
+}
@@ -79,7 +79,7 @@ where
 {
     #[cfg_attr(feature = "tracing", tracing::instrument(skip(self), ret, fields(path = format!("{:?}", self.path()))))]
     fn aliases(&self) -> Result<Vec<String>, Self::Error> {
-        let properties = self.properties()?.unwrap_or_default();
+        let properties = self.properties_or_default()?;
 
         match properties.get(ALIASES_FIELD_NAME) {
             Some(value) => {
@@ -0,0 +1,161 @@
+//! Note, which is [`NoteInMemory`] or [`NoteOnDisk`]
+//!
+//! Used by [`AnyVault`](crate::vault::vault_any::AnyVault)
+
+use super::{DefaultProperties, Note, note_in_memory, note_on_disk};
+use crate::prelude::{NoteInMemory, NoteOnDisk};
+use serde::de::DeserializeOwned;
+use std::{borrow::Cow, path::Path};
+use thiserror::Error;
+
+/// Note, which is [`NoteInMemory`] or [`NoteOnDisk`]
+///
+/// Chosen at runtime, see [`Vault::open_auto`](crate::vault::Vault::open_auto)
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum AnyNote<T = DefaultProperties>
+where
+    T: Clone + DeserializeOwned,
+{
+    /// Note is stored in memory
+    InMemory(NoteInMemory<T>),
+
+    /// Note is read from disk on every access
+    OnDisk(NoteOnDisk<T>),
+}
+
+/// Errors for [`AnyNote`]
+#[derive(Debug, Error)]
+pub enum Error {
+    /// Error from [`NoteInMemory`]
+    #[error(transparent)]
+    InMemory(#[from] note_in_memory::Error),
+
+    /// Error from [`NoteOnDisk`]
+    #[error(transparent)]
+    OnDisk(#[from] note_on_disk::Error),
+
+    /// YAML parsing error in frontmatter properties
+    #[error("YAML parsing error: {0}")]
+    Yaml(#[from] serde_yml::Error),
+}
+
+impl<T> Note for AnyNote<T>
+where
+    T: Clone + DeserializeOwned,
+{
+    type Properties = T;
+    type Error = self::Error;
+
+    fn properties(&self) -> Result<Option<Cow<'_, T>>, Error> {
+        match self {
+            Self::InMemory(note) => Ok(note.properties()?),
+            Self::OnDisk(note) => Ok(note.properties()?),
+        }
+    }
+
+    fn content(&self) -> Result<Cow<'_, str>, Error> {
+        match self {
+            Self::InMemory(note) => Ok(note.content()?),
+            Self::OnDisk(note) => Ok(note.content()?),
+        }
+    }
+
+    fn parts(&self) -> Result<(Option<Cow<'_, T>>, Cow<'_, str>), Error> {
+        match self {
+            Self::InMemory(note) => Ok(note.parts()?),
+            Self::OnDisk(note) => Ok(note.parts()?),
+        }
+    }
+
+    fn frontmatter_raw(&self) -> Result<Option<Cow<'_, str>>, Error> {
+        match self {
+            Self::InMemory(note) => Ok(note.frontmatter_raw()?),
+            Self::OnDisk(note) => Ok(note.frontmatter_raw()?),
+        }
+    }
+
+    fn raw(&self) -> Result<Cow<'_, str>, Error> {
+        match self {
+            Self::InMemory(note) => Ok(note.raw()?),
+            Self::OnDisk(note) => Ok(note.raw()?),
+        }
+    }
+
+    fn path(&self) -> Option<Cow<'_, Path>> {
+        match self {
+            Self::InMemory(note) => note.path(),
+            Self::OnDisk(note) => note.path(),
+        }
+    }
+
+    #[cfg(not(target_family = "wasm"))]
+    fn modified(&self) -> Result<Option<std::time::SystemTime>, Error> {
+        match self {
+            Self::InMemory(note) => Ok(note.modified()?),
+            Self::OnDisk(note) => Ok(note.modified()?),
+        }
+    }
+
+    #[cfg(not(target_family = "wasm"))]
+    fn created(&self) -> Result<Option<std::time::SystemTime>, Error> {
+        match self {
+            Self::InMemory(note) => Ok(note.created()?),
+            Self::OnDisk(note) => Ok(note.created()?),
+        }
+    }
+}
+
+impl<T> From<NoteInMemory<T>> for AnyNote<T>
+where
+    T: Clone + DeserializeOwned,
+{
+    fn from(note: NoteInMemory<T>) -> Self {
+        Self::InMemory(note)
+    }
+}
+
+impl<T> From<NoteOnDisk<T>> for AnyNote<T>
+where
+    T: Clone + DeserializeOwned,
+{
+    fn from(note: NoteOnDisk<T>) -> Self {
+        Self::OnDisk(note)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::prelude::{NoteFromFile, NoteFromString, NoteTags};
+    use std::io::Write;
+    use tempfile::NamedTempFile;
+
+    const TEST_DATA: &str = "---\ntags:\n- todo\n---\nContent #work";
+
+    fn check(note: &AnyNote) {
+        assert_eq!(note.properties().unwrap().unwrap()["tags"][0], "todo");
+        assert_eq!(note.content().unwrap(), "Content #work");
+        assert_eq!(note.tags().unwrap(), ["todo", "work"]);
+    }
+
+    #[cfg_attr(feature = "tracing", tracing_test::traced_test)]
+    #[test]
+    fn in_memory() {
+        let note = AnyNote::from(NoteInMemory::from_string(TEST_DATA).unwrap());
+
+        check(&note);
+        assert!(note.path().is_none());
+    }
+
+    #[cfg_attr(feature = "tracing", tracing_test::traced_test)]
+    #[test]
+    fn on_disk() {
+        let mut file = NamedTempFile::new().unwrap();
+        file.write_all(TEST_DATA.as_bytes()).unwrap();
+
+        let note = AnyNote::from(NoteOnDisk::from_file(file.path()).unwrap());
+
+        check(&note);
+        assert_eq!(note.path().unwrap(), file.path());
+    }
+}
@@ -0,0 +1,520 @@
+//! On-disk representation of an Obsidian note file with cache, which is shared between clones
+//!
+//! # Other
+//! [`NoteOnceLock`] owns its cache, so cloning it copies path, content and properties,
+//! and every clone reads file again. Use [`NoteArc`], if notes are cloned to many threads or tasks:
+//! clone is only increment of counter and all clones use the same cache.
+//!
+//! If notes aren't cloned, [`NoteOnceLock`] is enough
+//!
+//! [`NoteOnceLock`]: crate::note::note_once_lock::NoteOnceLock
+
+use crate::note::note_properties_mapping::{NotePropertiesMapping, parse_mapping};
+use crate::note::parser::{self, ResultParse, parse_note};
+use crate::note::{DefaultProperties, Note};
+use serde::de::DeserializeOwned;
+use std::borrow::Cow;
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, OnceLock};
+use thiserror::Error;
+
+/// On-disk representation of an Obsidian note file with cache, which is shared between clones
+///
+/// Clone is `O(1)`: content and properties are read from disk once for all clones
+///
+/// # Example
+/// ```no_run
+/// use obsidian_parser::prelude::*;
+///
+/// let note: NoteArc = NoteArc::from_file("note.md").unwrap();
+/// let clone = note.clone();
+///
+/// std::thread::spawn(move || {
+///     println!("{}", clone.content().unwrap()); // read from disk
+/// })
+/// .join()
+/// .unwrap();
+///
+/// assert!(note.is_cached()); // cache is filled by clone
+/// ```
+///
+/// # Other
+/// If notes aren't cloned, use [`NoteOnceLock`]
+///
+/// [`NoteOnceLock`]: crate::note::note_once_lock::NoteOnceLock
+#[derive(Debug, Default, PartialEq, Eq, Clone)]
+pub struct NoteArc<T = DefaultProperties>
+where
+    T: Clone + DeserializeOwned,
+{
+    inner: Arc<Inner<T>>,
+}
+
+/// Shared state of [`NoteArc`]
+#[derive(Debug, Default, PartialEq, Eq)]
+struct Inner<T> {
+    /// Absolute path to the source Markdown file
+    path: PathBuf,
+
+    /// Markdown content body (without frontmatter)
+    content: OnceLock<String>,
+
+    /// Parsed frontmatter properties
+    properties: OnceLock<Option<T>>,
+}
+
+impl<T> Inner<T> {
+    const fn new(path: PathBuf) -> Self {
+        Self {
+            path,
+            content: OnceLock::new(),
+            properties: OnceLock::new(),
+        }
+    }
+}
+
+/// Errors for [`NoteArc`]
+#[derive(Debug, Error)]
+#[non_exhaustive]
+pub enum Error {
+    /// I/O operation failed (file reading, directory traversal, etc.)
+    #[error("IO error: {0}")]
+    IO(#[from] std::io::Error),
+
+    /// Invalid frontmatter format detected
+    ///
+    /// Occurs when:
+    /// - Frontmatter delimiters are incomplete (`---` missing)
+    /// - Content between delimiters is empty
+    ///
+    /// # Example
+    /// Parsing a file with malformed frontmatter:
+    /// ```text
+    /// ---
+    /// incomplete yaml
+    /// // Missing closing ---
+    /// ```
+    #[error("Invalid frontmatter format: {0}")]
+    InvalidFormat(#[from] parser::Error),
+
+    /// YAML parsing error in frontmatter properties
+    ///
+    /// # Example
+    /// Parsing invalid YAML syntax:
+    /// ```text
+    /// ---
+    /// key: @invalid_value
+    /// ---
+    /// ```
+    #[error("YAML parsing error: {0}")]
+    Yaml(#[from] serde_yml::Error),
+
+    /// TOML parsing error in frontmatter properties (`+++` delimiters)
+    #[cfg_attr(docsrs, doc(cfg(feature = "toml")))]
+    #[cfg(feature = "toml")]
+    #[error("TOML parsing error: {0}")]
+    Toml(#[from] toml::de::Error),
+
+    /// Expected a file path
+    ///
+    /// # Example
+    /// ```no_run
+    /// use obsidian_parser::prelude::*;
+    ///
+    /// // Will fail if passed a directory path
+    /// NoteOnDisk::from_file_default("/home/test");
+    /// ```
+    #[error("Path: `{0}` is not a directory")]
+    IsNotFile(PathBuf),
+}
+
+impl<T> Note for NoteArc<T>
+where
+    T: DeserializeOwned + Clone,
+{
+    type Properties = T;
+    type Error = self::Error;
+
+    /// Parses YAML frontmatter directly from disk
+    ///
+    /// # Errors
+    /// - [`Error::Yaml`] if properties can't be deserialized
+    /// - [`Error::IsNotFile`] If file doesn't exist
+    /// - [`Error::IO`] on filesystem error
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip(self), fields(path = %self.inner.path.display())))]
+    fn properties(&self) -> Result<Option<Cow<'_, T>>, Error> {
+        #[cfg(feature = "tracing")]
+        tracing::trace!("Get properties from file");
+
+        if let Some(properties) = self.inner.properties.get() {
+            return Ok(properties.as_ref().map(|value| Cow::Borrowed(value)));
+        }
+
+        #[cfg(feature = "tracing")]
+        crate::note::read_stats::record(crate::note::read_stats::ReadKind::Properties);
+
+        let raw_text = std::fs::read_to_string(&self.inner.path)?;
+
+        let result = match parse_note(&raw_text)? {
+            ResultParse::WithProperties {
+                content: _,
+                properties,
+                format,
+            } => {
+                #[cfg(feature = "tracing")]
+                tracing::trace!("Frontmatter detected, parsing properties");
+
+                Some(parser::deserialize_properties::<_, Error>(
+                    properties, format,
+                )?)
+            }
+            ResultParse::WithoutProperties => {
+                #[cfg(feature = "tracing")]
+                tracing::trace!("No frontmatter found, storing raw content");
+
+                None
+            }
+        };
+
+        let _ = self.inner.properties.set(result.clone()); // already check
+        Ok(result.map(|value| Cow::Owned(value)))
+    }
+
+    /// Returns the note's content body (without frontmatter)
+    ///
+    /// # Errors
+    /// - [`Error::IO`] on filesystem error
+    ///
+    /// # Performance
+    /// Performs disk read only on first call of this note or any of its clones
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip(self), fields(path = %self.inner.path.display())))]
+    fn content(&self) -> Result<Cow<'_, str>, Error> {
+        #[cfg(feature = "tracing")]
+        tracing::trace!("Get content from file");
+
+        if let Some(content) = self.inner.content.get() {
+            return Ok(Cow::Borrowed(content));
+        }
+
+        #[cfg(feature = "tracing")]
+        crate::note::read_stats::record(crate::note::read_stats::ReadKind::Content);
+
+        let raw_text = std::fs::read_to_string(&self.inner.path)?;
+
+        let result = match parse_note(&raw_text)? {
+            ResultParse::WithProperties { content, .. } => {
+                #[cfg(feature = "tracing")]
+                tracing::trace!("Frontmatter detected, parsing properties");
+
+                content.to_string()
+            }
+            ResultParse::WithoutProperties => {
+                #[cfg(feature = "tracing")]
+                tracing::trace!("No frontmatter found, storing raw content");
+
+                raw_text
+            }
+        };
+
+        let _ = self.inner.content.set(result.clone()); // already check
+        Ok(Cow::Owned(result))
+    }
+
+    /// Reads raw frontmatter from disk on every call
+    ///
+    /// # Errors
+    /// - [`Error::InvalidFormat`] if frontmatter is not closed
+    /// - [`Error::IO`] on filesystem error
+    fn frontmatter_raw(&self) -> Result<Option<Cow<'_, str>>, Error> {
+        Ok(super::read_frontmatter_raw::<Error>(&self.inner.path)?.map(Cow::Owned))
+    }
+
+    /// Reads whole file from disk on every call
+    ///
+    /// # Errors
+    /// - [`Error::IO`] on filesystem error
+    fn raw(&self) -> Result<Cow<'_, str>, Error> {
+        #[cfg(feature = "tracing")]
+        crate::note::read_stats::record(crate::note::read_stats::ReadKind::Content);
+
+        Ok(Cow::Owned(std::fs::read_to_string(&self.inner.path)?))
+    }
+
+    /// Get path to note
+    #[inline]
+    fn path(&self) -> Option<Cow<'_, Path>> {
+        Some(Cow::Borrowed(&self.inner.path))
+    }
+
+    /// Get modified time of file
+    #[cfg(not(target_family = "wasm"))]
+    fn modified(&self) -> Result<Option<std::time::SystemTime>, Self::Error> {
+        Ok(super::file_time(
+            &self.inner.path,
+            std::fs::Metadata::modified,
+        )?)
+    }
+
+    /// Get creation time of file
+    #[cfg(not(target_family = "wasm"))]
+    fn created(&self) -> Result<Option<std::time::SystemTime>, Self::Error> {
+        Ok(super::file_time(
+            &self.inner.path,
+            std::fs::Metadata::created,
+        )?)
+    }
+}
+
+impl<T> NotePropertiesMapping for NoteArc<T>
+where
+    T: DeserializeOwned + Clone,
+{
+    /// Parses YAML frontmatter directly from disk into [`serde_yml::Mapping`]
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip(self), fields(path = %self.inner.path.display())))]
+    fn properties_mapping(&self) -> Result<Option<serde_yml::Mapping>, Error> {
+        #[cfg(feature = "tracing")]
+        tracing::trace!("Get properties mapping from file");
+
+        #[cfg(feature = "tracing")]
+        crate::note::read_stats::record(crate::note::read_stats::ReadKind::Properties);
+
+        let raw_text = std::fs::read_to_string(&self.inner.path)?;
+
+        match parse_note(&raw_text)? {
+            ResultParse::WithProperties {
+                content: _,
+                properties,
+                format,
+            } => Ok(Some(parse_mapping::<Error>(properties, format)?)),
+            ResultParse::WithoutProperties => Ok(None),
+        }
+    }
+}
+
+impl<T> NoteArc<T>
+where
+    T: DeserializeOwned + Clone,
+{
+    /// Set path to note
+    ///
+    /// Cache is cleared only for this note: other clones keep old path and cache
+    #[inline]
+    pub fn set_path(&mut self, path: PathBuf) {
+        self.inner = Arc::new(Inner::new(path));
+    }
+
+    /// Clear cached content and properties, so they are read from disk again on next access
+    ///
+    /// Cache is shared, so this note gets new empty cache and other clones keep old one
+    ///
+    /// # Example
+    /// ```no_run
+    /// use obsidian_parser::prelude::*;
+    ///
+    /// let mut note: NoteArc = NoteArc::from_file("note.md").unwrap();
+    /// println!("{}", note.content().unwrap());
+    ///
+    /// // File is changed on disk
+    /// note.reload().unwrap();
+    /// println!("{}", note.content().unwrap());
+    /// ```
+    ///
+    /// # Errors
+    /// [`Error::IsNotFile`] if file doesn't exist anymore. Cache is cleared anyway
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip(self), fields(path = %self.inner.path.display())))]
+    pub fn reload(&mut self) -> Result<(), Error> {
+        self.set_path(self.inner.path.clone());
+
+        if !self.inner.path.is_file() {
+            return Err(Error::IsNotFile(self.inner.path.clone()));
+        }
+
+        Ok(())
+    }
+
+    /// Content or properties are cached
+    #[must_use]
+    #[inline]
+    pub fn is_cached(&self) -> bool {
+        self.inner.content.get().is_some() || self.inner.properties.get().is_some()
+    }
+
+    /// Both notes use the same cache
+    #[must_use]
+    #[inline]
+    pub fn ptr_eq(&self, other: &Self) -> bool {
+        Arc::ptr_eq(&self.inner, &other.inner)
+    }
+}
+
+#[cfg(not(target_family = "wasm"))]
+impl<T> crate::prelude::NoteFromFile for NoteArc<T>
+where
+    T: DeserializeOwned + Clone,
+{
+    /// Creates instance from file
+    fn from_file(path: impl AsRef<Path>) -> Result<Self, Error> {
+        let path = path.as_ref().to_path_buf();
+
+        if !path.is_file() {
+            return Err(Error::IsNotFile(path));
+        }
+
+        Ok(Self {
+            inner: Arc::new(Inner::new(path)),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::note::NoteDefault;
+    use crate::note::impl_tests::impl_test_for_note;
+    use crate::note::note_aliases::tests::{from_file_have_aliases, from_file_have_not_aliases};
+    use crate::note::note_is_todo::tests::{
+        from_file_is_not_todo, from_file_is_todo, from_file_is_todo_with_tasks,
+    };
+    use crate::note::note_properties_mapping::tests::impl_all_tests_properties_mapping;
+    use crate::note::note_read::tests::{
+        from_file, from_file_frontmatter_raw, from_file_modified, from_file_raw,
+        from_file_with_unicode,
+    };
+    use crate::note::note_tags::tests::from_file_tags;
+    use crate::note::note_write::tests::impl_all_tests_flush;
+    use std::io::Write;
+    use tempfile::NamedTempFile;
+
+    impl_all_tests_flush!(NoteArc);
+    impl_all_tests_properties_mapping!(NoteArc);
+    impl_test_for_note!(impl_from_file, from_file, NoteArc);
+    impl_test_for_note!(impl_from_file_modified, from_file_modified, NoteArc);
+    impl_test_for_note!(
+        impl_from_file_frontmatter_raw,
+        from_file_frontmatter_raw,
+        NoteArc
+    );
+    impl_test_for_note!(impl_from_file_raw, from_file_raw, NoteArc);
+    impl_test_for_note!(impl_from_file_tags, from_file_tags, NoteArc);
+
+    impl_test_for_note!(impl_from_file_with_unicode, from_file_with_unicode, NoteArc);
+
+    impl_test_for_note!(impl_from_file_is_todo, from_file_is_todo, NoteArc);
+    impl_test_for_note!(
+        impl_from_file_is_todo_with_tasks,
+        from_file_is_todo_with_tasks,
+        NoteArc
+    );
+    impl_test_for_note!(impl_from_file_is_not_todo, from_file_is_not_todo, NoteArc);
+
+    impl_test_for_note!(impl_from_file_have_aliases, from_file_have_aliases, NoteArc);
+    impl_test_for_note!(
+        impl_from_file_have_not_aliases,
+        from_file_have_not_aliases,
+        NoteArc
+    );
+
+    #[cfg_attr(feature = "tracing", tracing_test::traced_test)]
+    #[test]
+    #[should_panic]
+    fn use_from_file_with_path_not_file() {
+        let temp_dir = tempfile::tempdir().unwrap();
+
+        NoteArc::from_file_default(temp_dir.path()).unwrap();
+    }
+
+    #[cfg_attr(feature = "tracing", tracing_test::traced_test)]
+    #[test]
+    fn get_path() {
+        let test_file = NamedTempFile::new().unwrap();
+        let file = NoteArc::from_file_default(test_file.path()).unwrap();
+
+        assert_eq!(file.path().unwrap(), test_file.path());
+        assert_eq!(file.inner.path, test_file.path());
+    }
+
+    #[cfg_attr(feature = "tracing", tracing_test::traced_test)]
+    #[test]
+    fn get_content() {
+        let test_data = "DATA";
+        let mut test_file = NamedTempFile::new().unwrap();
+        test_file.write_all(test_data.as_bytes()).unwrap();
+
+        let file = NoteArc::from_file_default(test_file.path()).unwrap();
+        assert_eq!(file.content().unwrap(), test_data);
+    }
+
+    #[cfg_attr(feature = "tracing", tracing_test::traced_test)]
+    #[test]
+    fn get_properties() {
+        let test_data = "---\ntime: now\n---\nDATA";
+        let mut test_file = NamedTempFile::new().unwrap();
+        test_file.write_all(test_data.as_bytes()).unwrap();
+
+        let file = NoteArc::from_file_default(test_file.path()).unwrap();
+        let properties = file.properties().unwrap().unwrap();
+
+        assert_eq!(file.content().unwrap(), "DATA");
+        assert_eq!(properties["time"], "now");
+    }
+
+    #[cfg_attr(feature = "tracing", tracing_test::traced_test)]
+    #[test]
+    fn reload() {
+        let mut test_file = NamedTempFile::new().unwrap();
+        test_file.write_all(b"---\ntopic: life\n---\nOld").unwrap();
+
+        let mut note = NoteArc::from_file_default(test_file.path()).unwrap();
+        assert!(!note.is_cached());
+
+        assert_eq!(note.content().unwrap(), "Old");
+        assert_eq!(note.properties().unwrap().unwrap()["topic"], "life");
+        assert!(note.is_cached());
+
+        std::fs::write(test_file.path(), "---\ntopic: work\n---\nNew").unwrap();
+        assert_eq!(note.content().unwrap(), "Old");
+
+        note.reload().unwrap();
+        assert!(!note.is_cached());
+        assert_eq!(note.content().unwrap(), "New");
+        assert_eq!(note.properties().unwrap().unwrap()["topic"], "work");
+    }
+
+    #[cfg_attr(feature = "tracing", tracing_test::traced_test)]
+    #[test]
+    fn reload_removed_file() {
+        let test_file = NamedTempFile::new().unwrap();
+        let mut note = NoteArc::from_file_default(test_file.path()).unwrap();
+        note.content().unwrap();
+
+        let path = test_file.path().to_path_buf();
+        drop(test_file);
+
+        assert!(matches!(note.reload(), Err(Error::IsNotFile(error_path)) if error_path == path));
+        assert!(!note.is_cached());
+    }
+
+    #[cfg_attr(feature = "tracing", tracing_test::traced_test)]
+    #[test]
+    fn clone_shares_cache() {
+        let mut test_file = NamedTempFile::new().unwrap();
+        test_file.write_all(b"---\ntopic: life\n---\nOld").unwrap();
+
+        let mut note = NoteArc::from_file_default(test_file.path()).unwrap();
+        let clone = note.clone();
+        assert!(note.ptr_eq(&clone));
+
+        std::thread::scope(|scope| {
+            scope.spawn(|| assert_eq!(clone.content().unwrap(), "Old"));
+        });
+        assert!(note.is_cached());
+
+        std::fs::write(test_file.path(), "---\ntopic: work\n---\nNew").unwrap();
+        assert_eq!(note.content().unwrap(), "Old");
+
+        note.reload().unwrap();
+        assert!(!note.ptr_eq(&clone));
+        assert_eq!(note.content().unwrap(), "New");
+        assert_eq!(clone.content().unwrap(), "Old");
+    }
+}
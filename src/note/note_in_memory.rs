@@ -1,8 +1,9 @@
 //! In-memory representation of an Obsidian note file
 
 use super::{DefaultProperties, Note, NoteFromReader, NoteFromString};
-use crate::note::parser::{self, ResultParse, parse_note};
-use serde::de::DeserializeOwned;
+use crate::note::note_properties_mapping::{NotePropertiesMapping, parse_mapping};
+use crate::note::parser::{self, FrontmatterFormat, ResultParse, parse_note};
+use serde::{Serialize, de::DeserializeOwned};
 use std::{
     borrow::Cow,
     fs::File,
@@ -38,10 +39,17 @@ where
 
     /// Parsed frontmatter properties
     properties: Option<T>,
+
+    /// Raw frontmatter (without `---` delimiters)
+    raw_properties: Option<String>,
+
+    /// Syntax of [`NoteInMemory::raw_properties`]
+    properties_format: FrontmatterFormat,
 }
 
 /// Errors in [`NoteInMemory`]
 #[derive(Debug, Error)]
+#[non_exhaustive]
 pub enum Error {
     /// I/O operation failed (file reading, directory traversal, etc.)
     #[error("IO error: {0}")]
@@ -60,7 +68,7 @@ pub enum Error {
     /// incomplete yaml
     /// // Missing closing ---
     /// ```
-    #[error("Invalid frontmatter format")]
+    #[error("Invalid frontmatter format: {0}")]
     InvalidFormat(#[from] parser::Error),
 
     /// YAML parsing error in frontmatter properties
@@ -74,6 +82,12 @@ pub enum Error {
     /// ```
     #[error("YAML parsing error: {0}")]
     Yaml(#[from] serde_yml::Error),
+
+    /// TOML parsing error in frontmatter properties (`+++` delimiters)
+    #[cfg_attr(docsrs, doc(cfg(feature = "toml")))]
+    #[cfg(feature = "toml")]
+    #[error("TOML parsing error: {0}")]
+    Toml(#[from] toml::de::Error),
 }
 
 impl<T> Note for NoteInMemory<T>
@@ -95,22 +109,165 @@ where
         Ok(Cow::Borrowed(&self.content))
     }
 
+    /// Returns stored raw frontmatter
+    ///
+    /// After [`NoteInMemory::set_properties`] it is frontmatter serialized as YAML
+    #[inline]
+    fn frontmatter_raw(&self) -> Result<Option<Cow<'_, str>>, Self::Error> {
+        Ok(self.raw_properties.as_deref().map(Cow::Borrowed))
+    }
+
+    /// Reconstructs text from stored raw frontmatter and content
+    fn raw(&self) -> Result<Cow<'_, str>, Self::Error> {
+        Ok(self.raw_properties.as_ref().map_or_else(
+            || Cow::Borrowed(self.content.as_str()),
+            |raw_properties| {
+                Cow::Owned(super::join_frontmatter(
+                    self.properties_format,
+                    raw_properties,
+                    &self.content,
+                ))
+            },
+        ))
+    }
+
     /// Get path to file
     #[inline]
     fn path(&self) -> Option<Cow<'_, Path>> {
         self.path.as_ref().map(|p| Cow::Borrowed(p.as_path()))
     }
+
+    /// Get modified time of file if note has path
+    #[cfg(not(target_family = "wasm"))]
+    fn modified(&self) -> Result<Option<std::time::SystemTime>, Self::Error> {
+        match &self.path {
+            Some(path) => Ok(super::file_time(path, std::fs::Metadata::modified)?),
+            None => Ok(None),
+        }
+    }
+
+    /// Get creation time of file if note has path
+    #[cfg(not(target_family = "wasm"))]
+    fn created(&self) -> Result<Option<std::time::SystemTime>, Self::Error> {
+        match &self.path {
+            Some(path) => Ok(super::file_time(path, std::fs::Metadata::created)?),
+            None => Ok(None),
+        }
+    }
+}
+
+impl<T> NotePropertiesMapping for NoteInMemory<T>
+where
+    T: Clone,
+{
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip(self), fields(path = format!("{:?}", self.path))))]
+    fn properties_mapping(&self) -> Result<Option<serde_yml::Mapping>, Self::Error> {
+        match &self.raw_properties {
+            Some(raw_properties) => Ok(Some(parse_mapping::<Error>(
+                raw_properties,
+                self.properties_format,
+            )?)),
+            None => Ok(None),
+        }
+    }
 }
 
 impl<T> NoteInMemory<T>
 where
     T: Clone,
 {
+    /// Create note from parts
+    ///
+    /// # Example
+    /// ```
+    /// use obsidian_parser::prelude::*;
+    /// use std::collections::HashMap;
+    ///
+    /// let properties = HashMap::from([("topic".to_string(), serde_yml::Value::from("life"))]);
+    ///
+    /// let note = NoteInMemory::new("Content", Some(properties), None).unwrap();
+    /// assert_eq!(note.content().unwrap(), "Content");
+    /// assert_eq!(note.properties().unwrap().unwrap()["topic"], "life");
+    /// ```
+    ///
+    /// # Errors
+    /// [`Error::Yaml`] if `properties` can't be serialized (see [`NoteInMemory::set_properties`])
+    pub fn new(
+        content: impl Into<String>,
+        properties: Option<T>,
+        path: Option<PathBuf>,
+    ) -> Result<Self, Error>
+    where
+        T: Serialize,
+    {
+        let mut note = Self {
+            content: content.into(),
+            path,
+            properties: None,
+            raw_properties: None,
+            properties_format: FrontmatterFormat::default(),
+        };
+
+        note.set_properties(properties)?;
+        Ok(note)
+    }
+
     /// Set path to note
     #[inline]
     pub fn set_path(&mut self, path: Option<PathBuf>) {
         self.path = path;
     }
+
+    /// Set content (without frontmatter)
+    ///
+    /// Use [`NoteWrite::flush`](crate::note::NoteWrite::flush) to save note
+    ///
+    /// # Example
+    /// ```
+    /// use obsidian_parser::prelude::*;
+    ///
+    /// let mut note = NoteInMemory::from_string_default("---\ntopic: life\n---\nOld").unwrap();
+    /// note.set_content("New");
+    ///
+    /// assert_eq!(note.content().unwrap(), "New");
+    /// assert_eq!(note.properties().unwrap().unwrap()["topic"], "life");
+    /// ```
+    #[inline]
+    pub fn set_content(&mut self, content: impl Into<String>) {
+        self.content = content.into();
+    }
+
+    /// Set properties. [`None`] removes frontmatter
+    ///
+    /// Raw frontmatter (used by [`NotePropertiesMapping`]) is serialized from `properties`
+    /// as YAML, so [`NoteWrite::flush`](crate::note::NoteWrite::flush) writes `---` delimiters
+    ///
+    /// # Example
+    /// ```
+    /// use obsidian_parser::prelude::*;
+    ///
+    /// let mut note = NoteInMemory::from_string_default("---\ntopic: life\n---\nText").unwrap();
+    ///
+    /// let mut properties = note.properties().unwrap().unwrap().into_owned();
+    /// properties.insert("topic".to_string(), "work".into());
+    /// note.set_properties(Some(properties)).unwrap();
+    ///
+    /// assert_eq!(note.properties_mapping().unwrap().unwrap()["topic"], "work");
+    /// ```
+    ///
+    /// # Errors
+    /// [`Error::Yaml`] if `properties` can't be serialized. Note is not changed in this case
+    pub fn set_properties(&mut self, properties: Option<T>) -> Result<(), Error>
+    where
+        T: Serialize,
+    {
+        self.raw_properties = properties.as_ref().map(serde_yml::to_string).transpose()?;
+
+        self.properties = properties;
+        self.properties_format = FrontmatterFormat::Yaml;
+
+        Ok(())
+    }
 }
 
 impl<T> NoteFromString for NoteInMemory<T>
@@ -164,13 +321,18 @@ where
             ResultParse::WithProperties {
                 content,
                 properties,
+                format,
             } => {
                 #[cfg(feature = "tracing")]
                 tracing::trace!("Frontmatter detected, parsing properties");
 
                 Ok(Self {
                     content: content.to_string(),
-                    properties: Some(serde_yml::from_str(properties)?),
+                    properties: Some(parser::deserialize_properties::<_, Error>(
+                        properties, format,
+                    )?),
+                    raw_properties: Some(properties.to_string()),
+                    properties_format: format,
                     path: None,
                 })
             }
@@ -182,6 +344,8 @@ where
                     content: raw_text.to_string(),
                     path: None,
                     properties: None,
+                    raw_properties: None,
+                    properties_format: FrontmatterFormat::default(),
                 })
             }
         }
@@ -214,6 +378,7 @@ mod tests {
     use crate::note::{
         note_aliases::tests::impl_all_tests_aliases,
         note_is_todo::tests::impl_all_tests_is_todo,
+        note_properties_mapping::tests::impl_all_tests_properties_mapping,
         note_read::tests::{
             impl_all_tests_from_file, impl_all_tests_from_reader, impl_all_tests_from_string,
         },
@@ -221,6 +386,9 @@ mod tests {
         note_write::tests::impl_all_tests_flush,
     };
 
+    use crate::note::DefaultProperties;
+    use crate::prelude::{NoteDefault, NotePropertiesMapping};
+
     impl_all_tests_tags!(NoteInMemory);
     impl_all_tests_from_reader!(NoteInMemory);
     impl_all_tests_from_string!(NoteInMemory);
@@ -228,4 +396,141 @@ mod tests {
     impl_all_tests_flush!(NoteInMemory);
     impl_all_tests_is_todo!(NoteInMemory);
     impl_all_tests_aliases!(NoteInMemory);
+    impl_all_tests_properties_mapping!(NoteInMemory);
+
+    impl_test_for_note!(
+        impl_from_string_properties_mapping,
+        from_string_properties_mapping,
+        NoteInMemory<TitleProperties>
+    );
+
+    fn flush_and_read(note: &NoteInMemory) -> NoteInMemory {
+        use crate::prelude::{NoteFromFile, NoteWrite};
+
+        let path = note.path().unwrap();
+        let open_options = std::fs::OpenOptions::new()
+            .write(true)
+            .truncate(true)
+            .clone();
+
+        note.flush(&open_options).unwrap();
+        NoteInMemory::from_file(path).unwrap()
+    }
+
+    #[cfg_attr(feature = "tracing", tracing_test::traced_test)]
+    #[test]
+    fn set_content() {
+        let file = tempfile::NamedTempFile::new().unwrap();
+        std::fs::write(file.path(), "---\ntopic: life\n---\nOld").unwrap();
+
+        let mut note = NoteInMemory::from_file_default(file.path()).unwrap();
+        note.set_content("New content");
+
+        let note = flush_and_read(&note);
+        assert_eq!(note.content().unwrap(), "New content");
+        assert_eq!(note.properties().unwrap().unwrap()["topic"], "life");
+    }
+
+    #[cfg_attr(feature = "tracing", tracing_test::traced_test)]
+    #[test]
+    fn set_properties() {
+        let file = tempfile::NamedTempFile::new().unwrap();
+        std::fs::write(file.path(), "Text").unwrap();
+
+        let mut note = NoteInMemory::from_file_default(file.path()).unwrap();
+        let properties = DefaultProperties::from([("topic".to_string(), "work".into())]);
+        note.set_properties(Some(properties.clone())).unwrap();
+
+        assert_eq!(note.properties_mapping().unwrap().unwrap()["topic"], "work");
+
+        let mut note = flush_and_read(&note);
+        assert_eq!(note.properties().unwrap().unwrap().into_owned(), properties);
+        assert_eq!(note.content().unwrap(), "Text");
+
+        note.set_properties(None).unwrap();
+        assert!(note.properties_mapping().unwrap().is_none());
+
+        let note = flush_and_read(&note);
+        assert!(note.properties().unwrap().is_none());
+        assert_eq!(note.content().unwrap(), "Text");
+    }
+
+    #[cfg_attr(feature = "tracing", tracing_test::traced_test)]
+    #[test]
+    fn set_properties_with_yaml_anchors() {
+        let file = tempfile::NamedTempFile::new().unwrap();
+        std::fs::write(
+            file.path(),
+            "---\nbase: &base\n  priority: 1\nother: *base\n---\nText",
+        )
+        .unwrap();
+
+        let mut note = NoteInMemory::from_file_default(file.path()).unwrap();
+        let mut properties = note.properties().unwrap().unwrap().into_owned();
+        properties.insert("topic".to_string(), "life".into());
+        note.set_properties(Some(properties)).unwrap();
+
+        // Changed properties are serialized again: anchors are expanded, values are the same
+        let note = flush_and_read(&note);
+        let text = std::fs::read_to_string(file.path()).unwrap();
+        assert!(!text.contains('&') && !text.contains('*'), "{text}");
+
+        let properties = note.properties().unwrap().unwrap();
+        assert_eq!(properties["other"]["priority"], 1);
+        assert_eq!(properties["base"]["priority"], 1);
+        assert_eq!(properties["topic"], "life");
+    }
+
+    #[cfg_attr(feature = "tracing", tracing_test::traced_test)]
+    #[test]
+    fn flush_yaml_anchors_to_new_file() {
+        use crate::prelude::NoteWrite;
+
+        const DATA: &str = "---\nbase: &base\n  priority: 1\nother: *base\n---\nText";
+
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let path = temp_dir.path().join("new.md");
+
+        // Anchors are kept from raw frontmatter in memory, file is not read
+        let mut note = NoteInMemory::from_string_default(DATA).unwrap();
+        note.set_path(Some(path.clone()));
+
+        let open_options = std::fs::OpenOptions::new().write(true).create(true).clone();
+        note.flush(&open_options).unwrap();
+
+        assert_eq!(std::fs::read_to_string(path).unwrap(), DATA);
+    }
+
+    #[cfg(feature = "toml")]
+    #[cfg_attr(feature = "tracing", tracing_test::traced_test)]
+    #[test]
+    fn set_properties_after_toml() {
+        let mut note =
+            NoteInMemory::from_string_default("+++\ntopic = \"life\"\n+++\nText").unwrap();
+
+        let properties = note.properties().unwrap().unwrap().into_owned();
+        note.set_properties(Some(properties)).unwrap();
+
+        assert_eq!(note.properties_format, FrontmatterFormat::Yaml);
+        assert_eq!(note.properties_mapping().unwrap().unwrap()["topic"], "life");
+    }
+
+    #[cfg_attr(feature = "tracing", tracing_test::traced_test)]
+    #[test]
+    fn new() {
+        let path = PathBuf::from("note.md");
+        let properties = DefaultProperties::from([("topic".to_string(), "life".into())]);
+
+        let note =
+            NoteInMemory::new("Content", Some(properties.clone()), Some(path.clone())).unwrap();
+        let expected = NoteInMemory::from_string_default("---\ntopic: life\n---\nContent").unwrap();
+
+        assert_eq!(note.path().unwrap(), path);
+        assert_eq!(note.content().unwrap(), expected.content().unwrap());
+        assert_eq!(note.properties().unwrap(), expected.properties().unwrap());
+        assert_eq!(
+            note.properties_mapping().unwrap(),
+            expected.properties_mapping().unwrap()
+        );
+    }
 }
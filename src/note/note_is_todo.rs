@@ -1,8 +1,24 @@
 //! Impl trait [`NoteIsTodo`]
 
-use super::Note;
+use super::{Note, tasks::parse_tasks};
 use crate::prelude::NoteTags;
 
+/// How [`NoteIsTodo::is_todo_with`] decides that note is todo
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum TodoMode {
+    /// Note has `todo` tag
+    #[default]
+    Tag,
+
+    /// Note has `todo` tag **or** at least one incomplete task (`- [ ]`)
+    TagOrTasks,
+}
+
+/// Note content has at least one incomplete task?
+fn has_incomplete_tasks(content: &str) -> bool {
+    parse_tasks(content).iter().any(|task| !task.done)
+}
+
 /// Trait for check note is marked todo
 pub trait NoteIsTodo: Note {
     /// Note is marked todo?
@@ -17,6 +33,33 @@ pub trait NoteIsTodo: Note {
     /// assert!(note.is_todo().unwrap());
     /// ```
     fn is_todo(&self) -> Result<bool, Self::Error>;
+
+    /// Note is marked todo with selected [`TodoMode`]?
+    ///
+    /// [`TodoMode::Tag`] is the same as [`NoteIsTodo::is_todo`]
+    ///
+    /// # Example
+    /// ```
+    /// use obsidian_parser::prelude::*;
+    /// use obsidian_parser::note::note_is_todo::TodoMode;
+    ///
+    /// let raw_text = "Plan:\n- [x] done\n- [ ] not done";
+    /// let note = NoteInMemory::from_string(raw_text).unwrap();
+    ///
+    /// assert!(!note.is_todo_with(TodoMode::Tag).unwrap());
+    /// assert!(note.is_todo_with(TodoMode::TagOrTasks).unwrap());
+    /// ```
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip(self), ret, fields(path = format!("{:?}", self.path()))))]
+    fn is_todo_with(&self, mode: TodoMode) -> Result<bool, Self::Error> {
+        if self.is_todo()? {
+            return Ok(true);
+        }
+
+        match mode {
+            TodoMode::Tag => Ok(false),
+            TodoMode::TagOrTasks => Ok(has_incomplete_tasks(&self.content()?)),
+        }
+    }
 }
 
 impl<N> NoteIsTodo for N
@@ -40,6 +83,8 @@ pub(crate) mod tests {
 
     const TEST_DATA_HAVE: &str = "---\ntags:\n- todo\n---\nSameData todo";
     const TEST_DATA_NOT_HAVE: &str = "---\ntags:\n- not_todo\n---\nSameData";
+    const TEST_DATA_TASKS: &str = "---\ntags:\n- not_todo\n---\n- [x] done\n- [ ] not done";
+    const TEST_DATA_DONE_TASKS: &str = "- [x] done\n- [X] done too\nText [ ]";
 
     fn is_todo<N>(note: &N) -> Result<(), N::Error>
     where
@@ -57,6 +102,34 @@ pub(crate) mod tests {
         Ok(())
     }
 
+    #[cfg_attr(feature = "tracing", tracing_test::traced_test)]
+    #[test]
+    fn incomplete_task() {
+        for line in [
+            "- [ ] task",
+            "* [ ] task",
+            "+ [ ] task",
+            "  - [ ] nested",
+            "1. [ ] task",
+            "2) [ ] task",
+            "-\t[ ] task",
+        ] {
+            assert!(has_incomplete_tasks(line), "{line}");
+        }
+
+        for line in [
+            "- [x] task",
+            "- [X] task",
+            "-[ ] task",
+            "[ ] task",
+            "text - [ ] task",
+            "1 [ ] task",
+            "- [] task",
+        ] {
+            assert!(!has_incomplete_tasks(line), "{line}");
+        }
+    }
+
     pub(crate) fn from_string_is_todo<N>() -> Result<(), N::Error>
     where
         N: NoteFromString + NoteTags,
@@ -75,6 +148,25 @@ pub(crate) mod tests {
         is_not_todo(&note)
     }
 
+    pub(crate) fn from_string_is_todo_with_tasks<N>() -> Result<(), N::Error>
+    where
+        N: NoteFromString + NoteTags,
+        N::Properties: DeserializeOwned,
+    {
+        let note = N::from_string(TEST_DATA_TASKS)?;
+        assert!(!note.is_todo_with(TodoMode::Tag)?);
+        assert!(note.is_todo_with(TodoMode::TagOrTasks)?);
+
+        let note = N::from_string(TEST_DATA_DONE_TASKS)?;
+        assert!(!note.is_todo_with(TodoMode::TagOrTasks)?);
+
+        let note = N::from_string(TEST_DATA_HAVE)?;
+        assert!(note.is_todo_with(TodoMode::Tag)?);
+        assert!(note.is_todo_with(TodoMode::TagOrTasks)?);
+
+        Ok(())
+    }
+
     pub(crate) fn from_reader_is_todo<N>() -> Result<(), N::Error>
     where
         N: NoteFromReader + NoteTags,
@@ -108,6 +200,22 @@ pub(crate) mod tests {
         is_todo(&note)
     }
 
+    pub(crate) fn from_file_is_todo_with_tasks<N>() -> Result<(), N::Error>
+    where
+        N: NoteFromFile + NoteTags,
+        N::Properties: DeserializeOwned,
+        N::Error: From<std::io::Error>,
+    {
+        let mut file = NamedTempFile::new().unwrap();
+        file.write_all(TEST_DATA_TASKS.as_bytes()).unwrap();
+
+        let note = N::from_file(file.path())?;
+        assert!(!note.is_todo_with(TodoMode::Tag)?);
+        assert!(note.is_todo_with(TodoMode::TagOrTasks)?);
+
+        Ok(())
+    }
+
     pub(crate) fn from_file_is_not_todo<N>() -> Result<(), N::Error>
     where
         N: NoteFromFile + NoteTags,
@@ -133,6 +241,12 @@ pub(crate) mod tests {
                 $impl_note
             );
 
+            impl_test_for_note!(
+                impl_from_string_is_todo_with_tasks,
+                from_string_is_todo_with_tasks,
+                $impl_note
+            );
+
             impl_test_for_note!(impl_from_reader_is_todo, from_reader_is_todo, $impl_note);
             impl_test_for_note!(
                 impl_from_reader_is_not_todo,
@@ -146,6 +260,11 @@ pub(crate) mod tests {
                 from_file_is_not_todo,
                 $impl_note
             );
+            impl_test_for_note!(
+                impl_from_file_is_todo_with_tasks,
+                from_file_is_todo_with_tasks,
+                $impl_note
+            );
         };
     }
 
@@ -0,0 +1,152 @@
+//! Validation and normalization of note names
+
+use thiserror::Error;
+
+/// Characters which are not allowed in note name
+///
+/// Path separators and characters forbidden by Obsidian (and Windows) in file names
+const FORBIDDEN_CHARS: &[char] = &['/', '\\', ':', '*', '?', '"', '<', '>', '|'];
+
+/// Name used by [`sanitize_note_name`] when nothing is left from name
+const UNTITLED_NOTE_NAME: &str = "Untitled";
+
+/// Error for unsafe note name
+///
+/// See [`validate_note_name`]. Flush of note with `..` in path fails with this error
+/// wrapped into [`std::io::Error`] (see [`std::io::Error::get_ref`])
+#[derive(Debug, Error, PartialEq, Eq, Clone)]
+#[error("Invalid note name: `{0}`")]
+pub struct InvalidNoteName(pub String);
+
+impl From<InvalidNoteName> for std::io::Error {
+    fn from(error: InvalidNoteName) -> Self {
+        Self::new(std::io::ErrorKind::InvalidInput, error)
+    }
+}
+
+#[inline]
+fn is_forbidden_char(c: char) -> bool {
+    FORBIDDEN_CHARS.contains(&c) || c.is_control()
+}
+
+/// Check note name is safe for filesystem
+///
+/// Note name (without extension) is invalid when it:
+/// - Is empty
+/// - Is `.` or `..` (path traversal)
+/// - Contains path separators (`/`, `\`) or other forbidden characters (`: * ? " < > |`)
+/// - Contains control characters
+///
+/// # Example
+/// ```
+/// use obsidian_parser::note::note_name::validate_note_name;
+///
+/// assert!(validate_note_name("My note").is_ok());
+/// assert!(validate_note_name("../secret").is_err());
+/// assert!(validate_note_name("a:b").is_err());
+/// ```
+pub fn validate_note_name(name: &str) -> Result<(), InvalidNoteName> {
+    let is_invalid =
+        name.trim().is_empty() || name == "." || name == ".." || name.contains(is_forbidden_char);
+
+    if is_invalid {
+        #[cfg(feature = "tracing")]
+        tracing::warn!("Invalid note name: {name}");
+
+        return Err(InvalidNoteName(name.to_string()));
+    }
+
+    Ok(())
+}
+
+/// Make note name safe for filesystem
+///
+/// # How does this work?
+/// 1. Replace forbidden characters (see [`validate_note_name`]) with `_`
+/// 2. Trim whitespaces and dots from both ends (it also removes `..`)
+/// 3. Use `Untitled` if nothing is left
+///
+/// Result always passes [`validate_note_name`]
+///
+/// # Example
+/// ```
+/// use obsidian_parser::note::note_name::sanitize_note_name;
+///
+/// assert_eq!(sanitize_note_name("Project: rust/parser"), "Project_ rust_parser");
+/// assert_eq!(sanitize_note_name("../../etc/passwd"), "_.._etc_passwd");
+/// assert_eq!(sanitize_note_name(".."), "Untitled");
+/// ```
+#[must_use]
+pub fn sanitize_note_name(name: &str) -> String {
+    let replaced: String = name
+        .chars()
+        .map(|c| if is_forbidden_char(c) { '_' } else { c })
+        .collect();
+
+    let trimmed = replaced.trim_matches(|c: char| c.is_whitespace() || c == '.');
+
+    if trimmed.is_empty() {
+        return UNTITLED_NOTE_NAME.to_string();
+    }
+
+    trimmed.to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[cfg_attr(feature = "tracing", tracing_test::traced_test)]
+    #[test]
+    fn validate_good_names() {
+        for name in ["note", "My super note", ".hidden", "заметка 💩", "a.b.c"] {
+            assert_eq!(validate_note_name(name), Ok(()), "{name}");
+        }
+    }
+
+    #[cfg_attr(feature = "tracing", tracing_test::traced_test)]
+    #[test]
+    fn validate_names_with_separators() {
+        for name in [
+            "dir/note",
+            "dir\\note",
+            "C:note",
+            "a|b",
+            "what?",
+            "line\nbreak",
+        ] {
+            assert_eq!(
+                validate_note_name(name),
+                Err(InvalidNoteName(name.to_string()))
+            );
+        }
+    }
+
+    #[cfg_attr(feature = "tracing", tracing_test::traced_test)]
+    #[test]
+    fn validate_traversal() {
+        for name in ["..", ".", "../note", "..\\note", "", "   "] {
+            assert!(validate_note_name(name).is_err(), "{name}");
+        }
+    }
+
+    #[cfg_attr(feature = "tracing", tracing_test::traced_test)]
+    #[test]
+    fn sanitize() {
+        assert_eq!(sanitize_note_name("note"), "note");
+        assert_eq!(sanitize_note_name("dir/note"), "dir_note");
+        assert_eq!(sanitize_note_name("a<b>c"), "a_b_c");
+        assert_eq!(sanitize_note_name("  note.  "), "note");
+        assert_eq!(sanitize_note_name("../../note"), "_.._note");
+        assert_eq!(sanitize_note_name("..."), "Untitled");
+        assert_eq!(sanitize_note_name(""), "Untitled");
+    }
+
+    #[cfg_attr(feature = "tracing", tracing_test::traced_test)]
+    #[test]
+    fn sanitized_is_valid() {
+        for name in ["..", "../x", "a:b", " . ", "\t", "ok"] {
+            assert_eq!(validate_note_name(&sanitize_note_name(name)), Ok(()));
+        }
+    }
+}
@@ -1,10 +1,13 @@
 //! On-disk representation of an Obsidian note file
 
+use crate::note::note_properties_mapping::{NotePropertiesMapping, parse_mapping};
 use crate::note::parser::{self, ResultParse, parse_note};
 use crate::note::{DefaultProperties, Note};
 use serde::de::DeserializeOwned;
 use std::borrow::Cow;
+use std::io::Read;
 use std::marker::PhantomData;
+use std::ops::Range;
 use std::path::Path;
 use std::path::PathBuf;
 use thiserror::Error;
@@ -48,6 +51,7 @@ where
 
 /// Errors for [`NoteOnDisk`]
 #[derive(Debug, Error)]
+#[non_exhaustive]
 pub enum Error {
     /// I/O operation failed (file reading, directory traversal, etc.)
     #[error("IO error: {0}")]
@@ -66,7 +70,7 @@ pub enum Error {
     /// incomplete yaml
     /// // Missing closing ---
     /// ```
-    #[error("Invalid frontmatter format")]
+    #[error("Invalid frontmatter format: {0}")]
     InvalidFormat(#[from] parser::Error),
 
     /// YAML parsing error in frontmatter properties
@@ -81,6 +85,12 @@ pub enum Error {
     #[error("YAML parsing error: {0}")]
     Yaml(#[from] serde_yml::Error),
 
+    /// TOML parsing error in frontmatter properties (`+++` delimiters)
+    #[cfg_attr(docsrs, doc(cfg(feature = "toml")))]
+    #[cfg(feature = "toml")]
+    #[error("TOML parsing error: {0}")]
+    Toml(#[from] toml::de::Error),
+
     /// Expected a file path
     ///
     /// # Example
@@ -112,17 +122,23 @@ where
         #[cfg(feature = "tracing")]
         tracing::trace!("Get properties from file");
 
+        #[cfg(feature = "tracing")]
+        crate::note::read_stats::record(crate::note::read_stats::ReadKind::Properties);
+
         let raw_text = std::fs::read_to_string(&self.path)?;
 
         let result = match parse_note(&raw_text)? {
             ResultParse::WithProperties {
                 content: _,
                 properties,
+                format,
             } => {
                 #[cfg(feature = "tracing")]
                 tracing::trace!("Frontmatter detected, parsing properties");
 
-                Some(Cow::Owned(serde_yml::from_str(properties)?))
+                Some(Cow::Owned(parser::deserialize_properties::<_, Error>(
+                    properties, format,
+                )?))
             }
             ResultParse::WithoutProperties => {
                 #[cfg(feature = "tracing")]
@@ -151,27 +167,64 @@ where
         #[cfg(feature = "tracing")]
         tracing::trace!("Get content from file");
 
+        let mut raw_text = String::new();
+        let range = self.read_content_into(&mut raw_text)?;
+
+        // Reuse buffer of file instead of copying content body
+        raw_text.truncate(range.end);
+        raw_text.drain(..range.start);
+
+        Ok(Cow::Owned(raw_text))
+    }
+
+    /// Reads and parses file only once
+    ///
+    /// # Errors
+    /// - [`Error::Yaml`] if properties can't be deserialized
+    /// - [`Error::IO`] on filesystem error
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip(self), fields(path = %self.path.display())))]
+    fn parts(&self) -> Result<(Option<Cow<'_, T>>, Cow<'_, str>), Error> {
+        #[cfg(feature = "tracing")]
+        tracing::trace!("Get properties and content from file");
+
+        #[cfg(feature = "tracing")]
+        crate::note::read_stats::record(crate::note::read_stats::ReadKind::Parsed);
+
         let raw_text = std::fs::read_to_string(&self.path)?;
 
-        let result = match parse_note(&raw_text)? {
+        match parse_note(&raw_text)? {
             ResultParse::WithProperties {
                 content,
-                properties: _,
-            } => {
-                #[cfg(feature = "tracing")]
-                tracing::trace!("Frontmatter detected, parsing properties");
+                properties,
+                format,
+            } => Ok((
+                Some(Cow::Owned(parser::deserialize_properties::<_, Error>(
+                    properties, format,
+                )?)),
+                Cow::Owned(content.to_string()),
+            )),
+            ResultParse::WithoutProperties => Ok((None, Cow::Owned(raw_text))),
+        }
+    }
 
-                content.to_string()
-            }
-            ResultParse::WithoutProperties => {
-                #[cfg(feature = "tracing")]
-                tracing::trace!("No frontmatter found, storing raw content");
+    /// Reads raw frontmatter from disk on every call
+    ///
+    /// # Errors
+    /// - [`Error::InvalidFormat`] if frontmatter is not closed
+    /// - [`Error::IO`] on filesystem error
+    fn frontmatter_raw(&self) -> Result<Option<Cow<'_, str>>, Error> {
+        Ok(super::read_frontmatter_raw::<Error>(&self.path)?.map(Cow::Owned))
+    }
 
-                raw_text
-            }
-        };
+    /// Reads whole file from disk on every call
+    ///
+    /// # Errors
+    /// - [`Error::IO`] on filesystem error
+    fn raw(&self) -> Result<Cow<'_, str>, Error> {
+        #[cfg(feature = "tracing")]
+        crate::note::read_stats::record(crate::note::read_stats::ReadKind::Content);
 
-        Ok(Cow::Owned(result))
+        Ok(Cow::Owned(std::fs::read_to_string(&self.path)?))
     }
 
     /// Get path to note
@@ -179,6 +232,44 @@ where
     fn path(&self) -> Option<Cow<'_, Path>> {
         Some(Cow::Borrowed(&self.path))
     }
+
+    /// Get modified time of file
+    #[cfg(not(target_family = "wasm"))]
+    fn modified(&self) -> Result<Option<std::time::SystemTime>, Self::Error> {
+        Ok(super::file_time(&self.path, std::fs::Metadata::modified)?)
+    }
+
+    /// Get creation time of file
+    #[cfg(not(target_family = "wasm"))]
+    fn created(&self) -> Result<Option<std::time::SystemTime>, Self::Error> {
+        Ok(super::file_time(&self.path, std::fs::Metadata::created)?)
+    }
+}
+
+impl<T> NotePropertiesMapping for NoteOnDisk<T>
+where
+    T: DeserializeOwned + Clone,
+{
+    /// Parses YAML frontmatter directly from disk into [`serde_yml::Mapping`]
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip(self), fields(path = %self.path.display())))]
+    fn properties_mapping(&self) -> Result<Option<serde_yml::Mapping>, Error> {
+        #[cfg(feature = "tracing")]
+        tracing::trace!("Get properties mapping from file");
+
+        #[cfg(feature = "tracing")]
+        crate::note::read_stats::record(crate::note::read_stats::ReadKind::Properties);
+
+        let raw_text = std::fs::read_to_string(&self.path)?;
+
+        match parse_note(&raw_text)? {
+            ResultParse::WithProperties {
+                content: _,
+                properties,
+                format,
+            } => Ok(Some(parse_mapping::<Error>(properties, format)?)),
+            ResultParse::WithoutProperties => Ok(None),
+        }
+    }
 }
 
 impl<T> NoteOnDisk<T>
@@ -190,6 +281,45 @@ where
     pub fn set_path(&mut self, path: PathBuf) {
         self.path = path;
     }
+
+    /// Reads note into `buf` and returns range of content body (without frontmatter) in it
+    ///
+    /// Unlike [`Note::content`] doesn't allocate new [`String`] for every call:
+    /// `buf` is cleared and reused. Useful for tight loops over large vaults
+    ///
+    /// # Example
+    /// ```no_run
+    /// use obsidian_parser::prelude::*;
+    ///
+    /// let note = NoteOnDisk::from_file_default("note.md").unwrap();
+    ///
+    /// let mut buf = String::new();
+    /// let range = note.read_content_into(&mut buf).unwrap();
+    /// println!("{}", &buf[range]);
+    /// ```
+    ///
+    /// # Errors
+    /// - [`Error::InvalidFormat`] if frontmatter is not closed
+    /// - [`Error::IO`] on filesystem error
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip(self, buf), fields(path = %self.path.display())))]
+    pub fn read_content_into(&self, buf: &mut String) -> Result<Range<usize>, Error> {
+        #[cfg(feature = "tracing")]
+        tracing::trace!("Read content from file into buffer");
+
+        #[cfg(feature = "tracing")]
+        crate::note::read_stats::record(crate::note::read_stats::ReadKind::Content);
+
+        buf.clear();
+        std::fs::File::open(&self.path)?.read_to_string(buf)?;
+
+        match parse_note(buf)? {
+            ResultParse::WithProperties { content, .. } => {
+                let start = content.as_ptr().addr() - buf.as_ptr().addr();
+                Ok(start..start + content.len())
+            }
+            ResultParse::WithoutProperties => Ok(0..buf.len()),
+        }
+    }
 }
 
 #[cfg(not(target_family = "wasm"))]
@@ -218,15 +348,80 @@ mod tests {
     use crate::note::NoteDefault;
     use crate::note::impl_tests::impl_test_for_note;
     use crate::note::note_aliases::tests::{from_file_have_aliases, from_file_have_not_aliases};
-    use crate::note::note_is_todo::tests::{from_file_is_not_todo, from_file_is_todo};
-    use crate::note::note_read::tests::{from_file, from_file_with_unicode};
+    use crate::note::note_is_todo::tests::{
+        from_file_is_not_todo, from_file_is_todo, from_file_is_todo_with_tasks,
+    };
+    use crate::note::note_properties_mapping::tests::impl_all_tests_properties_mapping;
+    use crate::note::note_read::tests::{
+        from_file, from_file_frontmatter_raw, from_file_modified, from_file_raw,
+        from_file_with_unicode,
+    };
     use crate::note::note_tags::tests::from_file_tags;
     use crate::note::note_write::tests::impl_all_tests_flush;
     use std::io::Write;
     use tempfile::NamedTempFile;
 
     impl_all_tests_flush!(NoteOnDisk);
+    impl_all_tests_properties_mapping!(NoteOnDisk);
     impl_test_for_note!(impl_from_file, from_file, NoteOnDisk);
+    impl_test_for_note!(impl_from_file_modified, from_file_modified, NoteOnDisk);
+    impl_test_for_note!(
+        impl_from_file_frontmatter_raw,
+        from_file_frontmatter_raw,
+        NoteOnDisk
+    );
+    impl_test_for_note!(impl_from_file_raw, from_file_raw, NoteOnDisk);
+
+    #[cfg_attr(feature = "tracing", tracing_test::traced_test)]
+    #[test]
+    fn read_content_into() {
+        let mut with_properties = NamedTempFile::new().unwrap();
+        with_properties
+            .write_all(b"---\ntopic: life\n---\nFirst content\n")
+            .unwrap();
+
+        let mut without_properties = NamedTempFile::new().unwrap();
+        without_properties.write_all(b"Second").unwrap();
+
+        let mut buf = String::new();
+        let expected = [
+            (with_properties.path(), "First content"),
+            (without_properties.path(), "Second"),
+            (with_properties.path(), "First content"),
+        ];
+
+        for (path, expected) in expected {
+            let note = NoteOnDisk::from_file_default(path).unwrap();
+            let range = note.read_content_into(&mut buf).unwrap();
+
+            assert_eq!(&buf[range.clone()], expected);
+            assert_eq!(buf[range], *note.content().unwrap());
+        }
+    }
+
+    #[cfg_attr(feature = "tracing", tracing_test::traced_test)]
+    #[test]
+    fn parts() {
+        let mut file = NamedTempFile::new().unwrap();
+        file.write_all(b"---\ntopic: life\n---\nContent").unwrap();
+
+        let note = NoteOnDisk::from_file_default(file.path()).unwrap();
+        let (properties, content) = note.parts().unwrap();
+
+        assert_eq!(properties, note.properties().unwrap());
+        assert_eq!(content, note.content().unwrap());
+    }
+
+    #[cfg_attr(feature = "tracing", tracing_test::traced_test)]
+    #[test]
+    fn parsed_without_properties() {
+        let mut file = NamedTempFile::new().unwrap();
+        file.write_all(b"Content").unwrap();
+
+        let note = NoteOnDisk::from_file_default(file.path()).unwrap();
+
+        assert_eq!(note.parsed().unwrap(), (None, "Content".to_string()));
+    }
     impl_test_for_note!(impl_from_file_tags, from_file_tags, NoteOnDisk);
 
     impl_test_for_note!(
@@ -236,6 +431,11 @@ mod tests {
     );
 
     impl_test_for_note!(impl_from_file_is_todo, from_file_is_todo, NoteOnDisk);
+    impl_test_for_note!(
+        impl_from_file_is_todo_with_tasks,
+        from_file_is_todo_with_tasks,
+        NoteOnDisk
+    );
     impl_test_for_note!(
         impl_from_file_is_not_todo,
         from_file_is_not_todo,
@@ -296,4 +496,34 @@ mod tests {
         assert_eq!(file.content().unwrap(), "DATA");
         assert_eq!(properties["time"], "now");
     }
+
+    #[cfg_attr(feature = "tracing", tracing_test::traced_test)]
+    #[test]
+    fn touch() {
+        let mut file = NamedTempFile::new().unwrap();
+        file.write_all(b"---\ntopic: life\n---\nContent").unwrap();
+
+        let past = std::time::SystemTime::now() - std::time::Duration::from_secs(3600);
+        file.as_file().set_modified(past).unwrap();
+
+        let note = NoteOnDisk::from_file_default(file.path()).unwrap();
+        note.touch().unwrap();
+
+        let modified = std::fs::metadata(file.path()).unwrap().modified().unwrap();
+        assert!(modified > past);
+        assert_eq!(
+            std::fs::read_to_string(file.path()).unwrap(),
+            "---\ntopic: life\n---\nContent"
+        );
+    }
+
+    #[cfg_attr(feature = "tracing", tracing_test::traced_test)]
+    #[test]
+    fn touch_removed_file() {
+        let file = NamedTempFile::new().unwrap();
+        let note = NoteOnDisk::from_file_default(file.path()).unwrap();
+        drop(file);
+
+        assert!(matches!(note.touch(), Err(Error::IO(_))));
+    }
 }
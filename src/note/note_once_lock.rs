@@ -5,6 +5,7 @@
 //!
 //! [`NoteOnceCell`]: crate::note::note_once_cell::NoteOnceCell
 
+use crate::note::note_properties_mapping::{NotePropertiesMapping, parse_mapping};
 use crate::note::parser::{self, ResultParse, parse_note};
 use crate::note::{DefaultProperties, Note};
 use serde::de::DeserializeOwned;
@@ -36,6 +37,7 @@ where
 
 /// Errors for [`NoteOnceLock`]
 #[derive(Debug, Error)]
+#[non_exhaustive]
 pub enum Error {
     /// I/O operation failed (file reading, directory traversal, etc.)
     #[error("IO error: {0}")]
@@ -54,7 +56,7 @@ pub enum Error {
     /// incomplete yaml
     /// // Missing closing ---
     /// ```
-    #[error("Invalid frontmatter format")]
+    #[error("Invalid frontmatter format: {0}")]
     InvalidFormat(#[from] parser::Error),
 
     /// YAML parsing error in frontmatter properties
@@ -69,6 +71,12 @@ pub enum Error {
     #[error("YAML parsing error: {0}")]
     Yaml(#[from] serde_yml::Error),
 
+    /// TOML parsing error in frontmatter properties (`+++` delimiters)
+    #[cfg_attr(docsrs, doc(cfg(feature = "toml")))]
+    #[cfg(feature = "toml")]
+    #[error("TOML parsing error: {0}")]
+    Toml(#[from] toml::de::Error),
+
     /// Expected a file path
     ///
     /// # Example
@@ -104,17 +112,23 @@ where
             return Ok(properties.as_ref().map(|value| Cow::Borrowed(value)));
         }
 
+        #[cfg(feature = "tracing")]
+        crate::note::read_stats::record(crate::note::read_stats::ReadKind::Properties);
+
         let raw_text = std::fs::read_to_string(&self.path)?;
 
         let result = match parse_note(&raw_text)? {
             ResultParse::WithProperties {
                 content: _,
                 properties,
+                format,
             } => {
                 #[cfg(feature = "tracing")]
                 tracing::trace!("Frontmatter detected, parsing properties");
 
-                Some(serde_yml::from_str(properties)?)
+                Some(parser::deserialize_properties::<_, Error>(
+                    properties, format,
+                )?)
             }
             ResultParse::WithoutProperties => {
                 #[cfg(feature = "tracing")]
@@ -148,13 +162,13 @@ where
             return Ok(Cow::Borrowed(content));
         }
 
+        #[cfg(feature = "tracing")]
+        crate::note::read_stats::record(crate::note::read_stats::ReadKind::Content);
+
         let raw_text = std::fs::read_to_string(&self.path)?;
 
         let result = match parse_note(&raw_text)? {
-            ResultParse::WithProperties {
-                content,
-                properties: _,
-            } => {
+            ResultParse::WithProperties { content, .. } => {
                 #[cfg(feature = "tracing")]
                 tracing::trace!("Frontmatter detected, parsing properties");
 
@@ -172,11 +186,69 @@ where
         Ok(Cow::Owned(result))
     }
 
+    /// Reads raw frontmatter from disk on every call
+    ///
+    /// # Errors
+    /// - [`Error::InvalidFormat`] if frontmatter is not closed
+    /// - [`Error::IO`] on filesystem error
+    fn frontmatter_raw(&self) -> Result<Option<Cow<'_, str>>, Error> {
+        Ok(super::read_frontmatter_raw::<Error>(&self.path)?.map(Cow::Owned))
+    }
+
+    /// Reads whole file from disk on every call
+    ///
+    /// # Errors
+    /// - [`Error::IO`] on filesystem error
+    fn raw(&self) -> Result<Cow<'_, str>, Error> {
+        #[cfg(feature = "tracing")]
+        crate::note::read_stats::record(crate::note::read_stats::ReadKind::Content);
+
+        Ok(Cow::Owned(std::fs::read_to_string(&self.path)?))
+    }
+
     /// Get path to note
     #[inline]
     fn path(&self) -> Option<Cow<'_, Path>> {
         Some(Cow::Borrowed(&self.path))
     }
+
+    /// Get modified time of file
+    #[cfg(not(target_family = "wasm"))]
+    fn modified(&self) -> Result<Option<std::time::SystemTime>, Self::Error> {
+        Ok(super::file_time(&self.path, std::fs::Metadata::modified)?)
+    }
+
+    /// Get creation time of file
+    #[cfg(not(target_family = "wasm"))]
+    fn created(&self) -> Result<Option<std::time::SystemTime>, Self::Error> {
+        Ok(super::file_time(&self.path, std::fs::Metadata::created)?)
+    }
+}
+
+impl<T> NotePropertiesMapping for NoteOnceLock<T>
+where
+    T: DeserializeOwned + Clone,
+{
+    /// Parses YAML frontmatter directly from disk into [`serde_yml::Mapping`]
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip(self), fields(path = %self.path.display())))]
+    fn properties_mapping(&self) -> Result<Option<serde_yml::Mapping>, Error> {
+        #[cfg(feature = "tracing")]
+        tracing::trace!("Get properties mapping from file");
+
+        #[cfg(feature = "tracing")]
+        crate::note::read_stats::record(crate::note::read_stats::ReadKind::Properties);
+
+        let raw_text = std::fs::read_to_string(&self.path)?;
+
+        match parse_note(&raw_text)? {
+            ResultParse::WithProperties {
+                content: _,
+                properties,
+                format,
+            } => Ok(Some(parse_mapping::<Error>(properties, format)?)),
+            ResultParse::WithoutProperties => Ok(None),
+        }
+    }
 }
 
 impl<T> NoteOnceLock<T>
@@ -188,6 +260,44 @@ where
     pub fn set_path(&mut self, path: PathBuf) {
         self.path = path;
     }
+
+    /// Clear cached content and properties, so they are read from disk again on next access
+    ///
+    /// Needs `&mut self`: cache can't be cleared while it is borrowed
+    /// (like by [`Note::content`])
+    ///
+    /// # Example
+    /// ```no_run
+    /// use obsidian_parser::prelude::*;
+    ///
+    /// let mut note: NoteOnceLock = NoteOnceLock::from_file("note.md").unwrap();
+    /// println!("{}", note.content().unwrap());
+    ///
+    /// // File is changed on disk
+    /// note.reload().unwrap();
+    /// println!("{}", note.content().unwrap());
+    /// ```
+    ///
+    /// # Errors
+    /// [`Error::IsNotFile`] if file doesn't exist anymore. Cache is cleared anyway
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip(self), fields(path = %self.path.display())))]
+    pub fn reload(&mut self) -> Result<(), Error> {
+        self.content.take();
+        self.properties.take();
+
+        if !self.path.is_file() {
+            return Err(Error::IsNotFile(self.path.clone()));
+        }
+
+        Ok(())
+    }
+
+    /// Content or properties are cached
+    #[must_use]
+    #[inline]
+    pub fn is_cached(&self) -> bool {
+        self.content.get().is_some() || self.properties.get().is_some()
+    }
 }
 
 #[cfg(not(target_family = "wasm"))]
@@ -217,15 +327,29 @@ mod tests {
     use crate::note::NoteDefault;
     use crate::note::impl_tests::impl_test_for_note;
     use crate::note::note_aliases::tests::{from_file_have_aliases, from_file_have_not_aliases};
-    use crate::note::note_is_todo::tests::{from_file_is_not_todo, from_file_is_todo};
-    use crate::note::note_read::tests::{from_file, from_file_with_unicode};
+    use crate::note::note_is_todo::tests::{
+        from_file_is_not_todo, from_file_is_todo, from_file_is_todo_with_tasks,
+    };
+    use crate::note::note_properties_mapping::tests::impl_all_tests_properties_mapping;
+    use crate::note::note_read::tests::{
+        from_file, from_file_frontmatter_raw, from_file_modified, from_file_raw,
+        from_file_with_unicode,
+    };
     use crate::note::note_tags::tests::from_file_tags;
     use crate::note::note_write::tests::impl_all_tests_flush;
     use std::io::Write;
     use tempfile::NamedTempFile;
 
     impl_all_tests_flush!(NoteOnceLock);
+    impl_all_tests_properties_mapping!(NoteOnceLock);
     impl_test_for_note!(impl_from_file, from_file, NoteOnceLock);
+    impl_test_for_note!(impl_from_file_modified, from_file_modified, NoteOnceLock);
+    impl_test_for_note!(
+        impl_from_file_frontmatter_raw,
+        from_file_frontmatter_raw,
+        NoteOnceLock
+    );
+    impl_test_for_note!(impl_from_file_raw, from_file_raw, NoteOnceLock);
     impl_test_for_note!(impl_from_file_tags, from_file_tags, NoteOnceLock);
 
     impl_test_for_note!(
@@ -235,6 +359,11 @@ mod tests {
     );
 
     impl_test_for_note!(impl_from_file_is_todo, from_file_is_todo, NoteOnceLock);
+    impl_test_for_note!(
+        impl_from_file_is_todo_with_tasks,
+        from_file_is_todo_with_tasks,
+        NoteOnceLock
+    );
     impl_test_for_note!(
         impl_from_file_is_not_todo,
         from_file_is_not_todo,
@@ -295,4 +424,40 @@ mod tests {
         assert_eq!(file.content().unwrap(), "DATA");
         assert_eq!(properties["time"], "now");
     }
+
+    #[cfg_attr(feature = "tracing", tracing_test::traced_test)]
+    #[test]
+    fn reload() {
+        let mut test_file = NamedTempFile::new().unwrap();
+        test_file.write_all(b"---\ntopic: life\n---\nOld").unwrap();
+
+        let mut note = NoteOnceLock::from_file_default(test_file.path()).unwrap();
+        assert!(!note.is_cached());
+
+        assert_eq!(note.content().unwrap(), "Old");
+        assert_eq!(note.properties().unwrap().unwrap()["topic"], "life");
+        assert!(note.is_cached());
+
+        std::fs::write(test_file.path(), "---\ntopic: work\n---\nNew").unwrap();
+        assert_eq!(note.content().unwrap(), "Old");
+
+        note.reload().unwrap();
+        assert!(!note.is_cached());
+        assert_eq!(note.content().unwrap(), "New");
+        assert_eq!(note.properties().unwrap().unwrap()["topic"], "work");
+    }
+
+    #[cfg_attr(feature = "tracing", tracing_test::traced_test)]
+    #[test]
+    fn reload_removed_file() {
+        let test_file = NamedTempFile::new().unwrap();
+        let mut note = NoteOnceLock::from_file_default(test_file.path()).unwrap();
+        note.content().unwrap();
+
+        let path = test_file.path().to_path_buf();
+        drop(test_file);
+
+        assert!(matches!(note.reload(), Err(Error::IsNotFile(error_path)) if error_path == path));
+        assert!(!note.is_cached());
+    }
 }
@@ -0,0 +1,179 @@
+//! Impl trait [`NotePropertiesMapping`]
+
+use super::Note;
+use super::parser::{FromFrontmatterError, FrontmatterFormat, deserialize_properties};
+use serde_yml::Mapping;
+use std::borrow::Cow;
+
+/// Trait for get frontmatter as [`Mapping`] without concrete [`Note::Properties`] type
+///
+/// Frontmatter is parsed again from raw YAML, so all fields are available even if
+/// [`Note::Properties`] doesn't declare them. Order of keys is preserved.
+///
+/// # Example
+/// ```
+/// use obsidian_parser::prelude::*;
+/// use serde::Deserialize;
+///
+/// #[derive(Deserialize, Clone)]
+/// struct NoteProperties {
+///     title: String,
+/// }
+///
+/// let raw_text = "---\ntitle: Example\npriority: 3\n---\nContent";
+/// let note: NoteInMemory<NoteProperties> = NoteInMemory::from_string(raw_text).unwrap();
+///
+/// let mapping = note.properties_mapping().unwrap().unwrap();
+/// assert_eq!(mapping["priority"], 3);
+/// ```
+pub trait NotePropertiesMapping: Note {
+    /// Returns frontmatter as ordered YAML [`Mapping`]
+    ///
+    /// Returns [`None`] if the note has no properties
+    fn properties_mapping(&self) -> Result<Option<Mapping>, Self::Error>;
+
+    /// Same as [`Note::properties`], but returns [`None`] for empty frontmatter too
+    ///
+    /// [`Note::properties`] returns properties deserialized from empty mapping for
+    /// frontmatter without fields (`---\n---`, only whitespaces or `null`).
+    /// Use this method if such notes should be treated as notes without frontmatter
+    ///
+    /// # Example
+    /// ```
+    /// use obsidian_parser::prelude::*;
+    ///
+    /// let note = NoteInMemory::from_string_default("---\n---\nContent").unwrap();
+    ///
+    /// assert!(note.properties().unwrap().unwrap().is_empty());
+    /// assert!(note.non_empty_properties().unwrap().is_none());
+    /// ```
+    fn non_empty_properties(&self) -> Result<Option<Cow<'_, Self::Properties>>, Self::Error> {
+        if self
+            .properties_mapping()?
+            .is_none_or(|mapping| mapping.is_empty())
+        {
+            return Ok(None);
+        }
+
+        self.properties()
+    }
+}
+
+/// Parse raw frontmatter to [`Mapping`]
+pub(crate) fn parse_mapping<E>(
+    raw_properties: &str,
+    format: FrontmatterFormat,
+) -> Result<Mapping, E>
+where
+    E: FromFrontmatterError,
+{
+    deserialize_properties(raw_properties, format)
+}
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use super::*;
+    use crate::note::{DefaultProperties, NoteFromFile, NoteFromString, note_tags::NoteTags};
+    use serde::Deserialize;
+    use std::io::Write;
+    use tempfile::NamedTempFile;
+
+    const TEST_DATA: &str = "---\ntitle: Example\nextra:\n- one\n- two\npriority: 3\n---\nContent";
+
+    #[derive(Debug, Deserialize, Clone, Default)]
+    pub(crate) struct TitleProperties {
+        #[allow(dead_code)]
+        title: String,
+    }
+
+    fn properties_mapping<N>(note: &N) -> Result<(), N::Error>
+    where
+        N: NotePropertiesMapping,
+    {
+        let mapping = note.properties_mapping()?.unwrap();
+
+        let keys: Vec<_> = mapping.keys().filter_map(|key| key.as_str()).collect();
+        assert_eq!(keys, ["title", "extra", "priority"]);
+        assert_eq!(mapping["title"], "Example");
+        assert_eq!(mapping["extra"][1], "two");
+        assert_eq!(mapping["priority"], 3);
+
+        Ok(())
+    }
+
+    pub(crate) fn from_string_properties_mapping<N>() -> Result<(), N::Error>
+    where
+        N: NoteFromString<Properties = TitleProperties> + NotePropertiesMapping,
+    {
+        let note = N::from_string(TEST_DATA)?;
+        properties_mapping(&note)?;
+
+        let note = N::from_string("Content")?;
+        assert_eq!(note.properties_mapping()?, None);
+
+        Ok(())
+    }
+
+    pub(crate) fn from_file_properties_mapping<N>() -> Result<(), N::Error>
+    where
+        N: NoteFromFile<Properties = TitleProperties> + NotePropertiesMapping,
+        N::Error: From<std::io::Error>,
+    {
+        let mut file = NamedTempFile::new().unwrap();
+        file.write_all(TEST_DATA.as_bytes()).unwrap();
+
+        let note = N::from_file(file.path())?;
+        properties_mapping(&note)
+    }
+
+    pub(crate) fn from_file_empty_frontmatter<N>() -> Result<(), N::Error>
+    where
+        N: NoteFromFile<Properties = DefaultProperties> + NotePropertiesMapping + NoteTags,
+        N::Error: From<std::io::Error>,
+    {
+        for data in [
+            "---\n---\nContent",
+            "---\n  \n\n---\nContent",
+            "---\n~\n---\nContent",
+        ] {
+            let mut file = NamedTempFile::new().unwrap();
+            file.write_all(data.as_bytes()).unwrap();
+
+            let note = N::from_file(file.path())?;
+            assert!(note.properties()?.unwrap().is_empty(), "{data:?}");
+            assert!(note.properties_mapping()?.unwrap().is_empty(), "{data:?}");
+            assert!(note.non_empty_properties()?.is_none(), "{data:?}");
+            assert!(note.tags()?.is_empty(), "{data:?}");
+            assert_eq!(note.content()?, "Content");
+        }
+
+        let mut file = NamedTempFile::new().unwrap();
+        file.write_all(b"---\ntopic: life\n---\nContent").unwrap();
+
+        let note = N::from_file(file.path())?;
+        assert_eq!(note.non_empty_properties()?.unwrap()["topic"], "life");
+
+        Ok(())
+    }
+
+    macro_rules! impl_all_tests_properties_mapping {
+        ($impl_note:ident) => {
+            #[allow(unused_imports)]
+            use $crate::note::note_properties_mapping::tests::*;
+
+            impl_test_for_note!(
+                impl_from_file_empty_frontmatter,
+                from_file_empty_frontmatter,
+                $impl_note
+            );
+
+            impl_test_for_note!(
+                impl_from_file_properties_mapping,
+                from_file_properties_mapping,
+                $impl_note<TitleProperties>
+            );
+        };
+    }
+
+    pub(crate) use impl_all_tests_properties_mapping;
+}
@@ -23,7 +23,28 @@ where
     Self::Error: From<std::io::Error>,
 {
     /// Parses an Obsidian note from a reader
+    ///
+    /// # Errors
+    /// I/O error with [`std::io::ErrorKind::InvalidData`] if data is not valid UTF-8.
+    /// See [`NoteFromReader::from_reader_lossy`]
     fn from_reader(read: &mut impl Read) -> Result<Self, Self::Error>;
+
+    /// Same as [`NoteFromReader::from_reader`], but invalid UTF-8 sequences
+    /// (Latin-1 text, binary data) are replaced with `U+FFFD`
+    ///
+    /// Valid UTF-8 is not copied again after reading
+    ///
+    /// # Example
+    /// ```
+    /// use obsidian_parser::prelude::*;
+    /// use std::io::Cursor;
+    ///
+    /// let mut reader = Cursor::new(b"Caf\xE9 [[Link]]");
+    /// let note: NoteInMemory = NoteInMemory::from_reader_lossy(&mut reader).unwrap();
+    ///
+    /// assert_eq!(note.content().unwrap(), "Caf\u{FFFD} [[Link]]");
+    /// ```
+    fn from_reader_lossy(read: &mut impl Read) -> Result<Self, Self::Error>;
 }
 
 impl<N> NoteFromReader for N
@@ -42,6 +63,24 @@ where
 
         Self::from_string(&buf)
     }
+
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip_all))]
+    fn from_reader_lossy(read: &mut impl Read) -> Result<Self, Self::Error> {
+        #[cfg(feature = "tracing")]
+        tracing::trace!("Parse obsidian file from reader (lossy)");
+
+        let mut buf = Vec::new();
+        read.read_to_end(&mut buf)?;
+
+        let text = String::from_utf8_lossy(&buf);
+
+        #[cfg(feature = "tracing")]
+        if matches!(text, std::borrow::Cow::Owned(_)) {
+            tracing::warn!("Invalid UTF-8 replaced with U+FFFD");
+        }
+
+        Self::from_string(text)
+    }
 }
 
 /// Trait for parses an Obsidian note from a file
@@ -90,6 +129,15 @@ Two test data";
 
     const SPACE_DATA: &str = "  ---\ntest: test-data\n---\n";
 
+    #[cfg(feature = "toml")]
+    const TOML_DATA: &str = "+++\n\
+topic = \"life\"\n\
+created = \"2025-03-16\"\n\
++++\n\
+Test data\n\
+---\n\
+Two test data";
+
     fn test_data<T>(note: T, path: Option<PathBuf>) -> Result<(), T::Error>
     where
         T: Note<Properties = DefaultProperties>,
@@ -175,6 +223,26 @@ Two test data";
         Ok(())
     }
 
+    pub(crate) fn from_reader_invalid_utf8<T>() -> Result<(), T::Error>
+    where
+        T: NoteFromReader<Properties = DefaultProperties>,
+        T::Error: From<std::io::Error> + 'static,
+    {
+        let data: &[u8] = b"---\ntopic: caf\xE9\n---\nData \xFF";
+
+        let error = T::from_reader(&mut Cursor::new(data)).err().unwrap();
+        assert!(is_error::<std::io::Error>(error));
+
+        let file = T::from_reader_lossy(&mut Cursor::new(data))?;
+        assert_eq!(file.properties()?.unwrap()["topic"], "caf\u{FFFD}");
+        assert_eq!(file.content()?, "Data \u{FFFD}");
+
+        let file = T::from_reader_lossy(&mut Cursor::new(TEST_DATA))?;
+        test_data(file, None)?;
+
+        Ok(())
+    }
+
     pub(crate) fn from_reader_without_properties<T>() -> Result<(), T::Error>
     where
         T: NoteFromReader<Properties = DefaultProperties>,
@@ -243,6 +311,18 @@ Two test data";
         Ok(())
     }
 
+    #[cfg(feature = "toml")]
+    pub(crate) fn from_string_toml<T>() -> Result<(), T::Error>
+    where
+        T: NoteFromString<Properties = DefaultProperties>,
+        T::Error: From<std::io::Error>,
+    {
+        let file = T::from_string(TOML_DATA)?;
+
+        test_data(file, None)?;
+        Ok(())
+    }
+
     pub(crate) fn from_string_without_properties<T>() -> Result<(), T::Error>
     where
         T: NoteFromString<Properties = DefaultProperties>,
@@ -313,6 +393,84 @@ Two test data";
         Ok(())
     }
 
+    pub(crate) fn from_file_modified<T>() -> Result<(), T::Error>
+    where
+        T: NoteFromFile<Properties = DefaultProperties>,
+        T::Error: From<std::io::Error>,
+    {
+        let mut temp_file = NamedTempFile::new().unwrap();
+        temp_file.write_all(TEST_DATA.as_bytes()).unwrap();
+
+        let past = std::time::SystemTime::now() - std::time::Duration::from_secs(3600);
+        temp_file.as_file().set_modified(past).unwrap();
+
+        let file = T::from_file(temp_file.path()).unwrap();
+
+        assert_eq!(file.modified()?, Some(past));
+        if let Ok(created) = std::fs::metadata(temp_file.path())?.created() {
+            assert_eq!(file.created()?, Some(created));
+        }
+
+        drop(temp_file);
+        assert!(file.modified().is_err());
+
+        Ok(())
+    }
+
+    pub(crate) fn from_file_frontmatter_raw<T>() -> Result<(), T::Error>
+    where
+        T: NoteFromFile<Properties = DefaultProperties>,
+        T::Error: From<std::io::Error>,
+    {
+        let mut temp_file = NamedTempFile::new().unwrap();
+        temp_file
+            .write_all(b"---\ntopic: life # comment\ntags: [a, b]\n---\nText")
+            .unwrap();
+
+        let file = T::from_file(temp_file.path())?;
+        assert_eq!(
+            file.frontmatter_raw()?.unwrap(),
+            "topic: life # comment\ntags: [a, b]"
+        );
+        assert_eq!(file.properties()?.unwrap()["topic"], "life");
+
+        std::fs::write(temp_file.path(), "Text").unwrap();
+        let file = T::from_file(temp_file.path())?;
+        assert_eq!(file.frontmatter_raw()?, None);
+
+        Ok(())
+    }
+
+    pub(crate) fn from_file_raw<T>() -> Result<(), T::Error>
+    where
+        T: NoteFromFile<Properties = DefaultProperties>,
+        T::Error: From<std::io::Error>,
+    {
+        let mut temp_file = NamedTempFile::new().unwrap();
+        temp_file.write_all(TEST_DATA.as_bytes()).unwrap();
+
+        let file = T::from_file(temp_file.path())?;
+        assert_eq!(file.raw()?, TEST_DATA);
+        assert_ne!(file.content()?, TEST_DATA);
+
+        Ok(())
+    }
+
+    #[cfg(feature = "toml")]
+    pub(crate) fn from_file_toml<T>() -> Result<(), T::Error>
+    where
+        T: NoteFromFile<Properties = DefaultProperties>,
+        T::Error: From<std::io::Error>,
+    {
+        let mut temp_file = NamedTempFile::new().unwrap();
+        temp_file.write_all(TOML_DATA.as_bytes()).unwrap();
+
+        let file = T::from_file(temp_file.path()).unwrap();
+
+        test_data(file, Some(temp_file.path().to_path_buf()))?;
+        Ok(())
+    }
+
     pub(crate) fn from_file_note_name<T>() -> Result<(), T::Error>
     where
         T: NoteFromFile<Properties = DefaultProperties>,
@@ -413,6 +571,11 @@ Two test data";
             use $crate::note::note_read::tests::*;
 
             impl_test_for_note!(impl_from_reader, from_reader, $impl_note);
+            impl_test_for_note!(
+                impl_from_reader_invalid_utf8,
+                from_reader_invalid_utf8,
+                $impl_note
+            );
 
             impl_test_for_note!(
                 impl_from_reader_without_properties,
@@ -449,6 +612,9 @@ Two test data";
 
             impl_test_for_note!(impl_from_string, from_string, $impl_note);
 
+            #[cfg(feature = "toml")]
+            impl_test_for_note!(impl_from_string_toml, from_string_toml, $impl_note);
+
             impl_test_for_note!(
                 impl_from_string_without_properties,
                 from_string_without_properties,
@@ -484,6 +650,16 @@ Two test data";
 
             impl_test_for_note!(impl_from_file, from_file, $impl_note);
             impl_test_for_note!(impl_from_file_note_name, from_file_note_name, $impl_note);
+            impl_test_for_note!(impl_from_file_modified, from_file_modified, $impl_note);
+            impl_test_for_note!(
+                impl_from_file_frontmatter_raw,
+                from_file_frontmatter_raw,
+                $impl_note
+            );
+            impl_test_for_note!(impl_from_file_raw, from_file_raw, $impl_note);
+
+            #[cfg(feature = "toml")]
+            impl_test_for_note!(impl_from_file_toml, from_file_toml, $impl_note);
 
             impl_test_for_note!(
                 impl_from_file_without_properties,
@@ -8,6 +8,13 @@ use super::{DefaultProperties, Note};
 pub trait NoteTags: Note {
     /// Return tags from Note
     ///
+    /// Nested tags (`#project/rust`) are returned as is
+    ///
+    /// Inline tag must start a word: `#` inside a word (like fragment in `example.com/page#section`)
+    /// is not a tag
+    ///
+    /// Emoji sequences with joiners and modifiers (`#👨‍💻`, `#👍🏽`) are kept whole
+    ///
     /// # Example
     /// ```
     /// use obsidian_parser::prelude::*;
@@ -21,6 +28,60 @@ pub trait NoteTags: Note {
     fn tags(&self) -> Result<Vec<String>, Self::Error>;
 }
 
+/// Returns tag and all its parents for nested tag
+///
+/// # Example
+/// ```
+/// use obsidian_parser::note::note_tags::tag_ancestors;
+///
+/// assert_eq!(tag_ancestors("project/rust"), ["project", "project/rust"]);
+/// assert_eq!(tag_ancestors("simple"), ["simple"]);
+/// ```
+#[must_use]
+pub fn tag_ancestors(tag: &str) -> Vec<&str> {
+    tag.match_indices('/')
+        .map(|(index, _)| &tag[..index])
+        .chain(std::iter::once(tag))
+        .filter(|ancestor| !ancestor.is_empty() && !ancestor.ends_with('/'))
+        .collect()
+}
+
+/// Char joins or modifies emoji in multi-codepoint sequence and is not emoji itself
+///
+/// - Zero width joiner (`👨‍💻`)
+/// - Variation selector-16 (`❤️`)
+/// - Combining enclosing keycap (`1️⃣`)
+/// - Tag characters of subdivision flags (`🏴󠁧󠁢󠁳󠁣󠁴󠁿`)
+///
+/// Skin tone modifiers (`👍🏽`) are emoji already
+const fn is_emoji_component(c: char) -> bool {
+    matches!(
+        c,
+        '\u{200D}' | '\u{FE0F}' | '\u{20E3}' | '\u{E0020}'..='\u{E007F}'
+    )
+}
+
+/// Keys of frontmatter with tags. Obsidian accepts both
+const FRONTMATTER_KEYS: [&str; 2] = ["tags", "tag"];
+
+/// Parse tags from value of frontmatter key
+///
+/// - List: used as is
+/// - String: split on commas and whitespaces (`tags: rust, parsing`)
+/// - Null: no tags
+fn tags_from_value(value: &serde_yml::Value) -> Result<Vec<String>, serde_yml::Error> {
+    match value {
+        serde_yml::Value::Null => Ok(Vec::new()),
+        serde_yml::Value::String(tags) => Ok(tags
+            .split(|c: char| c == ',' || c.is_whitespace())
+            .map(|tag| tag.trim_start_matches('#'))
+            .filter(|tag| !tag.is_empty())
+            .map(ToString::to_string)
+            .collect()),
+        value => serde_yml::from_value(value.clone()),
+    }
+}
+
 impl<N> NoteTags for N
 where
     N: Note<Properties = DefaultProperties>,
@@ -31,14 +92,22 @@ where
         #[cfg(feature = "tracing")]
         tracing::trace!("Get tags");
 
-        let properties = self.properties()?.unwrap_or_default();
-        let tags_from_properties: Vec<String> = match properties.get("tags") {
-            Some(value) => serde_yml::from_value(value.clone())?,
-            None => Vec::default(),
-        };
+        let properties = self.properties_or_default()?;
+        let mut tags_from_properties = Vec::new();
+        for key in FRONTMATTER_KEYS {
+            if let Some(value) = properties.get(key) {
+                tags_from_properties.extend(tags_from_value(value)?);
+            }
+        }
 
-        let check_good =
-            |c: char| c.is_alphanumeric() || (is_emoji(c) && c != '#') || c == '_' || c == '-';
+        let check_good = |c: char| {
+            c.is_alphanumeric()
+                || (is_emoji(c) && c != '#')
+                || is_emoji_component(c)
+                || c == '_'
+                || c == '-'
+                || c == '/'
+        };
 
         let content = self.content()?;
         let tags_from_content: Vec<_> = content
@@ -48,9 +117,10 @@ where
             .map(|word| word[1..].to_string())
             .filter_map(|tag| {
                 let end_index = tag.find(|c| !check_good(c)).unwrap_or(tag.len());
+                let tag = tag[..end_index].trim_end_matches('/');
 
-                if end_index > 0 {
-                    return Some(tag[..end_index].to_string());
+                if !tag.is_empty() {
+                    return Some(tag.to_string());
                 }
 
                 None
@@ -69,7 +139,7 @@ pub(crate) mod tests {
     use std::io::{Cursor, Write};
     use tempfile::NamedTempFile;
 
-    const TEST_STR_DATA: &str = "---\ntags:\n- my_tag\n---\nSameData #super_tag ##no_tag and #warning_tag! #two-tag #kek;d #dfds# #all, #татар #d😭";
+    const TEST_STR_DATA: &str = "---\ntags:\n- my_tag\n---\nSameData #super_tag ##no_tag and #warning_tag! #two-tag #kek;d #dfds# #all, #татар #d😭 #project/rust/ #/ #a/b/c";
     const TEST_ARRAY_DATA: &[&str] = &[
         "my_tag",
         "super_tag",
@@ -80,6 +150,8 @@ pub(crate) mod tests {
         "all",
         "татар",
         "d😭",
+        "project/rust",
+        "a/b/c",
     ];
 
     pub(crate) fn tags<N>(note: &N) -> Result<(), N::Error>
@@ -124,6 +196,78 @@ pub(crate) mod tests {
         tags(&note)
     }
 
+    #[cfg_attr(feature = "tracing", tracing_test::traced_test)]
+    #[test]
+    fn frontmatter_tags_string() {
+        let note =
+            NoteInMemory::from_string_default("---\ntags: rust, parsing  cli\n---\n#content")
+                .unwrap();
+
+        assert_eq!(note.tags().unwrap(), ["rust", "parsing", "cli", "content"]);
+    }
+
+    #[cfg_attr(feature = "tracing", tracing_test::traced_test)]
+    #[test]
+    fn frontmatter_tags_list_and_tag_key() {
+        let note = NoteInMemory::from_string_default(
+            "---\ntags:\n- rust\n- parsing\ntag: single\n---\nText",
+        )
+        .unwrap();
+
+        assert_eq!(note.tags().unwrap(), ["rust", "parsing", "single"]);
+    }
+
+    #[cfg_attr(feature = "tracing", tracing_test::traced_test)]
+    #[test]
+    fn frontmatter_tags_missing_or_empty() {
+        let note = NoteInMemory::from_string_default("---\ntopic: life\n---\nText").unwrap();
+        assert!(note.tags().unwrap().is_empty());
+
+        let note = NoteInMemory::from_string_default("---\ntags:\n---\nText").unwrap();
+        assert!(note.tags().unwrap().is_empty());
+    }
+
+    #[cfg_attr(feature = "tracing", tracing_test::traced_test)]
+    #[test]
+    fn frontmatter_tags_invalid() {
+        let note =
+            NoteInMemory::from_string_default("---\ntags:\n  key: value\n---\nText").unwrap();
+
+        assert!(note.tags().is_err());
+    }
+
+    #[cfg_attr(feature = "tracing", tracing_test::traced_test)]
+    #[test]
+    fn url_fragment_is_not_tag() {
+        let note = NoteInMemory::from_string_default(
+            "See https://example.com/page#section and [docs](https://docs.rs/#intro) #real",
+        )
+        .unwrap();
+
+        assert_eq!(note.tags().unwrap(), ["real"]);
+    }
+
+    #[cfg_attr(feature = "tracing", tracing_test::traced_test)]
+    #[test]
+    fn emoji_sequence_tags() {
+        let note =
+            NoteInMemory::from_string_default("#👨‍💻 #👍🏽 #❤️! #dev👩‍🔬/rust #🏳️‍🌈 #1️⃣ #🏴󠁧󠁢󠁳󠁣󠁴󠁿").unwrap();
+
+        assert_eq!(
+            note.tags().unwrap(),
+            ["👨‍💻", "👍🏽", "❤️", "dev👩‍🔬/rust", "🏳️‍🌈", "1️⃣", "🏴󠁧󠁢󠁳󠁣󠁴󠁿"]
+        );
+    }
+
+    #[cfg_attr(feature = "tracing", tracing_test::traced_test)]
+    #[test]
+    fn test_tag_ancestors() {
+        assert_eq!(tag_ancestors("a/b/c"), ["a", "a/b", "a/b/c"]);
+        assert_eq!(tag_ancestors("tag"), ["tag"]);
+        assert_eq!(tag_ancestors("a//b"), ["a", "a//b"]);
+        assert!(tag_ancestors("").is_empty());
+    }
+
     macro_rules! impl_all_tests_tags {
         ($impl_note:path) => {
             #[allow(unused_imports)]
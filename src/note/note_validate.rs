@@ -0,0 +1,133 @@
+//! Impl trait [`NoteValidate`]
+
+use super::Note;
+use serde::Serialize;
+
+/// Compiled JSON Schema for [`NoteValidate::validate_frontmatter`]
+///
+/// Create with [`jsonschema::validator_for`]
+pub type Schema = jsonschema::Validator;
+
+/// Trait for validate frontmatter of note against JSON Schema
+///
+/// # Example
+/// ```
+/// use obsidian_parser::prelude::*;
+/// use serde_json::json;
+///
+/// let schema = jsonschema::validator_for(&json!({
+///     "type": "object",
+///     "required": ["title"]
+/// }))
+/// .unwrap();
+///
+/// let note = NoteInMemory::from_string_default("---\ntopic: life\n---\nData").unwrap();
+/// let violations = note.validate_frontmatter(&schema).unwrap();
+///
+/// assert_eq!(violations.len(), 1);
+/// ```
+pub trait NoteValidate: Note {
+    /// Validate frontmatter (converted to JSON) against `schema`
+    ///
+    /// Returns messages of violations. Empty [`Vec`] means frontmatter is valid.
+    /// Note without frontmatter is validated as empty object (`{}`)
+    fn validate_frontmatter(&self, schema: &Schema) -> Result<Vec<String>, Self::Error>;
+}
+
+impl<N> NoteValidate for N
+where
+    N: Note,
+    N::Properties: Serialize,
+{
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip_all, fields(path = format!("{:?}", self.path()))))]
+    fn validate_frontmatter(&self, schema: &Schema) -> Result<Vec<String>, Self::Error> {
+        let instance = match self.properties()? {
+            Some(properties) => match serde_json::to_value(&*properties) {
+                Ok(instance) => instance,
+                Err(error) => {
+                    #[cfg(feature = "tracing")]
+                    tracing::warn!("Frontmatter can't be converted to JSON: {error}");
+
+                    return Ok(vec![format!(
+                        "Frontmatter can't be converted to JSON: {error}"
+                    )]);
+                }
+            },
+            None => serde_json::Value::Object(serde_json::Map::new()),
+        };
+
+        let violations = schema
+            .iter_errors(&instance)
+            .map(|error| {
+                let path = error.instance_path().to_string();
+
+                if path.is_empty() {
+                    error.to_string()
+                } else {
+                    format!("{path}: {error}")
+                }
+            })
+            .collect();
+
+        Ok(violations)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::prelude::{NoteDefault, NoteInMemory};
+    use serde_json::json;
+
+    fn title_schema() -> Schema {
+        jsonschema::validator_for(&json!({
+            "type": "object",
+            "required": ["title"],
+            "properties": {
+                "title": { "type": "string" },
+                "tags": { "type": "array", "items": { "type": "string" } }
+            }
+        }))
+        .unwrap()
+    }
+
+    #[cfg_attr(feature = "tracing", tracing_test::traced_test)]
+    #[test]
+    fn valid_frontmatter() {
+        let note =
+            NoteInMemory::from_string_default("---\ntitle: Note\ntags:\n- a\n---\nData").unwrap();
+
+        assert!(
+            note.validate_frontmatter(&title_schema())
+                .unwrap()
+                .is_empty()
+        );
+    }
+
+    #[cfg_attr(feature = "tracing", tracing_test::traced_test)]
+    #[test]
+    fn missing_title() {
+        let note = NoteInMemory::from_string_default("---\ntopic: life\n---\nData").unwrap();
+
+        let violations = note.validate_frontmatter(&title_schema()).unwrap();
+        assert_eq!(violations.len(), 1);
+        assert!(violations[0].contains("title"), "{violations:?}");
+
+        let note = NoteInMemory::from_string_default("Without frontmatter").unwrap();
+        assert_eq!(note.validate_frontmatter(&title_schema()).unwrap().len(), 1);
+    }
+
+    #[cfg_attr(feature = "tracing", tracing_test::traced_test)]
+    #[test]
+    fn wrong_types() {
+        let note =
+            NoteInMemory::from_string_default("---\ntitle: 1\ntags:\n- a\n- 2\n---\nData").unwrap();
+
+        let mut violations = note.validate_frontmatter(&title_schema()).unwrap();
+        violations.sort();
+
+        assert_eq!(violations.len(), 2);
+        assert!(violations[0].starts_with("/tags/1: "), "{violations:?}");
+        assert!(violations[1].starts_with("/title: "), "{violations:?}");
+    }
+}
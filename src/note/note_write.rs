@@ -1,9 +1,168 @@
 //! Impl trait [`NoteWrite`]
 
-use super::{Note, OpenOptions};
+use super::{Note, OpenOptions, note_name::InvalidNoteName};
 use crate::note::parser;
 use serde::Serialize;
+use serde_yml::Value;
+use std::fmt::Write as _;
 use std::io::Write;
+use std::path::{Component, Path};
+
+/// YAML style for collections in flushed frontmatter
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum FrontmatterStyle {
+    /// Block style (like `serde_yml::to_string`):
+    /// ```yaml
+    /// tags:
+    /// - a
+    /// - b
+    /// ```
+    #[default]
+    Block,
+
+    /// Flow style for lists and maps (like Obsidian):
+    /// ```yaml
+    /// tags: [a, b]
+    /// ```
+    Flow,
+}
+
+/// Quote string in YAML double-quoted style
+fn quote_yaml_string(text: &str) -> String {
+    let mut result = String::with_capacity(text.len() + 2);
+    result.push('"');
+
+    for c in text.chars() {
+        match c {
+            '"' => result.push_str("\\\""),
+            '\\' => result.push_str("\\\\"),
+            '\n' => result.push_str("\\n"),
+            '\r' => result.push_str("\\r"),
+            '\t' => result.push_str("\\t"),
+            c if c.is_control() => {
+                let _ = write!(result, "\\u{:04X}", u32::from(c));
+            }
+            c => result.push(c),
+        }
+    }
+
+    result.push('"');
+    result
+}
+
+/// Render `value` in YAML flow style (one line)
+fn to_flow_yaml(value: &Value) -> Result<String, serde_yml::Error> {
+    match value {
+        Value::Sequence(sequence) => {
+            let items = sequence
+                .iter()
+                .map(to_flow_yaml)
+                .collect::<Result<Vec<_>, _>>()?;
+
+            Ok(format!("[{}]", items.join(", ")))
+        }
+        Value::Mapping(mapping) => {
+            let entries = mapping
+                .iter()
+                .map(|(key, value)| Ok(format!("{}: {}", to_flow_yaml(key)?, to_flow_yaml(value)?)))
+                .collect::<Result<Vec<_>, serde_yml::Error>>()?;
+
+            Ok(format!("{{{}}}", entries.join(", ")))
+        }
+        Value::String(text) => {
+            let plain = serde_yml::to_string(value)?;
+            let plain = plain.trim_end();
+
+            let is_unsafe_in_flow =
+                plain.contains(['\n', ',', '[', ']', '{', '}']) || plain.starts_with(['|', '>']);
+
+            if is_unsafe_in_flow {
+                Ok(quote_yaml_string(text))
+            } else {
+                Ok(plain.to_string())
+            }
+        }
+        _ => Ok(serde_yml::to_string(value)?.trim_end().to_string()),
+    }
+}
+
+/// Serialize properties to YAML with `style`
+fn properties_to_yaml<T>(
+    properties: &T,
+    style: FrontmatterStyle,
+) -> Result<String, serde_yml::Error>
+where
+    T: Serialize,
+{
+    if style == FrontmatterStyle::Block {
+        return serde_yml::to_string(properties);
+    }
+
+    let Value::Mapping(mapping) = serde_yml::to_value(properties)? else {
+        return serde_yml::to_string(properties);
+    };
+
+    let mut result = String::new();
+    for (key, value) in mapping {
+        if matches!(value, Value::Sequence(_) | Value::Mapping(_)) {
+            let _ = writeln!(result, "{}: {}", to_flow_yaml(&key)?, to_flow_yaml(&value)?);
+        } else {
+            let mut entry = serde_yml::Mapping::new();
+            entry.insert(key, value);
+
+            result.push_str(&serde_yml::to_string(&entry)?);
+        }
+    }
+
+    Ok(result)
+}
+
+/// Raw frontmatter must be written as is?
+///
+/// YAML anchors and aliases are expanded by deserialization (see [`parser::has_yaml_anchors`]).
+/// If properties are not changed, raw frontmatter is kept to preserve them.
+/// Otherwise frontmatter is serialized again and anchors are lost
+fn keep_raw_frontmatter<T>(
+    raw_properties: &str,
+    format: parser::FrontmatterFormat,
+    properties: &T,
+) -> Result<bool, serde_yml::Error>
+where
+    T: Serialize,
+{
+    if format != parser::FrontmatterFormat::Yaml || !parser::has_yaml_anchors(raw_properties) {
+        return Ok(false);
+    }
+
+    // Raw frontmatter is not always YAML (like TOML from `Note::frontmatter_raw`)
+    let Ok(raw_value) = serde_yml::from_str::<Value>(raw_properties) else {
+        return Ok(false);
+    };
+
+    let is_unchanged = raw_value == serde_yml::to_value(properties)?;
+
+    #[cfg(feature = "tracing")]
+    if !is_unchanged {
+        tracing::warn!("Properties with YAML anchors are changed, anchors will be expanded");
+    }
+
+    Ok(is_unchanged)
+}
+
+/// Reject `path` with `..` components: note with such path can be flushed outside its directory
+fn validate_path(path: &Path) -> std::io::Result<()> {
+    if path
+        .components()
+        .any(|component| component == Component::ParentDir)
+    {
+        #[cfg(feature = "tracing")]
+        tracing::warn!("Path of note has `..` component: {}", path.display());
+
+        return Err(InvalidNoteName(path.display().to_string()).into());
+    }
+
+    Ok(())
+}
 
 /// [`Note`] support write operation
 pub trait NoteWrite: Note
@@ -14,58 +173,86 @@ where
     /// Flush only `content`
     ///
     /// Ignore if path is `None`
+    ///
+    /// Frontmatter in file is kept as is, including its format (`---` or `+++`)
+    ///
+    /// # Errors
+    /// [`InvalidNoteName`] (wrapped into [`std::io::Error`]) if path of note has `..` component
     fn flush_content(&self, open_option: &OpenOptions) -> Result<(), Self::Error> {
         if let Some(path) = self.path() {
-            let text = std::fs::read_to_string(&path)?;
-            let parsed = parser::parse_note(&text)?;
+            validate_path(&path)?;
 
-            let mut file = open_option.open(path)?;
+            let text = std::fs::read_to_string(&path)?;
 
-            match parsed {
+            // Build text before open: `open_option` may truncate the file
+            let new_text = match parser::parse_note(&text)? {
                 parser::ResultParse::WithProperties {
                     content: _,
                     properties,
-                } => file.write_all(
-                    format!("---\n{}\n---\n{}", properties, self.content()?).as_bytes(),
-                )?,
-                parser::ResultParse::WithoutProperties => {
-                    file.write_all(self.content()?.as_bytes())?;
-                }
-            }
+                    format,
+                } => super::join_frontmatter(format, properties, &self.content()?),
+                parser::ResultParse::WithoutProperties => self.content()?.into_owned(),
+            };
+
+            let mut file = open_option.open(path)?;
+            file.write_all(new_text.as_bytes())?;
         }
 
         Ok(())
     }
 
-    /// Flush only `content`
+    /// Flush only properties
     ///
     /// Ignore if path is `None`
+    ///
+    /// Properties are always written as YAML (`---`), so TOML frontmatter (`+++`)
+    /// is converted to YAML. Use [`NoteWrite::flush_content`] to keep frontmatter as is
+    ///
+    /// # Errors
+    /// [`InvalidNoteName`] (wrapped into [`std::io::Error`]) if path of note has `..` component
     fn flush_properties(&self, open_option: &OpenOptions) -> Result<(), Self::Error> {
+        self.flush_properties_with_style(open_option, FrontmatterStyle::default())
+    }
+
+    /// Same as [`NoteWrite::flush_properties`], but with [`FrontmatterStyle`]
+    ///
+    /// # Errors
+    /// [`InvalidNoteName`] (wrapped into [`std::io::Error`]) if path of note has `..` component
+    fn flush_properties_with_style(
+        &self,
+        open_option: &OpenOptions,
+        style: FrontmatterStyle,
+    ) -> Result<(), Self::Error> {
         if let Some(path) = self.path() {
+            validate_path(&path)?;
+
             let text = std::fs::read_to_string(&path)?;
             let parsed = parser::parse_note(&text)?;
 
-            let mut file = open_option.open(path)?;
-
-            match parsed {
+            // Build text before open: `open_option` may truncate the file
+            let new_text = match parsed {
                 parser::ResultParse::WithProperties {
                     content,
-                    properties: _,
+                    properties: raw_properties,
+                    format,
                 } => match self.properties()? {
-                    Some(properties) => file.write_all(
-                        format!(
-                            "---\n{}\n---\n{}",
-                            serde_yml::to_string(&properties)?,
-                            content
-                        )
-                        .as_bytes(),
-                    )?,
-                    None => file.write_all(self.content()?.as_bytes())?,
+                    Some(properties)
+                        if keep_raw_frontmatter(raw_properties, format, &properties)? =>
+                    {
+                        format!("---\n{raw_properties}\n---\n{content}")
+                    }
+                    Some(properties) => format!(
+                        "---\n{}\n---\n{}",
+                        properties_to_yaml(&properties, style)?,
+                        content
+                    ),
+                    None => self.content()?.into_owned(),
                 },
-                parser::ResultParse::WithoutProperties => {
-                    file.write_all(self.content()?.as_bytes())?;
-                }
-            }
+                parser::ResultParse::WithoutProperties => self.content()?.into_owned(),
+            };
+
+            let mut file = open_option.open(path)?;
+            file.write_all(new_text.as_bytes())?;
         }
 
         Ok(())
@@ -74,21 +261,68 @@ where
     /// Flush [`Note`] to [`Note::path`]
     ///
     /// Ignore if path is `None`
+    ///
+    /// Properties are always written as YAML (`---`), so TOML frontmatter (`+++`)
+    /// is converted to YAML.
+    ///
+    /// Frontmatter is serialized again, so YAML anchors (`&anchor`) and aliases (`*anchor`)
+    /// are expanded. The exception is unchanged properties: then [`Note::frontmatter_raw`]
+    /// with anchors is kept as is (see [`parser::has_yaml_anchors`])
+    ///
+    /// # Errors
+    /// [`InvalidNoteName`] (wrapped into [`std::io::Error`]) if path of note has `..` component
     fn flush(&self, open_option: &OpenOptions) -> Result<(), Self::Error> {
+        self.flush_with_style(open_option, FrontmatterStyle::default())
+    }
+
+    /// Same as [`NoteWrite::flush`], but with [`FrontmatterStyle`]
+    ///
+    /// # Example
+    /// ```no_run
+    /// use obsidian_parser::prelude::*;
+    /// use obsidian_parser::note::note_write::FrontmatterStyle;
+    /// use std::fs::OpenOptions;
+    ///
+    /// let note = NoteInMemory::from_file_default("note.md").unwrap();
+    /// let open_options = OpenOptions::new().write(true).truncate(true).clone();
+    ///
+    /// // tags: [a, b]
+    /// note.flush_with_style(&open_options, FrontmatterStyle::Flow).unwrap();
+    /// ```
+    ///
+    /// # Errors
+    /// [`InvalidNoteName`] (wrapped into [`std::io::Error`]) if path of note has `..` component
+    fn flush_with_style(
+        &self,
+        open_option: &OpenOptions,
+        style: FrontmatterStyle,
+    ) -> Result<(), Self::Error> {
         if let Some(path) = self.path() {
-            let mut file = open_option.open(path)?;
+            validate_path(&path)?;
 
-            match self.properties()? {
-                Some(properties) => file.write_all(
-                    format!(
+            // Build text before open: `open_option` may truncate the file
+            let new_text = match self.parsed()? {
+                (Some(properties), content) => match self.frontmatter_raw()? {
+                    Some(raw_properties)
+                        if keep_raw_frontmatter(
+                            &raw_properties,
+                            parser::FrontmatterFormat::Yaml,
+                            &properties,
+                        )? =>
+                    {
+                        format!("---\n{raw_properties}\n---\n{content}")
+                    }
+                    _ => format!(
                         "---\n{}\n---\n{}",
-                        serde_yml::to_string(&properties)?,
-                        self.content()?
-                    )
-                    .as_bytes(),
-                )?,
-                None => file.write_all(self.content()?.as_bytes())?,
-            }
+                        properties_to_yaml(&properties, style)?,
+                        content
+                    ),
+                },
+                (None, content) => content,
+            };
+
+            let mut file = open_option.open(path)?;
+            file.write_all(new_text.as_bytes())?;
         }
 
         Ok(())
@@ -106,7 +340,9 @@ where
 pub(crate) mod tests {
     use super::*;
     use crate::note::{DefaultProperties, NoteFromFile};
-    use tempfile::NamedTempFile;
+    use crate::test_utils::is_error;
+    use std::fs::File;
+    use tempfile::{NamedTempFile, TempDir};
 
     const TEST_DATA: &str = "---\n\
 topic: life\n\
@@ -183,6 +419,205 @@ Two test data";
         Ok(())
     }
 
+    pub(crate) fn flush_with_parent_dir<T>() -> Result<(), T::Error>
+    where
+        T: NoteFromFile<Properties = DefaultProperties> + NoteWrite,
+        T::Error: From<std::io::Error>
+            + From<serde_yml::Error>
+            + From<parser::Error>
+            + std::error::Error
+            + 'static,
+    {
+        let temp_dir = TempDir::new().unwrap();
+        std::fs::create_dir(temp_dir.path().join("sub")).unwrap();
+        let path = temp_dir.path().join("note.md");
+        File::create(&path)
+            .unwrap()
+            .write_all(TEST_DATA.as_bytes())
+            .unwrap();
+
+        let file = T::from_file(temp_dir.path().join("sub/../note.md"))?;
+        let open_options = OpenOptions::new().write(true).create(false).clone();
+
+        assert!(is_error::<InvalidNoteName>(
+            file.flush(&open_options).err().unwrap()
+        ));
+        assert!(is_error::<InvalidNoteName>(
+            file.flush_content(&open_options).err().unwrap()
+        ));
+        assert!(is_error::<InvalidNoteName>(
+            file.flush_properties(&open_options).err().unwrap()
+        ));
+        assert_eq!(std::fs::read_to_string(&path).unwrap(), TEST_DATA);
+
+        Ok(())
+    }
+
+    pub(crate) fn flush_with_unusual_name<T>() -> Result<(), T::Error>
+    where
+        T: NoteFromFile<Properties = DefaultProperties> + NoteWrite,
+        T::Error: From<std::io::Error> + From<serde_yml::Error> + From<parser::Error>,
+    {
+        // Not valid for `validate_note_name`, but existing file must be flushed
+        let temp_dir = TempDir::new().unwrap();
+        let path = temp_dir.path().join("a:b.md");
+        File::create(&path)
+            .unwrap()
+            .write_all(TEST_DATA.as_bytes())
+            .unwrap();
+
+        let file = T::from_file(&path)?;
+        let open_options = OpenOptions::new().write(true).create(false).clone();
+        file.flush(&open_options)?;
+        drop(file);
+
+        let file = T::from_file(&path)?;
+        assert_eq!(file.properties()?.unwrap()["topic"], "life");
+        assert_eq!(file.content()?, "Test data\n---\nTwo test data");
+
+        Ok(())
+    }
+
+    pub(crate) fn flush_with_flow_style<T>() -> Result<(), T::Error>
+    where
+        T: NoteFromFile<Properties = DefaultProperties> + NoteWrite,
+        T::Error: From<std::io::Error> + From<serde_yml::Error> + From<parser::Error>,
+    {
+        let mut test_file = NamedTempFile::new().unwrap();
+        test_file
+            .write_all(b"---\ntags:\n- a\n- b, c\ntopic: life\n---\nContent")
+            .unwrap();
+
+        let file = T::from_file(test_file.path())?;
+        let open_options = OpenOptions::new()
+            .write(true)
+            .truncate(true)
+            .create(false)
+            .clone();
+        file.flush_with_style(&open_options, FrontmatterStyle::Flow)?;
+        file.flush_properties_with_style(&open_options, FrontmatterStyle::Flow)?;
+        drop(file);
+
+        let text = std::fs::read_to_string(test_file.path())?;
+        assert!(text.contains("tags: [a, \"b, c\"]\n"), "{text}");
+        assert!(text.contains("topic: life\n"), "{text}");
+
+        let file = T::from_file(test_file.path())?;
+        let properties = file.properties()?.unwrap();
+        assert_eq!(properties["tags"][0], "a");
+        assert_eq!(properties["tags"][1], "b, c");
+        assert_eq!(properties["topic"], "life");
+        assert_eq!(file.content()?, "Content");
+
+        Ok(())
+    }
+
+    pub(crate) fn flush_with_yaml_anchors<T>() -> Result<(), T::Error>
+    where
+        T: NoteFromFile<Properties = DefaultProperties> + NoteWrite,
+        T::Error: From<std::io::Error> + From<serde_yml::Error> + From<parser::Error>,
+    {
+        const FRONTMATTER: &str = "base: &base\n  priority: 1\nother: *base\nlist: [*base]";
+
+        let mut test_file = NamedTempFile::new().unwrap();
+        test_file
+            .write_all(format!("---\n{FRONTMATTER}\n---\nContent").as_bytes())
+            .unwrap();
+
+        let file = T::from_file(test_file.path())?;
+        assert_eq!(file.properties()?.unwrap()["other"]["priority"], 1);
+
+        let open_options = OpenOptions::new()
+            .write(true)
+            .truncate(true)
+            .create(false)
+            .clone();
+        file.flush(&open_options)?;
+        file.flush_properties(&open_options)?;
+        drop(file);
+
+        let text = std::fs::read_to_string(test_file.path())?;
+        assert_eq!(text, format!("---\n{FRONTMATTER}\n---\nContent"));
+
+        let file = T::from_file(test_file.path())?;
+        assert_eq!(file.properties()?.unwrap()["list"][0]["priority"], 1);
+        assert_eq!(file.content()?, "Content");
+
+        Ok(())
+    }
+
+    #[cfg_attr(feature = "tracing", tracing_test::traced_test)]
+    #[test]
+    fn yaml_anchors() {
+        assert!(parser::has_yaml_anchors("a: &anchor 1\nb: *anchor"));
+        assert!(parser::has_yaml_anchors("<<: *base"));
+        assert!(parser::has_yaml_anchors("- &item value"));
+        assert!(parser::has_yaml_anchors("map: {a: *x}"));
+        assert!(!parser::has_yaml_anchors("title: Tom & Jerry"));
+        assert!(!parser::has_yaml_anchors("title: 'a *b'"));
+        assert!(!parser::has_yaml_anchors("math: 2*3 # *comment"));
+    }
+
+    #[cfg_attr(feature = "tracing", tracing_test::traced_test)]
+    #[test]
+    fn flow_yaml() {
+        let value: Value =
+            serde_yml::from_str("list: [1, 'x y', {k: v}, [], '[]']\nnested: {a: [true, null]}")
+                .unwrap();
+
+        let yaml = properties_to_yaml(&value, FrontmatterStyle::Flow).unwrap();
+        assert_eq!(
+            yaml,
+            "list: [1, x y, {k: v}, [], \"[]\"]\nnested: {a: [true, null]}\n"
+        );
+        assert_eq!(serde_yml::from_str::<Value>(&yaml).unwrap(), value);
+    }
+
+    #[cfg_attr(feature = "tracing", tracing_test::traced_test)]
+    #[test]
+    fn block_yaml() {
+        let value: Value = serde_yml::from_str("list: [1, 2]").unwrap();
+
+        let yaml = properties_to_yaml(&value, FrontmatterStyle::Block).unwrap();
+        assert_eq!(yaml, "list:\n- 1\n- 2\n");
+    }
+
+    #[cfg(feature = "toml")]
+    pub(crate) fn flush_toml_frontmatter<T>() -> Result<(), T::Error>
+    where
+        T: NoteFromFile<Properties = DefaultProperties> + NoteWrite,
+        T::Error: From<std::io::Error> + From<serde_yml::Error> + From<parser::Error>,
+    {
+        const TOML_DATA: &str = "+++\ntopic = \"life\"\n+++\nContent";
+
+        let mut test_file = NamedTempFile::new().unwrap();
+        test_file.write_all(TOML_DATA.as_bytes()).unwrap();
+
+        let file = T::from_file(test_file.path())?;
+        let open_options = OpenOptions::new()
+            .write(true)
+            .truncate(true)
+            .create(false)
+            .clone();
+
+        // Content only: frontmatter is kept as TOML
+        file.flush_content(&open_options)?;
+        assert_eq!(std::fs::read_to_string(test_file.path())?, TOML_DATA);
+
+        // Properties: converted to YAML
+        for flush in [T::flush, T::flush_properties] {
+            std::fs::write(test_file.path(), TOML_DATA)?;
+            flush(&file, &open_options)?;
+
+            assert_eq!(
+                std::fs::read_to_string(test_file.path())?,
+                "---\ntopic: life\n\n---\nContent"
+            );
+        }
+
+        Ok(())
+    }
+
     macro_rules! impl_all_tests_flush {
         ($impl_note:path) => {
             #[allow(unused_imports)]
@@ -191,6 +626,32 @@ Two test data";
             impl_test_for_note!(impl_flush, flush, $impl_note);
             impl_test_for_note!(impl_flush_content, flush_content, $impl_note);
             impl_test_for_note!(impl_flush_properties, flush_properties, $impl_note);
+            impl_test_for_note!(
+                impl_flush_with_flow_style,
+                flush_with_flow_style,
+                $impl_note
+            );
+            impl_test_for_note!(
+                impl_flush_with_yaml_anchors,
+                flush_with_yaml_anchors,
+                $impl_note
+            );
+            impl_test_for_note!(
+                impl_flush_with_parent_dir,
+                flush_with_parent_dir,
+                $impl_note
+            );
+            impl_test_for_note!(
+                impl_flush_with_unusual_name,
+                flush_with_unusual_name,
+                $impl_note
+            );
+            #[cfg(feature = "toml")]
+            impl_test_for_note!(
+                impl_flush_toml_frontmatter,
+                flush_toml_frontmatter,
+                $impl_note
+            );
         };
     }
 
@@ -1,7 +1,125 @@
 //! impl parser for Obsidian notes
 
+use super::code_block::{code_ranges, is_in_ranges, lines_with_offsets};
+use serde::de::DeserializeOwned;
+use std::borrow::Cow;
 use thiserror::Error;
 
+/// Link parsed by [`parse_links_detailed`]
+///
+/// For `[[Note#Heading^block|Alias]]`:
+/// - `target`: `Note`
+/// - `heading`: `Some("Heading")`
+/// - `block`: `Some("block")`
+/// - `alias`: `Some("Alias")`
+///
+/// Empty parts (like heading in `[[Note#^block]]`) are [`None`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct ParsedLink<'a> {
+    /// Note name or path to note
+    pub target: &'a str,
+
+    /// Heading after `#`
+    pub heading: Option<&'a str>,
+
+    /// Block anchor after `^`
+    pub block: Option<&'a str>,
+
+    /// Display text after `|`
+    pub alias: Option<&'a str>,
+}
+
+impl<'a> ParsedLink<'a> {
+    /// Parse inner text of link (without `[[` and `]]`)
+    pub(crate) fn parse(inner: &'a str) -> Self {
+        let non_empty = |text: &'a str| Some(text.trim()).filter(|text| !text.is_empty());
+
+        let (link, alias) = match inner.split_once('|') {
+            Some((link, alias)) => (link, non_empty(alias)),
+            None => (inner, None),
+        };
+
+        let (link, block) = match link.split_once('^') {
+            Some((link, block)) => (link, non_empty(block)),
+            None => (link, None),
+        };
+
+        let (target, heading) = match link.split_once('#') {
+            Some((target, heading)) => (target, non_empty(heading)),
+            None => (link, None),
+        };
+
+        Self {
+            target: target.trim(),
+            heading,
+            block,
+            alias,
+        }
+    }
+}
+
+/// Parses Obsidian-style links in note content with all metadata
+///
+/// Precedence: alias (`|`) is split first, then block (`^`), then heading (`#`)
+///
+/// # Example
+/// ```
+/// # use obsidian_parser::note::parser::{ParsedLink, parse_links_detailed};
+/// let content = "[[Physics#Laws^newton|Newton]] and [[Math]]";
+/// let links: Vec<_> = parse_links_detailed(content).collect();
+///
+/// assert_eq!(
+///     links[0],
+///     ParsedLink {
+///         target: "Physics",
+///         heading: Some("Laws"),
+///         block: Some("newton"),
+///         alias: Some("Newton"),
+///     }
+/// );
+/// assert_eq!(links[1].target, "Math");
+/// assert_eq!(links[1].heading, None);
+/// ```
+pub fn parse_links_detailed(text: &str) -> impl Iterator<Item = ParsedLink<'_>> {
+    parse_links_with_embed_flag(text).map(|(link, _)| link)
+}
+
+/// Parses all links and marks embeds (`![[...]]`) with `true`
+pub(crate) fn parse_links_with_embed_flag(
+    text: &str,
+) -> impl Iterator<Item = (ParsedLink<'_>, bool)> {
+    let code_ranges = code_ranges(text);
+
+    text.match_indices("[[").filter_map(move |(start_pos, _)| {
+        if is_in_ranges(&code_ranges, start_pos) {
+            return None;
+        }
+
+        let end_pos = text[start_pos + 2..].find("]]")?;
+        let inner = &text[start_pos + 2..start_pos + 2 + end_pos];
+        let is_embed = text[..start_pos].ends_with('!');
+
+        Some((ParsedLink::parse(inner), is_embed))
+    })
+}
+
+/// Parses Obsidian-style embeds (`![[...]]`) in note content
+///
+/// Returns only target of embed, like [`parse_links`]
+///
+/// # Example
+/// ```
+/// # use obsidian_parser::note::parser::parse_embeds;
+/// let content = "![[image.png]] and [[Math]] and ![[Physics#Laws]]";
+/// let embeds: Vec<_> = parse_embeds(content).collect();
+/// assert_eq!(embeds, vec!["image.png", "Physics"]);
+/// ```
+pub fn parse_embeds(text: &str) -> impl Iterator<Item = &str> {
+    parse_links_with_embed_flag(text)
+        .filter(|(_, is_embed)| *is_embed)
+        .map(|(link, _)| link.target)
+}
+
 /// Parses Obsidian-style links in note content
 ///
 /// Handles all link formats:
@@ -11,6 +129,12 @@ use thiserror::Error;
 /// - `[[Note#heading]]`
 /// - `[[Note#heading|Alias]]`
 ///
+/// Returns only target of link. See [`parse_links_detailed`] for heading, block and alias
+///
+/// Links inside fenced code blocks and inline code (`` `[[Note]]` ``) are ignored
+///
+/// Embeds (`![[...]]`) are returned too. See [`parse_embeds`]
+///
 /// # Example
 /// ```
 /// # use obsidian_parser::note::parser::parse_links;
@@ -19,56 +143,343 @@ use thiserror::Error;
 /// assert_eq!(links, vec!["Physics", "Math"]);
 /// ```
 pub fn parse_links(text: &str) -> impl Iterator<Item = &str> {
-    text.match_indices("[[").filter_map(move |(start_pos, _)| {
-        let end_pos = text[start_pos + 2..].find("]]")?;
-        let inner = &text[start_pos + 2..start_pos + 2 + end_pos];
+    parse_links_detailed(text).map(|link| link.target)
+}
+
+/// Normalize link target to canonical form
+///
+/// 1. Trim whitespaces
+/// 2. Collapse internal whitespaces to single space
+/// 3. Strip trailing `.md`
+///
+/// Graph index in [`Vault`](crate::vault::Vault) uses the same form for note paths
+///
+/// # Example
+/// ```
+/// # use obsidian_parser::note::parser::normalize_link;
+/// assert_eq!(normalize_link("  My   Note.md "), "My Note");
+/// assert_eq!(normalize_link("dir/Note"), "dir/Note");
+/// ```
+#[must_use]
+pub fn normalize_link(target: &str) -> String {
+    let collapsed = target.split_whitespace().collect::<Vec<_>>().join(" ");
+
+    collapsed
+        .strip_suffix(".md")
+        .unwrap_or(&collapsed)
+        .trim_end()
+        .to_string()
+}
+
+/// Same as [`parse_links`], but every target is normalized by [`normalize_link`]
+///
+/// # Example
+/// ```
+/// # use obsidian_parser::note::parser::parse_links_normalized;
+/// let content = "[[My  Note.md]] and [[ Math |Mathematics]]";
+/// let links: Vec<_> = parse_links_normalized(content).collect();
+/// assert_eq!(links, vec!["My Note", "Math"]);
+/// ```
+pub fn parse_links_normalized(text: &str) -> impl Iterator<Item = String> {
+    parse_links(text).map(normalize_link)
+}
+
+/// Check raw YAML frontmatter for anchors (`&anchor`) and aliases (`*anchor`)
+///
+/// Deserialization expands aliases, so properties written back lose them.
+/// It is a heuristic: token starting with `&` or `*` after whitespace, `:`, `[`, `{` or `,`
+/// is counted (so `a &b` in plain string is counted too), quoted strings and comments are not checked
+///
+/// # Example
+/// ```
+/// # use obsidian_parser::note::parser::has_yaml_anchors;
+/// assert!(has_yaml_anchors("base: &base\n  a: 1\nother: *base"));
+/// assert!(has_yaml_anchors("list: [*first, *second]"));
+/// assert!(!has_yaml_anchors("title: Tom & Jerry\nnote: \"*quoted*\""));
+/// ```
+#[must_use]
+pub fn has_yaml_anchors(properties: &str) -> bool {
+    properties.lines().any(|line| {
+        let line = line.split(" #").next().unwrap_or(line);
+        let mut in_quotes = None;
+        let mut previous = ' ';
 
-        let note_name = inner
-            .split('#')
-            .next()?
-            .split('^')
-            .next()?
-            .split('|')
-            .next()?
-            .trim();
+        line.char_indices().any(|(i, c)| {
+            let is_anchor = in_quotes.is_none()
+                && matches!(c, '&' | '*')
+                && matches!(previous, ' ' | '\t' | ':' | '[' | '{' | ',')
+                && line[i + 1..]
+                    .chars()
+                    .next()
+                    .is_some_and(|next| !next.is_whitespace() && !",[]{}".contains(next));
 
-        Some(note_name)
+            match (in_quotes, c) {
+                (None, '"' | '\'') if matches!(previous, ' ' | ':' | '[' | '{' | ',') => {
+                    in_quotes = Some(c);
+                }
+                (Some(quote), c) if c == quote => in_quotes = None,
+                _ => {}
+            }
+            previous = c;
+
+            is_anchor
+        })
     })
 }
 
+/// Remove Obsidian comments (`%%comment%%`) from text
+///
+/// Both inline and multiline comments are removed. `%%` inside fenced code blocks
+/// and inline code is preserved. Like in Obsidian, unclosed `%%` comments out the rest of text
+///
+/// # Example
+/// ```
+/// # use obsidian_parser::note::parser::strip_comments;
+/// let text = "Visible %%hidden%% text\n%%\nHidden block\n%%\n`%%code%%`";
+/// assert_eq!(strip_comments(text), "Visible  text\n\n`%%code%%`");
+/// ```
+#[must_use]
+pub fn strip_comments(text: &str) -> Cow<'_, str> {
+    const MARKER: &str = "%%";
+
+    if !text.contains(MARKER) {
+        return Cow::Borrowed(text);
+    }
+
+    let code_ranges = code_ranges(text);
+    let mut markers = text
+        .match_indices(MARKER)
+        .map(|(pos, _)| pos)
+        .filter(|pos| !is_in_ranges(&code_ranges, *pos));
+
+    let mut result = String::with_capacity(text.len());
+    let mut last = 0;
+    while let Some(open) = markers.find(|pos| *pos >= last) {
+        result.push_str(&text[last..open]);
+
+        match markers.find(|pos| *pos >= open + MARKER.len()) {
+            Some(close) => last = close + MARKER.len(),
+            None => return Cow::Owned(result),
+        }
+    }
+
+    result.push_str(&text[last..]);
+    Cow::Owned(result)
+}
+
+/// Syntax of frontmatter
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum FrontmatterFormat {
+    /// YAML between `---` delimiters
+    #[default]
+    Yaml,
+
+    /// TOML between `+++` delimiters
+    #[cfg_attr(docsrs, doc(cfg(feature = "toml")))]
+    #[cfg(feature = "toml")]
+    Toml,
+}
+
+impl FrontmatterFormat {
+    /// Delimiter of frontmatter: `---` for YAML, `+++` for TOML
+    #[must_use]
+    pub const fn delimiter(self) -> &'static str {
+        match self {
+            Self::Yaml => "---",
+            #[cfg(feature = "toml")]
+            Self::Toml => "+++",
+        }
+    }
+
+    /// Detect format by first line of note
+    fn from_first_line(line: &str) -> Option<Self> {
+        match line.trim_end() {
+            "---" => Some(Self::Yaml),
+            #[cfg(feature = "toml")]
+            "+++" => Some(Self::Toml),
+            _ => None,
+        }
+    }
+}
+
 #[derive(Debug, PartialEq, Eq)]
 #[allow(missing_docs)]
 pub enum ResultParse<'a> {
     WithProperties {
         content: &'a str,
         properties: &'a str,
+        format: FrontmatterFormat,
     },
     WithoutProperties,
 }
 
+/// Error types, which can be returned by [`deserialize_properties`]
+#[cfg(not(feature = "toml"))]
+pub(crate) trait FromFrontmatterError: From<serde_yml::Error> {}
+
+#[cfg(not(feature = "toml"))]
+impl<E> FromFrontmatterError for E where E: From<serde_yml::Error> {}
+
+/// Error types, which can be returned by [`deserialize_properties`]
+#[cfg(feature = "toml")]
+pub(crate) trait FromFrontmatterError:
+    From<serde_yml::Error> + From<toml::de::Error>
+{
+}
+
+#[cfg(feature = "toml")]
+impl<E> FromFrontmatterError for E where E: From<serde_yml::Error> + From<toml::de::Error> {}
+
+/// Deserialize raw frontmatter (without delimiters) in `format`
+///
+/// Empty frontmatter (`---\n---`), frontmatter with only whitespaces and YAML `null` (`~`)
+/// are deserialized from empty mapping. So map gives empty map and struct needs
+/// `#[serde(default)]` for missing fields
+pub(crate) fn deserialize_properties<T, E>(
+    properties: &str,
+    format: FrontmatterFormat,
+) -> Result<T, E>
+where
+    T: DeserializeOwned,
+    E: FromFrontmatterError,
+{
+    match format {
+        FrontmatterFormat::Yaml => match serde_yml::from_str(properties) {
+            Ok(properties) => Ok(properties),
+            Err(_) if is_null_yaml(properties) => {
+                #[cfg(feature = "tracing")]
+                tracing::trace!("Frontmatter is empty, use empty mapping");
+
+                Ok(serde_yml::from_value(serde_yml::Value::Mapping(
+                    serde_yml::Mapping::new(),
+                ))?)
+            }
+            Err(error) => Err(error.into()),
+        },
+        #[cfg(feature = "toml")]
+        FrontmatterFormat::Toml => Ok(toml::from_str(properties)?),
+    }
+}
+
+/// YAML is empty, has only whitespaces or is `null`
+fn is_null_yaml(properties: &str) -> bool {
+    serde_yml::from_str::<serde_yml::Value>(properties).is_ok_and(|value| value.is_null())
+}
+
 /// Errors for [`parse_note`]
 #[derive(Debug, Error)]
 pub enum Error {
-    /// Not found closer in yanl like `---`
-    #[error("Not found closer in yaml like `---`")]
-    NotFoundCloser,
+    /// Not found closer of frontmatter like `---` (or `+++` for TOML)
+    ///
+    /// Frontmatter is opened only on the first line, so closer is searched till the end of note
+    #[error(
+        "Not found closer of frontmatter like `---` or `+++` opened at line 1 (searched to line {last_line})"
+    )]
+    NotFoundCloser {
+        /// The last line (from 1) scanned for closer, that is the last line of note
+        last_line: usize,
+    },
+
+    /// Frontmatter is indented with tabs (before key or `- ` item) and is not valid YAML.
+    /// YAML forbids tabs for indentation
+    #[error("Tab indentation in frontmatter at line {0} (YAML allows only spaces)")]
+    TabInFrontmatter(usize),
+}
+
+/// Find first line (number in note, from 1) of frontmatter, where key or `- ` item is indented with tab
+///
+/// Tabs in other lines (like in block scalar `|`) are allowed.
+/// `properties` is untrimmed text after first `---`, so its first line is the rest of `---` line
+fn find_tab_indentation(properties: &str) -> Option<usize> {
+    properties
+        .lines()
+        .position(|line| {
+            let rest = line.trim_start();
+            let indentation = &line[..line.len() - rest.len()];
+
+            indentation.contains('\t') && is_structural_line(rest)
+        })
+        .map(|position| position + 1)
+}
+
+/// Line (without indentation) starts with `- ` item or `key:`
+fn is_structural_line(line: &str) -> bool {
+    if line == "-" || line.starts_with("- ") {
+        return true;
+    }
+
+    line.split_once(':').is_some_and(|(key, value)| {
+        !key.is_empty()
+            && !key.starts_with('#')
+            && (value.is_empty() || value.starts_with([' ', '\t']))
+    })
+}
+
+/// Find byte offset of closing delimiter line in `text` after opening delimiter
+///
+/// See precedence rules in [`parse_note`]
+fn find_closer(text: &str, delimiter: &str) -> Option<usize> {
+    let mut first_in_code_block = None;
+
+    for (offset, line, is_outside) in lines_with_offsets(text) {
+        if line.trim_end() != delimiter {
+            continue;
+        }
+
+        if is_outside {
+            return Some(offset);
+        }
+
+        first_in_code_block.get_or_insert(offset);
+    }
+
+    first_in_code_block
 }
 
 /// Parse obsidian note
+///
+/// YAML frontmatter (`---`) is always detected.
+/// TOML frontmatter (`+++`) is detected only with `toml` feature
+///
+/// # Closer of frontmatter
+/// 1. Closer is a whole line with delimiter (trailing whitespaces are ignored), so `a---b` is not closer
+/// 2. Closer inside fenced code block (```` ``` ```` or `~~~`) is skipped
+/// 3. If there is no closer outside code blocks (like with unclosed fence),
+///    first closer line inside code block is used
+///
+/// # Errors
+/// - [`Error::NotFoundCloser`] if frontmatter is not closed
+/// - [`Error::TabInFrontmatter`] if YAML frontmatter is indented with tabs and is not valid YAML
 pub fn parse_note(raw_text: &str) -> Result<ResultParse<'_>, Error> {
-    let have_start_properties = raw_text
+    let format = raw_text
         .lines()
         .next()
-        .is_some_and(|line| line.trim_end() == "---");
+        .and_then(FrontmatterFormat::from_first_line);
+
+    if let Some(format) = format {
+        let delimiter = format.delimiter();
+        let after_opener = &raw_text[delimiter.len()..];
+        // Frontmatter is recognized only on the first line of note
+        let closed = find_closer(after_opener, delimiter).ok_or_else(|| Error::NotFoundCloser {
+            last_line: raw_text.lines().count(),
+        })?;
+
+        let properties = &after_opener[..closed];
+        if format == FrontmatterFormat::Yaml
+            && let Some(line) = find_tab_indentation(properties)
+            && serde_yml::from_str::<serde_yml::Value>(properties).is_err()
+        {
+            #[cfg(feature = "tracing")]
+            tracing::warn!("Tab indentation in frontmatter at line {line}");
 
-    if have_start_properties {
-        let closed = raw_text["---".len()..]
-            .find("---")
-            .ok_or(Error::NotFoundCloser)?;
+            return Err(Error::TabInFrontmatter(line));
+        }
 
         return Ok(ResultParse::WithProperties {
-            content: raw_text[(closed + 2 * "...".len())..].trim(),
-            properties: raw_text["...".len()..(closed + "...".len())].trim(),
+            content: after_opener[(closed + delimiter.len())..].trim(),
+            properties: properties.trim(),
+            format,
         });
     }
 
@@ -77,7 +488,11 @@ pub fn parse_note(raw_text: &str) -> Result<ResultParse<'_>, Error> {
 
 #[cfg(test)]
 mod tests {
-    use super::{ResultParse, parse_note};
+    use super::{
+        Error, FrontmatterFormat, ParsedLink, ResultParse, deserialize_properties, normalize_link,
+        parse_embeds, parse_links, parse_links_detailed, parse_links_normalized, parse_note,
+        strip_comments,
+    };
 
     #[cfg_attr(feature = "tracing", tracing_test::traced_test)]
     #[test]
@@ -98,7 +513,106 @@ mod tests {
             result,
             ResultParse::WithProperties {
                 content: "test data",
-                properties: "properties data"
+                properties: "properties data",
+                format: FrontmatterFormat::Yaml,
+            }
+        );
+    }
+
+    #[cfg(feature = "toml")]
+    #[cfg_attr(feature = "tracing", tracing_test::traced_test)]
+    #[test]
+    fn parse_note_with_toml_properties() {
+        let test_data = "+++\ntopic = \"life\"\n\tindented = true\n+++\ntest data";
+        let result = parse_note(test_data).unwrap();
+
+        assert_eq!(
+            result,
+            ResultParse::WithProperties {
+                content: "test data",
+                properties: "topic = \"life\"\n\tindented = true",
+                format: FrontmatterFormat::Toml,
+            }
+        );
+    }
+
+    #[cfg(feature = "toml")]
+    #[cfg_attr(feature = "tracing", tracing_test::traced_test)]
+    #[test]
+    fn parse_note_with_toml_properties_not_closed() {
+        let result = parse_note("+++\ntopic = \"life\"\n---\ntest data");
+
+        assert!(matches!(
+            result,
+            Err(Error::NotFoundCloser { last_line: 4 })
+        ));
+    }
+
+    #[cfg_attr(feature = "tracing", tracing_test::traced_test)]
+    #[test]
+    fn parse_note_not_closed_reports_line() {
+        let error = parse_note("---\ntopic: life\n\nContent\n--- not closer").unwrap_err();
+
+        assert!(matches!(error, Error::NotFoundCloser { last_line: 5 }));
+        assert!(error.to_string().ends_with("(searched to line 5)"));
+
+        let error = crate::note::note_in_memory::Error::from(error);
+        assert!(error.to_string().contains("searched to line 5"));
+    }
+
+    #[cfg(not(feature = "toml"))]
+    #[cfg_attr(feature = "tracing", tracing_test::traced_test)]
+    #[test]
+    fn parse_note_with_toml_properties_without_feature() {
+        let result = parse_note("+++\ntopic = \"life\"\n+++\ntest data").unwrap();
+
+        assert_eq!(result, ResultParse::WithoutProperties);
+    }
+
+    #[cfg_attr(feature = "tracing", tracing_test::traced_test)]
+    #[test]
+    fn parse_note_closer_is_whole_line() {
+        let test_data = "---\ntitle: a---b\n---\ntest data";
+        let result = parse_note(test_data).unwrap();
+
+        assert_eq!(
+            result,
+            ResultParse::WithProperties {
+                content: "test data",
+                properties: "title: a---b",
+                format: FrontmatterFormat::Yaml,
+            }
+        );
+    }
+
+    #[cfg_attr(feature = "tracing", tracing_test::traced_test)]
+    #[test]
+    fn parse_note_closer_in_code_block() {
+        let test_data = "---\nexample: |\n  ```\n---\n  ```\n---\ntest data\n```\n---\n```";
+        let result = parse_note(test_data).unwrap();
+
+        assert_eq!(
+            result,
+            ResultParse::WithProperties {
+                content: "test data\n```\n---\n```",
+                properties: "example: |\n  ```\n---\n  ```",
+                format: FrontmatterFormat::Yaml,
+            }
+        );
+    }
+
+    #[cfg_attr(feature = "tracing", tracing_test::traced_test)]
+    #[test]
+    fn parse_note_closer_in_unclosed_code_block() {
+        let test_data = "---\ntopic: life\n```\n---\ntest data";
+        let result = parse_note(test_data).unwrap();
+
+        assert_eq!(
+            result,
+            ResultParse::WithProperties {
+                content: "test data",
+                properties: "topic: life\n```",
+                format: FrontmatterFormat::Yaml,
             }
         );
     }
@@ -142,6 +656,47 @@ mod tests {
         assert_eq!(result, ResultParse::WithoutProperties);
     }
 
+    #[cfg_attr(feature = "tracing", tracing_test::traced_test)]
+    #[test]
+    fn deserialize_empty_properties() {
+        #[derive(Debug, Default, PartialEq, serde::Deserialize)]
+        #[serde(default)]
+        struct Properties {
+            topic: Option<String>,
+        }
+
+        #[derive(Debug, serde::Deserialize)]
+        struct Required {
+            #[allow(dead_code)]
+            topic: String,
+        }
+
+        for properties in ["", "  \n\n", "~", "null"] {
+            let map: crate::note::DefaultProperties =
+                deserialize_properties::<_, crate::note::note_in_memory::Error>(
+                    properties,
+                    FrontmatterFormat::Yaml,
+                )
+                .unwrap();
+            assert!(map.is_empty());
+
+            let with_default: Properties = deserialize_properties::<
+                _,
+                crate::note::note_in_memory::Error,
+            >(properties, FrontmatterFormat::Yaml)
+            .unwrap();
+            assert_eq!(with_default, Properties::default());
+
+            assert!(
+                deserialize_properties::<Required, crate::note::note_in_memory::Error>(
+                    properties,
+                    FrontmatterFormat::Yaml
+                )
+                .is_err()
+            );
+        }
+    }
+
     #[cfg_attr(feature = "tracing", tracing_test::traced_test)]
     #[test]
     fn parse_note_with_properties_but_check_trim_end() {
@@ -152,7 +707,8 @@ mod tests {
             result,
             ResultParse::WithProperties {
                 content: "test data",
-                properties: "properties data"
+                properties: "properties data",
+                format: FrontmatterFormat::Yaml,
             }
         );
     }
@@ -167,4 +723,182 @@ mod tests {
 
         assert!(ds.iter().all(|x| *x == "Note"))
     }
+
+    #[cfg_attr(feature = "tracing", tracing_test::traced_test)]
+    #[test]
+    fn test_normalize_link() {
+        assert_eq!(normalize_link("Note"), "Note");
+        assert_eq!(normalize_link("  Note\t"), "Note");
+        assert_eq!(normalize_link("My \t  super   note"), "My super note");
+        assert_eq!(normalize_link("Note.md"), "Note");
+        assert_eq!(normalize_link("dir/Note .md"), "dir/Note");
+        assert_eq!(normalize_link("Note.md.md"), "Note.md");
+        assert_eq!(normalize_link("Note.markdown"), "Note.markdown");
+    }
+
+    #[cfg_attr(feature = "tracing", tracing_test::traced_test)]
+    #[test]
+    fn test_parse_links_normalized() {
+        let test_data =
+            "[[ My  Note.md ]] [[My Note|Alias]] [[dir/My   Note#Heading]] [[Note.md^block]]";
+
+        let raw: Vec<_> = parse_links(test_data).collect();
+        assert_eq!(raw, ["My  Note.md", "My Note", "dir/My   Note", "Note.md"]);
+
+        let normalized: Vec<_> = parse_links_normalized(test_data).collect();
+        assert_eq!(normalized, ["My Note", "My Note", "dir/My Note", "Note"]);
+    }
+
+    #[cfg_attr(feature = "tracing", tracing_test::traced_test)]
+    #[test]
+    fn test_parse_links_detailed() {
+        let test_data =
+            "[[Note]] [[Note#H^b|Alias]] [[Note#^b]] [[ dir/Note # H | Alias ]] [[Note|]]";
+
+        let links: Vec<_> = parse_links_detailed(test_data).collect();
+        let link = |target, heading, block, alias| ParsedLink {
+            target,
+            heading,
+            block,
+            alias,
+        };
+
+        assert_eq!(
+            links,
+            [
+                link("Note", None, None, None),
+                link("Note", Some("H"), Some("b"), Some("Alias")),
+                link("Note", None, Some("b"), None),
+                link("dir/Note", Some("H"), None, Some("Alias")),
+                link("Note", None, None, None),
+            ]
+        );
+    }
+
+    #[cfg_attr(feature = "tracing", tracing_test::traced_test)]
+    #[test]
+    fn test_parse_links_detailed_alias_with_special_chars() {
+        let link = parse_links_detailed("[[Note|C# ^ notes]]").next().unwrap();
+
+        assert_eq!(link.target, "Note");
+        assert_eq!(link.heading, None);
+        assert_eq!(link.block, None);
+        assert_eq!(link.alias, Some("C# ^ notes"));
+    }
+
+    #[cfg_attr(feature = "tracing", tracing_test::traced_test)]
+    #[test]
+    fn test_parse_embeds() {
+        let test_data = "![[image.png]] [[Note]] text! [[Other]] ![[Note#Heading|Alias]]";
+
+        let embeds: Vec<_> = parse_embeds(test_data).collect();
+        assert_eq!(embeds, ["image.png", "Note"]);
+
+        let links: Vec<_> = parse_links(test_data).collect();
+        assert_eq!(links, ["image.png", "Note", "Other", "Note"]);
+    }
+
+    #[cfg_attr(feature = "tracing", tracing_test::traced_test)]
+    #[test]
+    fn parse_note_with_tab_in_frontmatter() {
+        let test_data = "---\ntopic: life\ntags:\n\t- todo\n---\ntest data";
+
+        let error = parse_note(test_data).unwrap_err();
+        assert!(matches!(error, Error::TabInFrontmatter(4)));
+        assert_eq!(
+            error.to_string(),
+            "Tab indentation in frontmatter at line 4 (YAML allows only spaces)"
+        );
+
+        let error = parse_note("---\n\ttopic: life\n---\ntest data").unwrap_err();
+        assert!(matches!(error, Error::TabInFrontmatter(2)));
+    }
+
+    #[cfg_attr(feature = "tracing", tracing_test::traced_test)]
+    #[test]
+    fn parse_note_with_tab_in_frontmatter_value() {
+        let test_data = "---\ntopic: life\tand work\ntags:\n  - todo\n---\n\ttest data";
+
+        let result = parse_note(test_data).unwrap();
+        assert_eq!(
+            result,
+            ResultParse::WithProperties {
+                content: "test data",
+                properties: "topic: life\tand work\ntags:\n  - todo",
+                format: FrontmatterFormat::Yaml,
+            }
+        );
+    }
+
+    #[cfg_attr(feature = "tracing", tracing_test::traced_test)]
+    #[test]
+    fn parse_note_with_tab_in_block_scalar() {
+        let test_data = "---\nbody: |\n  line1\n  \tindented\n  \t- not item\n---\nx";
+
+        let result = parse_note(test_data).unwrap();
+        assert_eq!(
+            result,
+            ResultParse::WithProperties {
+                content: "x",
+                properties: "body: |\n  line1\n  \tindented\n  \t- not item",
+                format: FrontmatterFormat::Yaml,
+            }
+        );
+    }
+
+    #[cfg_attr(feature = "tracing", tracing_test::traced_test)]
+    #[test]
+    fn test_parse_links_ignore_code() {
+        let test_data = "[[Real]]\n```markdown\nUse [[Example]] for links\n```\n~~~\n![[Embed]]\n~~~\nInline `[[Code]]` and ``[[Double` ``]] [[Other]]";
+
+        let links: Vec<_> = parse_links(test_data).collect();
+        assert_eq!(links, ["Real", "Other"]);
+
+        let embeds: Vec<_> = parse_embeds(test_data).collect();
+        assert!(embeds.is_empty());
+    }
+
+    #[cfg_attr(feature = "tracing", tracing_test::traced_test)]
+    #[test]
+    fn parse_links_after_single_line_code() {
+        let test_data = "```[[Code]]```\n[[Link]]";
+
+        let links: Vec<_> = parse_links(test_data).collect();
+        assert_eq!(links, ["Link"]);
+    }
+
+    #[cfg_attr(feature = "tracing", tracing_test::traced_test)]
+    #[test]
+    fn test_parse_links_unclosed_code() {
+        let test_data = "[[Real]] `not code [[Link]]\n```\n[[Example]]";
+
+        let links: Vec<_> = parse_links(test_data).collect();
+        assert_eq!(links, ["Real", "Link"]);
+    }
+
+    #[cfg_attr(feature = "tracing", tracing_test::traced_test)]
+    #[test]
+    fn test_strip_comments() {
+        assert_eq!(strip_comments("No comments"), "No comments");
+        assert!(matches!(
+            strip_comments("No comments"),
+            std::borrow::Cow::Borrowed(_)
+        ));
+
+        assert_eq!(strip_comments("a %%b%% c %%d%%"), "a  c ");
+        assert_eq!(strip_comments("a\n%%\nb\n[[Link]]\n%%\nc"), "a\n\nc");
+        assert_eq!(strip_comments("a %%%% b"), "a  b");
+        assert_eq!(strip_comments("a %%unclosed\nb"), "a ");
+    }
+
+    #[cfg_attr(feature = "tracing", tracing_test::traced_test)]
+    #[test]
+    fn test_strip_comments_in_code() {
+        let text = "```\n%% not comment %%\n```\n`%%` %%comment%% ``a %% b``";
+
+        assert_eq!(
+            strip_comments(text),
+            "```\n%% not comment %%\n```\n`%%`  ``a %% b``"
+        );
+    }
 }
@@ -0,0 +1,170 @@
+//! Count reads of notes from disk
+//!
+//! Useful to find multi-pass analysis over [`NoteOnDisk`](crate::note::note_on_disk::NoteOnDisk),
+//! where every [`Note::content`](crate::note::Note::content) reads the file again
+//!
+//! # Threads
+//! Counters are thread-local: reads in other threads (like in `par_*` methods)
+//! are not collected by [`collect_read_stats`]
+
+use std::cell::Cell;
+
+/// Kind of read from disk
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum ReadKind {
+    Content,
+    Properties,
+    Parsed,
+}
+
+/// Count reads of notes from disk
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct ReadStats {
+    /// Reads for [`Note::content`](crate::note::Note::content)
+    pub content: usize,
+
+    /// Reads for [`Note::properties`](crate::note::Note::properties) and
+    /// [`NotePropertiesMapping::properties_mapping`](crate::note::note_properties_mapping::NotePropertiesMapping::properties_mapping)
+    pub properties: usize,
+
+    /// Reads for [`Note::parts`](crate::note::Note::parts) and [`Note::parsed`](crate::note::Note::parsed)
+    pub parsed: usize,
+}
+
+impl ReadStats {
+    /// Total count of reads from disk
+    #[must_use]
+    pub const fn total(&self) -> usize {
+        self.content + self.properties + self.parsed
+    }
+
+    const fn saturating_sub(self, other: Self) -> Self {
+        Self {
+            content: self.content.saturating_sub(other.content),
+            properties: self.properties.saturating_sub(other.properties),
+            parsed: self.parsed.saturating_sub(other.parsed),
+        }
+    }
+}
+
+thread_local! {
+    static READ_STATS: Cell<ReadStats> = const { Cell::new(ReadStats { content: 0, properties: 0, parsed: 0 }) };
+}
+
+/// Record read from disk in current thread
+pub(crate) fn record(kind: ReadKind) {
+    READ_STATS.with(|stats| {
+        let mut value = stats.get();
+        match kind {
+            ReadKind::Content => value.content += 1,
+            ReadKind::Properties => value.properties += 1,
+            ReadKind::Parsed => value.parsed += 1,
+        }
+        stats.set(value);
+    });
+}
+
+/// Run `f` and count reads of notes from disk in current thread
+///
+/// # Example
+/// ```no_run
+/// use obsidian_parser::note::read_stats::collect_read_stats;
+/// use obsidian_parser::prelude::*;
+///
+/// let note: NoteOnDisk = NoteOnDisk::from_file("note.md").unwrap();
+///
+/// let (_, stats) = collect_read_stats(|| {
+///     let _ = note.content();
+///     let _ = note.content();
+/// });
+///
+/// assert_eq!(stats.content, 2);
+/// ```
+pub fn collect_read_stats<R>(f: impl FnOnce() -> R) -> (R, ReadStats) {
+    let before = READ_STATS.with(Cell::get);
+    let result = f();
+    let after = READ_STATS.with(Cell::get);
+
+    (result, after.saturating_sub(before))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::prelude::*;
+    use std::io::Write;
+    use tempfile::NamedTempFile;
+
+    fn create_note() -> (NamedTempFile, NoteOnDisk) {
+        let mut file = NamedTempFile::new().unwrap();
+        file.write_all(b"---\ntopic: life\n---\nContent").unwrap();
+
+        let note = NoteOnDisk::from_file(file.path()).unwrap();
+        (file, note)
+    }
+
+    #[tracing_test::traced_test]
+    #[test]
+    fn double_pass_on_disk() {
+        let (_file, note) = create_note();
+
+        let ((), stats) = collect_read_stats(|| {
+            for _ in 0..2 {
+                note.content().unwrap();
+                note.properties().unwrap();
+            }
+            note.parsed().unwrap();
+        });
+
+        assert_eq!(
+            stats,
+            ReadStats {
+                content: 2,
+                properties: 2,
+                parsed: 1
+            }
+        );
+        assert_eq!(stats.total(), 5);
+    }
+
+    #[tracing_test::traced_test]
+    #[test]
+    fn parts_reads_once() {
+        let (_file, note) = create_note();
+
+        let ((properties, content), stats) = collect_read_stats(|| note.parts().unwrap());
+
+        assert_eq!(properties.unwrap()["topic"], "life");
+        assert_eq!(content, "Content");
+        assert_eq!(stats.total(), 1);
+    }
+
+    #[tracing_test::traced_test]
+    #[test]
+    fn once_cell_reads_once() {
+        let mut file = NamedTempFile::new().unwrap();
+        file.write_all(b"Content").unwrap();
+        let note: NoteOnceCell = NoteOnceCell::from_file(file.path()).unwrap();
+
+        let ((), stats) = collect_read_stats(|| {
+            note.content().unwrap();
+            note.content().unwrap();
+        });
+
+        assert_eq!(stats.content, 1);
+    }
+
+    #[tracing_test::traced_test]
+    #[test]
+    fn nested() {
+        let (_file, note) = create_note();
+
+        let (inner, outer) = collect_read_stats(|| {
+            note.content().unwrap();
+            collect_read_stats(|| note.content().unwrap()).1
+        });
+
+        assert_eq!(inner.content, 1);
+        assert_eq!(outer.content, 2);
+    }
+}
@@ -0,0 +1,145 @@
+//! Tasks (`- [ ] task`) of note
+
+use super::code_block::lines_outside_code_blocks;
+
+/// Task (checkbox) of note, like `- [x] Done`
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Task {
+    /// Task is completed (`[x]` or `[X]`)
+    pub done: bool,
+
+    /// Rest of line after checkbox
+    pub text: String,
+
+    /// Number of line in content (from `1`)
+    pub line: usize,
+}
+
+/// Parse line like `- [ ] task`. Returns `(done, text)`
+///
+/// Supports bullets `-`, `*`, `+` and ordered lists (`1.`, `1)`)
+pub(crate) fn parse_task(line: &str) -> Option<(bool, &str)> {
+    let line = line.trim_start();
+
+    let rest = if let Some(rest) = line.strip_prefix(['-', '*', '+']) {
+        rest
+    } else {
+        let without_digits = line.trim_start_matches(|c: char| c.is_ascii_digit());
+        if without_digits.len() == line.len() {
+            return None;
+        }
+
+        without_digits.strip_prefix(['.', ')'])?
+    };
+
+    let rest = rest.strip_prefix([' ', '\t'])?.trim_start();
+    let done = match rest.get(..3)? {
+        "[ ]" => false,
+        "[x]" | "[X]" => true,
+        _ => return None,
+    };
+
+    let text = &rest[3..];
+    if !(text.is_empty() || text.starts_with([' ', '\t'])) {
+        return None;
+    }
+
+    Some((done, text.trim()))
+}
+
+/// Parse tasks from content of note
+///
+/// Tasks inside fenced code blocks are ignored
+///
+/// # Example
+/// ```
+/// use obsidian_parser::note::tasks::parse_tasks;
+///
+/// let content = "Plan:\n- [x] Write code\n- [ ] Write tests";
+/// let tasks = parse_tasks(content);
+///
+/// assert_eq!(tasks.len(), 2);
+/// assert!(tasks[0].done);
+/// assert_eq!(tasks[1].text, "Write tests");
+/// assert_eq!(tasks[1].line, 3);
+/// ```
+#[must_use]
+pub fn parse_tasks(content: &str) -> Vec<Task> {
+    lines_outside_code_blocks(content)
+        .filter_map(|(i, line)| {
+            let (done, text) = parse_task(line)?;
+
+            Some(Task {
+                done,
+                text: text.to_string(),
+                line: i + 1,
+            })
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[cfg_attr(feature = "tracing", tracing_test::traced_test)]
+    #[test]
+    fn bullets() {
+        for (line, done, text) in [
+            ("- [ ] task", false, "task"),
+            ("- [x] task", true, "task"),
+            ("- [X] task", true, "task"),
+            ("* [ ] task", false, "task"),
+            (
+                "+ [x] task with [[link]] #tag",
+                true,
+                "task with [[link]] #tag",
+            ),
+            ("  - [ ] nested", false, "nested"),
+            ("1. [ ] ordered", false, "ordered"),
+            ("2) [x] ordered", true, "ordered"),
+            ("- [ ]", false, ""),
+        ] {
+            assert_eq!(parse_task(line), Some((done, text)), "{line}");
+        }
+    }
+
+    #[cfg_attr(feature = "tracing", tracing_test::traced_test)]
+    #[test]
+    fn not_tasks() {
+        for line in [
+            "-[ ] task",
+            "[ ] task",
+            "- [] task",
+            "- [-] cancelled",
+            "- [ ]task",
+            "text - [ ] task",
+            "1 [ ] task",
+            "- text",
+        ] {
+            assert_eq!(parse_task(line), None, "{line}");
+        }
+    }
+
+    #[cfg_attr(feature = "tracing", tracing_test::traced_test)]
+    #[test]
+    fn skip_code_blocks() {
+        let content = "- [ ] one\n```markdown\n- [ ] in code\n```\n- [x] two";
+
+        assert_eq!(
+            parse_tasks(content),
+            [
+                Task {
+                    done: false,
+                    text: "one".to_string(),
+                    line: 1
+                },
+                Task {
+                    done: true,
+                    text: "two".to_string(),
+                    line: 5
+                }
+            ]
+        );
+    }
+}
@@ -0,0 +1,136 @@
+//! Markdown-aware word count for [`Note::count_words_markdown_aware`](super::Note::count_words_markdown_aware)
+
+use super::code_block::lines_outside_code_blocks;
+use super::parser::ParsedLink;
+
+/// Count words in markdown content
+///
+/// Heuristic:
+/// 1. Lines inside fenced code blocks (and fences) are skipped
+/// 2. Wikilink `[[Note|Alias]]` is replaced by its display text: alias or target
+/// 3. Markdown link `[text](url)` is replaced by `text`
+/// 4. Embeds (`![[...]]`) and images (`![alt](url)`) are removed
+/// 5. Rest is split on whitespaces. Bare URLs (`http://`, `https://`) and tokens
+///    without letters or digits (like `-`, `#`, `>` and `**`) are not words
+pub fn count_words_markdown(content: &str) -> usize {
+    lines_outside_code_blocks(content)
+        .map(|(_, line)| {
+            strip_links(line)
+                .split_whitespace()
+                .filter(|word| is_word(word))
+                .count()
+        })
+        .sum()
+}
+
+/// Token is word: has letter or digit and is not bare URL
+fn is_word(token: &str) -> bool {
+    let is_url = token.starts_with("http://") || token.starts_with("https://");
+
+    !is_url && token.chars().any(char::is_alphanumeric)
+}
+
+/// Replace links in line by their display text. Embeds and images are removed
+fn strip_links(line: &str) -> String {
+    let mut result = String::with_capacity(line.len());
+    let mut rest = line;
+
+    while let Some(start) = rest.find('[') {
+        result.push_str(&rest[..start]);
+        let after = &rest[start..];
+        let is_embed = result.ends_with('!');
+
+        if let Some(end) = after.strip_prefix("[[").and_then(|inner| inner.find("]]")) {
+            let inner = &after[2..2 + end];
+            rest = &after[end + 4..];
+
+            if is_embed {
+                result.pop();
+            } else {
+                let parsed = ParsedLink::parse(inner);
+                result.push_str(parsed.alias.unwrap_or(parsed.target));
+            }
+        } else if let Some((text, len)) = markdown_link(after) {
+            rest = &after[len..];
+
+            if is_embed {
+                result.pop();
+            } else {
+                result.push_str(text);
+            }
+        } else {
+            result.push('[');
+            rest = &after[1..];
+        }
+    }
+
+    result.push_str(rest);
+    result
+}
+
+/// Parse markdown link `[text](url)` at start of `text`
+///
+/// Returns text of link and length of whole link
+fn markdown_link(text: &str) -> Option<(&str, usize)> {
+    let text_end = text.find(']')?;
+    let url = text[text_end + 1..].strip_prefix('(')?;
+    let url_end = url.find(')')?;
+
+    Some((&text[1..text_end], text_end + 2 + url_end + 1))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[cfg_attr(feature = "tracing", tracing_test::traced_test)]
+    #[test]
+    fn plain_text() {
+        assert_eq!(count_words_markdown("One two\nthree"), 3);
+        assert_eq!(count_words_markdown(""), 0);
+    }
+
+    #[cfg_attr(feature = "tracing", tracing_test::traced_test)]
+    #[test]
+    fn wikilinks() {
+        assert_eq!(count_words_markdown("See [[Some Note|Alias]]"), 2);
+        assert_eq!(count_words_markdown("See [[A B]] and [[C#Heading]]"), 5);
+        assert_eq!(
+            count_words_markdown("![[image.png]] ![[Some Note]] text"),
+            1
+        );
+    }
+
+    #[cfg_attr(feature = "tracing", tracing_test::traced_test)]
+    #[test]
+    fn markdown_links_and_urls() {
+        assert_eq!(
+            count_words_markdown("[Rust book](https://doc.rust-lang.org) is good"),
+            4
+        );
+        assert_eq!(
+            count_words_markdown("![alt text](image.png) https://a.b"),
+            0
+        );
+        assert_eq!(count_words_markdown("[not link] and [x]"), 4);
+    }
+
+    #[cfg_attr(feature = "tracing", tracing_test::traced_test)]
+    #[test]
+    fn emphasis_and_markup() {
+        assert_eq!(
+            count_words_markdown("# Title\n- **bold** and _it_\n> quote"),
+            5
+        );
+        assert_eq!(count_words_markdown("** --- ~~ =="), 0);
+    }
+
+    #[cfg_attr(feature = "tracing", tracing_test::traced_test)]
+    #[test]
+    fn fenced_code() {
+        assert_eq!(
+            count_words_markdown("Before\n```rust\nfn main() {}\n```\nafter"),
+            2
+        );
+    }
+}
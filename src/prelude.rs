@@ -1,18 +1,28 @@
 //! All prelude
 
 pub use crate::note::note_aliases::NoteAliases;
+pub use crate::note::note_any::AnyNote;
+pub use crate::note::note_arc::NoteArc;
 pub use crate::note::note_in_memory::NoteInMemory;
 pub use crate::note::note_is_todo::NoteIsTodo;
 pub use crate::note::note_on_disk::NoteOnDisk;
 pub use crate::note::note_once_cell::NoteOnceCell;
 pub use crate::note::note_once_lock::NoteOnceLock;
+pub use crate::note::note_properties_mapping::NotePropertiesMapping;
 pub use crate::note::note_tags::NoteTags;
+pub use crate::note::parser::FrontmatterFormat;
 pub use crate::note::{Note, NoteDefault, NoteFromReader, NoteFromString};
 pub use crate::vault::vault_open::{IteratorVaultBuilder, VaultBuilder, VaultOptions};
-pub use crate::vault::{Vault, VaultInMemory, VaultOnDisk, VaultOnceCell, VaultOnceLock};
+pub use crate::vault::{Vault, VaultArc, VaultInMemory, VaultOnDisk, VaultOnceCell, VaultOnceLock};
 
 #[cfg(not(target_family = "wasm"))]
-pub use crate::note::{NoteFromFile, NoteWrite};
+pub use crate::note::{NoteFromFile, NoteWrite, note_write::FrontmatterStyle};
+
+#[cfg(not(target_family = "wasm"))]
+pub use crate::vault::vault_any::AnyVault;
 
 #[cfg(feature = "rayon")]
 pub use crate::vault::vault_open::ParallelIteratorVaultBuilder;
+
+#[cfg(feature = "jsonschema")]
+pub use crate::note::note_validate::NoteValidate;
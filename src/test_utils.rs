@@ -11,7 +11,12 @@ where
             return true;
         }
 
-        source = err.source();
+        // `std::io::Error` returns source of wrapped error, not wrapped error itself
+        source = err
+            .downcast_ref::<std::io::Error>()
+            .and_then(std::io::Error::get_ref)
+            .map(|inner| inner as &(dyn std::error::Error + 'static))
+            .or_else(|| err.source());
     }
 
     false
@@ -14,3 +14,56 @@ pub enum Error {
     #[error("Path: `{0}` is not a directory")]
     IsNotDir(PathBuf),
 }
+
+/// Errors for opening [`Vault`] with [`VaultBuilder::into_iter_cancellable`]
+///
+/// [`Vault`]: crate::prelude::Vault
+/// [`VaultBuilder::into_iter_cancellable`]: crate::vault::vault_open::VaultBuilder::into_iter_cancellable
+#[derive(Debug, Error)]
+pub enum OpenError<E>
+where
+    E: std::error::Error,
+{
+    /// Opening was cancelled by flag
+    #[error("Opening vault was cancelled")]
+    Cancelled,
+
+    /// Error from note
+    #[error(transparent)]
+    Note(E),
+}
+
+/// Error of saving note with [`Vault::save`]
+///
+/// [`Vault`]: crate::prelude::Vault
+/// [`Vault::save`]: crate::prelude::Vault::save
+#[derive(Debug, Error)]
+#[error("Failed to save note `{}`: {source}", path.display())]
+pub struct SaveError<E>
+where
+    E: std::error::Error + 'static,
+{
+    /// Path of note, which failed to save
+    pub path: PathBuf,
+
+    /// Error from note
+    #[source]
+    pub source: E,
+}
+
+/// Note, which failed to open, collected by [`VaultBuilder::build_collecting`]
+///
+/// [`VaultBuilder::build_collecting`]: crate::vault::vault_open::VaultBuilder::build_collecting
+#[derive(Debug, Error)]
+#[error("Failed to open note `{}`: {source}", path.display())]
+pub struct CollectedError<E>
+where
+    E: std::error::Error + 'static,
+{
+    /// Path of note
+    pub path: PathBuf,
+
+    /// Error from note
+    #[source]
+    pub source: E,
+}
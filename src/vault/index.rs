@@ -0,0 +1,241 @@
+//! Index of notes for resolve links
+//!
+//! Used by graph in [`vault_petgraph`](crate::vault::vault_petgraph) and link analysis
+//! in [`vault_links`](crate::vault::vault_links), so both resolve links the same way
+
+use super::Vault;
+use crate::note::{Note, parser::normalize_link};
+use std::collections::HashMap;
+
+/// Index of notes for resolve links
+///
+/// - Link with `/` is resolved by full path (relative to vault, without `.md`)
+/// - Other links are resolved by note name. If several notes share a name, first inserted wins
+/// - Canvases (see [`is_canvas`]) are not notes, links are never resolved to them
+///
+/// All keys are normalized by [`normalize_link`]. Optionally keys are lowercased
+/// and normalized to Unicode NFC with `unicode` feature (see [`Index::new`])
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Index<V> {
+    full: HashMap<String, V>,
+    short: HashMap<String, V>,
+    case_insensitive: bool,
+    normalize_unicode: bool,
+}
+
+impl<V> Default for Index<V> {
+    fn default() -> Self {
+        Self::new(false, false)
+    }
+}
+
+impl<V> Index<V> {
+    /// Create empty index
+    ///
+    /// - `case_insensitive`: `[[note]]` resolves to `Note.md`
+    /// - `normalize_unicode`: composed (`é`) and decomposed (`e` + `◌́`) forms are equal (NFC).
+    ///   Ignored without `unicode` feature
+    pub(crate) fn new(case_insensitive: bool, normalize_unicode: bool) -> Self {
+        #[cfg(all(feature = "tracing", not(feature = "unicode")))]
+        if normalize_unicode {
+            tracing::warn!(
+                "Unicode normalization requires `unicode` feature, links are not normalized"
+            );
+        }
+
+        Self {
+            full: HashMap::default(),
+            short: HashMap::default(),
+            case_insensitive,
+            normalize_unicode,
+        }
+    }
+
+    fn key(&self, link: &str) -> String {
+        let mut key = normalize_link(link);
+
+        #[cfg(feature = "unicode")]
+        if self.normalize_unicode {
+            use unicode_normalization::UnicodeNormalization;
+
+            key = key.nfc().collect();
+        }
+
+        if self.case_insensitive {
+            key = key.to_lowercase();
+        }
+
+        key
+    }
+}
+
+impl Index<usize> {
+    /// Create index, where value is position of note in [`Vault::notes`]
+    ///
+    /// Notes without [`Note::path`] and canvases are skipped
+    pub(crate) fn from_vault<N>(vault: &Vault<N>) -> Self
+    where
+        N: Note,
+    {
+        let mut index = Self::default();
+
+        for (i, note) in vault.notes().iter().enumerate() {
+            if is_canvas(note) {
+                continue;
+            }
+
+            let (Some(full), Some(short)) = (relative_path(note, vault.path()), note.note_name())
+            else {
+                continue;
+            };
+
+            index.insert(&full, &short, i);
+        }
+
+        index
+    }
+}
+
+/// Note is canvas: its extension is `canvas`
+///
+/// Canvas is opened as note (see [`VaultBuilder::extensions`]), but its content is JSON.
+/// So canvas has the same name as `.md` note next to it and links in JSON are not links of note
+///
+/// [`VaultBuilder::extensions`]: crate::vault::vault_open::VaultBuilder::extensions
+pub fn is_canvas<N>(note: &N) -> bool
+where
+    N: Note,
+{
+    note.path().is_some_and(|path| {
+        path.extension()
+            .is_some_and(|extension| extension.eq_ignore_ascii_case("canvas"))
+    })
+}
+
+/// Get path of note relative to `strip_prefix` without `.md`
+///
+/// For example `/vault/dir/note.md` with prefix `/vault` is `dir/note`
+pub fn relative_path<N>(note: &N, strip_prefix: &std::path::Path) -> Option<String>
+where
+    N: Note,
+{
+    let path = note.path()?;
+    let relative_path = path.strip_prefix(strip_prefix).unwrap_or(&path);
+
+    Some(
+        relative_path
+            .with_extension("")
+            .to_string_lossy()
+            .to_string(),
+    )
+}
+
+impl<V> Index<V>
+where
+    V: Copy,
+{
+    pub(crate) fn insert(&mut self, full_path: &str, short_path: &str, value: V) {
+        self.full.insert(self.key(full_path), value);
+        self.short.entry(self.key(short_path)).or_insert(value);
+    }
+
+    #[inline]
+    pub(crate) fn full(&self, full_path: &str) -> Option<&V> {
+        self.full.get(&self.key(full_path))
+    }
+
+    pub(crate) fn get(&self, key: &str) -> Option<&V> {
+        let key = self.key(key);
+
+        if key.contains('/') {
+            self.full.get(&key)
+        } else {
+            self.short.get(&key)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[cfg_attr(feature = "tracing", tracing_test::traced_test)]
+    #[test]
+    fn insert_and_get() {
+        let mut index = Index::default();
+        index.insert("123/123", "123", 3);
+
+        assert_eq!(index.get("123"), Some(&3));
+        assert_eq!(index.get("123/123"), Some(&3));
+    }
+
+    #[cfg_attr(feature = "tracing", tracing_test::traced_test)]
+    #[test]
+    fn full() {
+        let mut index = Index::default();
+        index.insert("123/123", "123", 3);
+
+        assert_eq!(index.full("123/123"), Some(&3));
+        assert_eq!(index.full("123"), None);
+        assert_eq!(index.get("123"), Some(&3));
+    }
+
+    #[cfg_attr(feature = "tracing", tracing_test::traced_test)]
+    #[test]
+    fn get_normalized() {
+        let mut index = Index::default();
+        index.insert("dir/My  note", "My  note", 1);
+
+        assert_eq!(index.get("My note"), Some(&1));
+        assert_eq!(index.get(" My note.md"), Some(&1));
+        assert_eq!(index.get("dir/My note.md"), Some(&1));
+        assert_eq!(index.full("dir/My note"), Some(&1));
+    }
+
+    #[cfg(feature = "unicode")]
+    #[cfg_attr(feature = "tracing", tracing_test::traced_test)]
+    #[test]
+    fn case_insensitive_and_unicode() {
+        let mut strict = Index::default();
+        let mut relaxed = Index::new(true, true);
+        for index in [&mut strict, &mut relaxed] {
+            index.insert("Dir/Café", "Café", 1);
+        }
+
+        assert_eq!(strict.get("Café"), Some(&1));
+        assert_eq!(strict.get("café"), None);
+        assert_eq!(strict.get("Cafe\u{301}"), None);
+
+        assert_eq!(relaxed.get("café"), Some(&1));
+        assert_eq!(relaxed.get("CAFE\u{301}"), Some(&1));
+        assert_eq!(relaxed.get("dir/cafe\u{301}"), Some(&1));
+        assert_eq!(relaxed.full("DIR/CAFÉ"), Some(&1));
+    }
+
+    #[cfg_attr(feature = "tracing", tracing_test::traced_test)]
+    #[test]
+    fn first_short_name_wins() {
+        let mut index = Index::default();
+        index.insert("a/note", "note", 0);
+        index.insert("b/note", "note", 1);
+
+        assert_eq!(index.get("note"), Some(&0));
+        assert_eq!(index.get("a/note"), Some(&0));
+        assert_eq!(index.get("b/note"), Some(&1));
+    }
+
+    #[cfg_attr(feature = "tracing", tracing_test::traced_test)]
+    #[test]
+    fn from_vault() {
+        let (vault, _path, _files) = crate::vault::vault_test::create_test_vault().unwrap();
+        let index = Index::from_vault(&vault);
+
+        for (i, note) in vault.notes().iter().enumerate() {
+            let full = relative_path(note, vault.path()).unwrap();
+            assert_eq!(index.full(&full), Some(&i));
+        }
+
+        assert!(index.get("link").is_some());
+        assert!(index.get("not_found").is_none());
+    }
+}
@@ -7,20 +7,65 @@
 //! by reading files on-demand rather than loading everything into memory upfront.
 
 pub mod error;
+mod index;
+pub mod vault_aliases;
 pub mod vault_duplicates;
+pub mod vault_headings;
+pub mod vault_links;
+pub mod vault_name_index;
+pub mod vault_name_transform;
 pub mod vault_open;
+pub mod vault_similarity;
+pub mod vault_stats;
+pub mod vault_tags;
+
+#[cfg(not(target_family = "wasm"))]
+pub mod vault_any;
+
+#[cfg(not(target_family = "wasm"))]
+pub mod vault_map;
+
+#[cfg(not(target_family = "wasm"))]
+pub mod vault_save;
 
 #[cfg(feature = "petgraph")]
 #[cfg_attr(docsrs, doc(cfg(feature = "petgraph")))]
 pub mod vault_petgraph;
 
+#[cfg(feature = "canvas")]
+#[cfg_attr(docsrs, doc(cfg(feature = "canvas")))]
+pub mod vault_canvas;
+
+#[cfg(feature = "json")]
+#[cfg_attr(docsrs, doc(cfg(feature = "json")))]
+pub mod vault_graph_json;
+
+#[cfg(feature = "json")]
+#[cfg_attr(docsrs, doc(cfg(feature = "json")))]
+pub mod vault_property_types;
+
+#[cfg(feature = "jsonschema")]
+#[cfg_attr(docsrs, doc(cfg(feature = "jsonschema")))]
+pub mod vault_validate;
+
+#[cfg(feature = "serde")]
+#[cfg_attr(docsrs, doc(cfg(feature = "serde")))]
+pub mod vault_serialize;
+
+#[cfg(feature = "zip")]
+#[cfg_attr(docsrs, doc(cfg(feature = "zip")))]
+pub mod vault_zip;
+
 #[cfg(test)]
 mod vault_test;
 
 use crate::note::DefaultProperties;
 use crate::note::Note;
-use crate::prelude::{NoteInMemory, NoteOnDisk, NoteOnceCell, NoteOnceLock};
+use crate::note::{NoteFromString, note_in_memory};
+use crate::prelude::{NoteArc, NoteInMemory, NoteOnDisk, NoteOnceCell, NoteOnceLock};
+use serde::de::DeserializeOwned;
 use std::path::{Path, PathBuf};
+use vault_name_transform::NameTransform;
 
 /// Vault, but used [`NoteOnDisk`]
 pub type VaultOnDisk<T = DefaultProperties> = Vault<NoteOnDisk<T>>;
@@ -31,6 +76,9 @@ pub type VaultOnceCell<T = DefaultProperties> = Vault<NoteOnceCell<T>>;
 /// Vault, but used [`NoteOnceLock`]
 pub type VaultOnceLock<T = DefaultProperties> = Vault<NoteOnceLock<T>>;
 
+/// Vault, but used [`NoteArc`]
+pub type VaultArc<T = DefaultProperties> = Vault<NoteArc<T>>;
+
 /// Vault, but used [`NoteInMemory`]
 pub type VaultInMemory<T = DefaultProperties> = Vault<NoteInMemory<T>>;
 
@@ -48,12 +96,40 @@ where
 
     /// Path to vault root directory
     path: PathBuf,
+
+    /// Transform for [`Vault::display_name`]
+    name_transform: NameTransform,
 }
 
 impl<N> Vault<N>
 where
     N: Note,
 {
+    /// Create vault from already parsed notes without touching filesystem
+    ///
+    /// `path` is root of vault: paths of notes should be inside it, so links are resolved
+    /// by path relative to `path`
+    ///
+    /// # Example
+    /// ```
+    /// use obsidian_parser::prelude::*;
+    ///
+    /// let mut note = NoteInMemory::from_string_default("[[other]]").unwrap();
+    /// note.set_path(Some("vault/note.md".into()));
+    ///
+    /// let vault = Vault::from_notes(vec![note], "vault");
+    /// assert_eq!(vault.count_notes(), 1);
+    /// assert_eq!(vault.path(), std::path::Path::new("vault"));
+    /// ```
+    #[must_use]
+    pub fn from_notes(notes: Vec<N>, path: impl Into<PathBuf>) -> Self {
+        Self {
+            notes,
+            path: path.into(),
+            name_transform: NameTransform::default(),
+        }
+    }
+
     /// Get notes
     #[must_use]
     #[inline]
@@ -61,6 +137,12 @@ where
         &self.notes
     }
 
+    /// Iterate over notes. Same as `vault.notes().iter()`
+    #[inline]
+    pub fn iter(&self) -> std::slice::Iter<'_, N> {
+        self.notes.iter()
+    }
+
     /// Get mutables notes
     #[must_use]
     #[inline]
@@ -81,6 +163,346 @@ where
     pub fn path(&self) -> &Path {
         &self.path
     }
+
+    /// Transform every note with `f`
+    ///
+    /// Path of vault and name transform (see [`Vault::set_name_transform`]) are kept
+    ///
+    /// # Example
+    /// ```no_run
+    /// use obsidian_parser::prelude::*;
+    ///
+    /// let options = VaultOptions::new("/path/to/vault");
+    /// let vault: VaultOnDisk = VaultBuilder::new(&options)
+    ///     .into_iter()
+    ///     .filter_map(Result::ok)
+    ///     .build_vault(&options);
+    ///
+    /// // Load all notes to memory
+    /// let vault: VaultInMemory = vault.map(|note| {
+    ///     NoteInMemory::from_file_default(note.path().unwrap()).unwrap()
+    /// });
+    /// ```
+    #[must_use]
+    pub fn map<M>(self, f: impl FnMut(N) -> M) -> Vault<M>
+    where
+        M: Note,
+    {
+        Vault {
+            notes: self.notes.into_iter().map(f).collect(),
+            path: self.path,
+            name_transform: self.name_transform,
+        }
+    }
+
+    /// Borrowing view of vault with notes, for which `pred` returns `true`
+    ///
+    /// Notes are not cloned, so it is cheap way to run analysis (graph, tags and so on)
+    /// on part of vault. Path of vault and name transform are kept
+    ///
+    /// # Example
+    /// ```no_run
+    /// use obsidian_parser::prelude::*;
+    ///
+    /// let options = VaultOptions::new("/path/to/vault");
+    /// let vault: VaultInMemory = VaultBuilder::new(&options)
+    ///     .into_iter()
+    ///     .filter_map(Result::ok)
+    ///     .build_vault(&options);
+    ///
+    /// let without_archive = vault.filtered_view(|note| {
+    ///     !note.path().unwrap().starts_with("/path/to/vault/archive")
+    /// });
+    ///
+    /// println!("Notes outside archive: {}", without_archive.count_notes());
+    /// ```
+    #[must_use]
+    pub fn filtered_view(&self, pred: impl Fn(&N) -> bool) -> Vault<&N> {
+        Vault {
+            notes: self.notes.iter().filter(|note| pred(note)).collect(),
+            path: self.path.clone(),
+            name_transform: self.name_transform.clone(),
+        }
+    }
+
+    /// Keep only notes for which `f` returns `true`
+    ///
+    /// Order of notes is kept. Indexes and graphs built from vault
+    /// (like [`NameIndex`](vault_name_index::NameIndex)) borrow it, so they must be dropped before
+    /// and built again after this call
+    ///
+    /// # Example
+    /// ```no_run
+    /// use obsidian_parser::prelude::*;
+    ///
+    /// let options = VaultOptions::new("/path/to/vault");
+    /// let mut vault: VaultInMemory = VaultBuilder::new(&options)
+    ///     .into_iter()
+    ///     .filter_map(Result::ok)
+    ///     .build_vault(&options);
+    ///
+    /// vault.retain_notes(|note| note.note_name().as_deref() != Some("Inbox"));
+    /// ```
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip_all, fields(path = %self.path.display(), count_notes = %self.notes.len())))]
+    pub fn retain_notes<F>(&mut self, f: F)
+    where
+        F: FnMut(&N) -> bool,
+    {
+        self.notes.retain(f);
+
+        #[cfg(feature = "tracing")]
+        tracing::debug!("Retained {} notes", self.notes.len());
+    }
+
+    /// Same as [`Vault::retain_notes`], but `f` can fail (like when it reads [`Note::content`])
+    ///
+    /// # Errors
+    /// First error of `f`. Vault is not changed in this case
+    ///
+    /// # Example
+    /// ```no_run
+    /// use obsidian_parser::prelude::*;
+    ///
+    /// let options = VaultOptions::new("/path/to/vault");
+    /// let mut vault: VaultOnceLock = VaultBuilder::new(&options)
+    ///     .into_iter()
+    ///     .filter_map(Result::ok)
+    ///     .build_vault(&options);
+    ///
+    /// // Drop empty notes and TODOs
+    /// vault
+    ///     .try_retain_notes(|note| Ok(!note.content()?.is_empty() && !note.is_todo()?))
+    ///     .unwrap();
+    /// ```
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip_all, fields(path = %self.path.display(), count_notes = %self.notes.len())))]
+    pub fn try_retain_notes<F>(&mut self, mut f: F) -> Result<(), N::Error>
+    where
+        F: FnMut(&N) -> Result<bool, N::Error>,
+    {
+        let keep = self
+            .notes
+            .iter()
+            .map(&mut f)
+            .collect::<Result<Vec<_>, _>>()?;
+
+        let mut keep = keep.into_iter();
+        self.notes.retain(|_| keep.next().unwrap_or(true));
+
+        #[cfg(feature = "tracing")]
+        tracing::debug!("Retained {} notes", self.notes.len());
+
+        Ok(())
+    }
+
+    /// New vault with clones of notes, for which `f` returns `true`
+    ///
+    /// Made for predicates on [`Note::properties`], but `f` gets whole note.
+    /// Unlike [`Vault::filtered_view`], result owns notes, and unlike [`Vault::try_retain_notes`],
+    /// this vault is not changed. Path of vault and name transform are kept
+    ///
+    /// # Example
+    /// ```
+    /// use obsidian_parser::prelude::*;
+    /// use serde::Deserialize;
+    ///
+    /// #[derive(Debug, Clone, Deserialize)]
+    /// struct Properties {
+    ///     importance: u8,
+    /// }
+    ///
+    /// let vault: VaultInMemory<Properties> = Vault::from_str_notes([
+    ///     ("core", "---\nimportance: 5\n---\n[[draft]]"),
+    ///     ("draft", "---\nimportance: 1\n---\n[[core]]"),
+    /// ])
+    /// .unwrap();
+    ///
+    /// let important = vault
+    ///     .filter_by_property(|note| {
+    ///         Ok(note
+    ///             .properties()?
+    ///             .is_some_and(|properties| properties.importance >= 3))
+    ///     })
+    ///     .unwrap();
+    ///
+    /// assert_eq!(important.count_notes(), 1);
+    /// assert_eq!(important.notes()[0].note_name().unwrap(), "core");
+    /// ```
+    ///
+    /// # Errors
+    /// First error of `f`
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip_all, fields(path = %self.path.display(), count_notes = %self.notes.len())))]
+    pub fn filter_by_property<F>(&self, mut f: F) -> Result<Self, N::Error>
+    where
+        N: Clone,
+        F: FnMut(&N) -> Result<bool, N::Error>,
+    {
+        let mut notes = Vec::new();
+        for note in &self.notes {
+            if f(note)? {
+                notes.push(note.clone());
+            }
+        }
+
+        #[cfg(feature = "tracing")]
+        tracing::debug!("Filtered {} notes", notes.len());
+
+        Ok(Self {
+            notes,
+            path: self.path.clone(),
+            name_transform: self.name_transform.clone(),
+        })
+    }
+}
+
+#[cfg(not(target_family = "wasm"))]
+impl<N> Vault<N>
+where
+    N: Note,
+{
+    /// Returns notes with modification time (see [`Note::modified`]) newer than `time`
+    ///
+    /// Useful for incremental processing: save time of the last run and process only changed notes.
+    /// Notes without modification time (like without [`Note::path`]) are skipped
+    ///
+    /// # Example
+    /// ```no_run
+    /// use obsidian_parser::prelude::*;
+    /// use std::time::{Duration, SystemTime};
+    ///
+    /// let options = VaultOptions::new("/path/to/vault");
+    /// let vault: VaultOnDisk = VaultBuilder::new(&options)
+    ///     .into_iter()
+    ///     .filter_map(Result::ok)
+    ///     .build_vault(&options);
+    ///
+    /// let last_run = SystemTime::now() - Duration::from_secs(24 * 60 * 60);
+    /// for note in vault.notes_modified_since(last_run).unwrap() {
+    ///     println!("Changed: {:?}", note.path());
+    /// }
+    /// ```
+    ///
+    /// # Errors
+    /// Error from [`Note::modified`]
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip(self), fields(path = %self.path.display(), count_notes = %self.notes.len())))]
+    pub fn notes_modified_since(&self, time: std::time::SystemTime) -> Result<Vec<&N>, N::Error> {
+        let mut result = Vec::new();
+
+        for note in &self.notes {
+            if note.modified()?.is_some_and(|modified| modified > time) {
+                result.push(note);
+            }
+        }
+
+        #[cfg(feature = "tracing")]
+        tracing::debug!("Found {} modified notes", result.len());
+
+        Ok(result)
+    }
+}
+
+impl<T> Vault<NoteInMemory<T>>
+where
+    T: DeserializeOwned + Clone,
+{
+    /// Create vault from `(name, text)` pairs without touching filesystem (useful for tests and WASM)
+    ///
+    /// Every `text` is parsed with [`NoteFromString`]. `name` is path of note relative
+    /// to vault (like `dir/note`), `.md` is added if missing. Path of vault is empty
+    ///
+    /// # Example
+    /// ```
+    /// use obsidian_parser::prelude::*;
+    ///
+    /// let vault: VaultInMemory = VaultInMemory::from_str_notes([
+    ///     ("main", "[[dir/other]]"),
+    ///     ("dir/other", "---\ntopic: life\n---\nText"),
+    /// ])
+    /// .unwrap();
+    ///
+    /// assert_eq!(vault.count_notes(), 2);
+    /// assert_eq!(vault.notes()[1].note_name().unwrap(), "other");
+    /// ```
+    ///
+    /// # Errors
+    /// Error of [`NoteInMemory`] if text of note can't be parsed
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip(entries)))]
+    pub fn from_str_notes(
+        entries: impl IntoIterator<Item = (impl AsRef<str>, impl AsRef<str>)>,
+    ) -> Result<Self, note_in_memory::Error> {
+        let notes = entries
+            .into_iter()
+            .map(|(name, text)| {
+                let mut path = PathBuf::from(name.as_ref());
+                if !vault_open::is_md_file(&path) {
+                    path.as_mut_os_string().push(".md");
+                }
+
+                let mut note = NoteInMemory::from_string(text)?;
+                note.set_path(Some(path));
+
+                Ok(note)
+            })
+            .collect::<Result<Vec<_>, note_in_memory::Error>>()?;
+
+        #[cfg(feature = "tracing")]
+        tracing::debug!("Created vault with {} notes", notes.len());
+
+        Ok(Self::from_notes(notes, PathBuf::new()))
+    }
+}
+
+/// Iterate over notes of vault
+///
+/// # Example
+/// ```no_run
+/// use obsidian_parser::prelude::*;
+///
+/// let options = VaultOptions::new("/path/to/vault");
+/// let vault: VaultOnDisk = VaultBuilder::new(&options)
+///     .into_iter()
+///     .filter_map(Result::ok)
+///     .build_vault(&options);
+///
+/// for note in &vault {
+///     println!("{:?}", note.note_name());
+/// }
+/// ```
+impl<'a, N> IntoIterator for &'a Vault<N>
+where
+    N: Note,
+{
+    type Item = &'a N;
+    type IntoIter = std::slice::Iter<'a, N>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+/// Consume vault and iterate over its notes
+///
+/// # Example
+/// ```no_run
+/// use obsidian_parser::prelude::*;
+///
+/// let options = VaultOptions::new("/path/to/vault");
+/// let vault: VaultInMemory = VaultBuilder::new(&options)
+///     .into_iter()
+///     .filter_map(Result::ok)
+///     .build_vault(&options);
+///
+/// let notes: Vec<NoteInMemory> = vault.into_iter().collect();
+/// ```
+impl<N> IntoIterator for Vault<N>
+where
+    N: Note,
+{
+    type Item = N;
+    type IntoIter = std::vec::IntoIter<N>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.notes.into_iter()
+    }
 }
 
 #[cfg(test)]
@@ -90,6 +512,7 @@ mod tests {
         prelude::{IteratorVaultBuilder, VaultBuilder, VaultOptions},
         vault::vault_test::create_files_for_vault,
     };
+    use std::borrow::Cow;
 
     #[cfg_attr(feature = "tracing", tracing_test::traced_test)]
     #[test]
@@ -135,4 +558,242 @@ mod tests {
 
         assert_eq!(vault.path(), path.path());
     }
+
+    #[cfg_attr(feature = "tracing", tracing_test::traced_test)]
+    #[test]
+    fn map() {
+        #[derive(Debug)]
+        struct Wrapper {
+            note: NoteInMemory,
+            count_words: usize,
+        }
+
+        impl Note for Wrapper {
+            type Properties = DefaultProperties;
+            type Error = crate::note::note_in_memory::Error;
+
+            fn properties(&self) -> Result<Option<Cow<'_, Self::Properties>>, Self::Error> {
+                self.note.properties()
+            }
+
+            fn content(&self) -> Result<Cow<'_, str>, Self::Error> {
+                self.note.content()
+            }
+
+            fn path(&self) -> Option<Cow<'_, Path>> {
+                self.note.path()
+            }
+        }
+
+        let (path, files) = create_files_for_vault().unwrap();
+
+        let options = VaultOptions::new(&path);
+        let vault: VaultInMemory = VaultBuilder::new(&options)
+            .into_iter()
+            .map(|file| file.unwrap())
+            .build_vault(&options);
+
+        let mapped = vault.clone().map(|note| Wrapper {
+            count_words: note.count_words_from_content().unwrap(),
+            note,
+        });
+
+        assert_eq!(mapped.path(), vault.path());
+        assert_eq!(mapped.count_notes(), files.len());
+
+        for (wrapper, note) in mapped.notes().iter().zip(vault.notes()) {
+            assert_eq!(&wrapper.note, note);
+            assert_eq!(
+                wrapper.count_words,
+                note.count_words_from_content().unwrap()
+            );
+            assert_eq!(wrapper.note_name(), note.note_name());
+        }
+    }
+
+    #[cfg_attr(feature = "tracing", tracing_test::traced_test)]
+    #[test]
+    fn into_iter() {
+        let (vault, _path, files) = crate::vault::vault_test::create_test_vault().unwrap();
+
+        let mut count = 0;
+        for (note, expected) in (&vault).into_iter().zip(vault.notes()) {
+            assert!(std::ptr::eq(note, expected));
+            count += 1;
+        }
+        assert_eq!(count, files.len());
+
+        let notes = vault.notes().clone();
+        let owned: Vec<_> = vault.into_iter().collect();
+        assert_eq!(owned, notes);
+    }
+
+    #[cfg_attr(feature = "tracing", tracing_test::traced_test)]
+    #[test]
+    fn retain_notes() {
+        let (mut vault, _path, _files) = crate::vault::vault_test::create_test_vault().unwrap();
+
+        vault.retain_notes(|note| note.note_name().unwrap() == "main");
+
+        assert_eq!(vault.count_notes(), 2);
+        assert!(vault.iter().all(|note| note.note_name().unwrap() == "main"));
+    }
+
+    #[cfg_attr(feature = "tracing", tracing_test::traced_test)]
+    #[test]
+    fn try_retain_notes() {
+        let (mut vault, _path, _files) = crate::vault::vault_test::create_test_vault().unwrap();
+        let notes = vault.notes().clone();
+
+        vault
+            .try_retain_notes(|note| Ok(note.count_words_from_content()? > 1))
+            .unwrap();
+
+        let expected: Vec<_> = notes
+            .into_iter()
+            .filter(|note| note.count_words_from_content().unwrap() > 1)
+            .collect();
+        assert_eq!(vault.notes(), &expected);
+    }
+
+    #[cfg_attr(feature = "tracing", tracing_test::traced_test)]
+    #[test]
+    fn try_retain_notes_error() {
+        let (mut vault, _path, _files) = crate::vault::vault_test::create_test_vault().unwrap();
+        let notes = vault.notes().clone();
+
+        let mut checked = 0;
+        let result = vault.try_retain_notes(|_| {
+            checked += 1;
+            if checked == 2 {
+                return Err(std::io::Error::other("error").into());
+            }
+
+            Ok(false)
+        });
+
+        assert!(result.is_err());
+        assert_eq!(vault.notes(), &notes);
+    }
+
+    #[cfg_attr(feature = "tracing", tracing_test::traced_test)]
+    #[test]
+    fn filter_by_property() {
+        let vault = <VaultInMemory>::from_str_notes([
+            ("a", "---\ntopic: life\n---\n[[b]]"),
+            ("b", "---\ntopic: work\n---\n[[a]]"),
+            ("c", "Without properties"),
+            ("d", "---\ntopic: life\n---\n"),
+        ])
+        .unwrap();
+
+        let life = vault
+            .filter_by_property(|note| {
+                Ok(note
+                    .properties()?
+                    .is_some_and(|properties| properties["topic"] == "life"))
+            })
+            .unwrap();
+
+        assert_eq!(life.path(), vault.path());
+        assert_eq!(
+            life.notes(),
+            &[vault.notes()[0].clone(), vault.notes()[3].clone()]
+        );
+        assert_eq!(vault.count_notes(), 4);
+
+        let result = vault.filter_by_property(|note| {
+            if note.properties()?.is_none() {
+                return Err(std::io::Error::other("error").into());
+            }
+
+            Ok(true)
+        });
+        assert!(result.is_err());
+    }
+
+    #[cfg_attr(feature = "tracing", tracing_test::traced_test)]
+    #[test]
+    fn filtered_view() {
+        let (vault, _path, _files) = crate::vault::vault_test::create_test_vault().unwrap();
+
+        let view = vault.filtered_view(|note| note.note_name().unwrap() == "main");
+
+        assert_eq!(view.count_notes(), 2);
+        assert_eq!(view.path(), vault.path());
+        for note in &view {
+            assert!(vault.notes().iter().any(|other| std::ptr::eq(*note, other)));
+        }
+
+        assert_eq!(
+            view.total_word_count().unwrap(),
+            view.iter()
+                .map(|note| note.count_words_from_content().unwrap())
+                .sum::<usize>()
+        );
+    }
+
+    #[cfg(feature = "petgraph")]
+    #[cfg_attr(feature = "tracing", tracing_test::traced_test)]
+    #[test]
+    fn filtered_view_graph() {
+        let (vault, _path) = crate::vault::vault_test::create_vault_with_files(&[
+            ("a.md", "[[b]] [[c]]"),
+            ("b.md", "[[a]] [[c]]"),
+            ("archive/c.md", "[[a]]"),
+        ])
+        .unwrap();
+        assert_eq!(vault.get_digraph().unwrap().edge_count(), 5);
+
+        let view = vault.filtered_view(|note| note.note_name().unwrap() != "c");
+        let graph = view.get_digraph().unwrap();
+
+        assert_eq!(graph.node_count(), 2);
+        assert_eq!(graph.edge_count(), 2);
+    }
+
+    #[cfg_attr(feature = "tracing", tracing_test::traced_test)]
+    #[test]
+    fn notes_modified_since() {
+        let (path, files) = create_files_for_vault().unwrap();
+
+        let past = std::time::SystemTime::now() - std::time::Duration::from_secs(3600);
+        for file in &files {
+            file.set_modified(past).unwrap();
+        }
+
+        let options = VaultOptions::new(&path);
+        let vault: VaultOnDisk = VaultBuilder::new(&options)
+            .into_iter()
+            .map(|note| note.unwrap())
+            .build_vault(&options);
+
+        let since = past + std::time::Duration::from_secs(60);
+        assert!(vault.notes_modified_since(since).unwrap().is_empty());
+
+        let touched = &vault.notes()[1];
+        touched.touch().unwrap();
+
+        let modified = vault.notes_modified_since(since).unwrap();
+        assert_eq!(modified.len(), 1);
+        assert_eq!(modified[0].path(), touched.path());
+
+        assert_eq!(
+            vault
+                .notes_modified_since(past - std::time::Duration::from_secs(60))
+                .unwrap()
+                .len(),
+            files.len()
+        );
+
+        // Without path there is no modification time
+        let note = NoteInMemory::new("Text", None::<DefaultProperties>, None).unwrap();
+        let vault = Vault::from_notes(vec![note], "");
+        assert!(
+            vault
+                .notes_modified_since(std::time::SystemTime::UNIX_EPOCH)
+                .unwrap()
+                .is_empty()
+        );
+    }
 }
@@ -0,0 +1,96 @@
+//! Resolve notes by aliases in vault
+
+use super::Vault;
+use crate::note::note_aliases::NoteAliases;
+
+impl<N> Vault<N>
+where
+    N: NoteAliases,
+{
+    /// Returns note which declares `alias` in frontmatter
+    ///
+    /// # Collisions
+    /// If several notes declare the same alias, the first one in [`Vault::notes`] is returned
+    ///
+    /// # Example
+    /// ```no_run
+    /// use obsidian_parser::prelude::*;
+    ///
+    /// let options = VaultOptions::new("/path/to/vault");
+    /// let vault: VaultInMemory = VaultBuilder::new(&options)
+    ///     .into_iter()
+    ///     .filter_map(Result::ok)
+    ///     .build_vault(&options);
+    ///
+    /// if let Some(note) = vault.note_by_alias("my_alias").unwrap() {
+    ///     println!("Found: {:?}", note.note_name());
+    /// }
+    /// ```
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip(self), fields(path = %self.path.display(), count_notes = %self.notes.len())))]
+    pub fn note_by_alias(&self, alias: &str) -> Result<Option<&N>, N::Error> {
+        #[cfg(feature = "tracing")]
+        tracing::debug!("Find note by alias...");
+
+        for note in self.notes() {
+            if note.aliases()?.iter().any(|note_alias| note_alias == alias) {
+                return Ok(Some(note));
+            }
+        }
+
+        Ok(None)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        note::Note,
+        prelude::{IteratorVaultBuilder, VaultBuilder, VaultInMemory, VaultOptions},
+    };
+    use std::{fs::File, io::Write};
+    use tempfile::TempDir;
+
+    fn create_vault_with_aliases() -> (VaultInMemory, TempDir) {
+        let temp_dir = TempDir::new().unwrap();
+
+        let mut file1 = File::create(temp_dir.path().join("first.md")).unwrap();
+        file1
+            .write_all(b"---\naliases:\n- one\n- uno\n---\nFirst")
+            .unwrap();
+
+        let mut file2 = File::create(temp_dir.path().join("second.md")).unwrap();
+        file2
+            .write_all(b"---\naliases:\n- two\n---\nSecond")
+            .unwrap();
+
+        File::create(temp_dir.path().join("third.md")).unwrap();
+
+        let options = VaultOptions::new(&temp_dir);
+        let vault = VaultBuilder::new(&options)
+            .into_iter()
+            .map(Result::unwrap)
+            .build_vault(&options);
+
+        (vault, temp_dir)
+    }
+
+    #[cfg_attr(feature = "tracing", tracing_test::traced_test)]
+    #[test]
+    fn note_by_alias() {
+        let (vault, _path) = create_vault_with_aliases();
+
+        let first = vault.note_by_alias("uno").unwrap().unwrap();
+        let second = vault.note_by_alias("two").unwrap().unwrap();
+
+        assert_eq!(first.note_name().unwrap(), "first");
+        assert_eq!(second.note_name().unwrap(), "second");
+    }
+
+    #[cfg_attr(feature = "tracing", tracing_test::traced_test)]
+    #[test]
+    fn note_by_alias_not_found() {
+        let (vault, _path) = create_vault_with_aliases();
+
+        assert!(vault.note_by_alias("first").unwrap().is_none());
+    }
+}
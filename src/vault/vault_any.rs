@@ -0,0 +1,173 @@
+//! Open [`Vault`] with note type chosen by size of vault
+
+use super::{Vault, error};
+use crate::{
+    note::{
+        DefaultProperties,
+        note_any::{self, AnyNote},
+    },
+    prelude::{IteratorVaultBuilder, NoteInMemory, NoteOnDisk, VaultBuilder, VaultOptions},
+};
+use serde::de::DeserializeOwned;
+use std::path::{Path, PathBuf};
+use thiserror::Error;
+
+/// Vault, but used [`AnyNote`]
+pub type AnyVault<T = DefaultProperties> = Vault<AnyNote<T>>;
+
+/// Max count of notes, which [`Vault::open_auto`] loads to memory
+///
+/// Same as recommendation for [`NoteInMemory`]
+pub const AUTO_IN_MEMORY_MAX_NOTES: usize = 10_000;
+
+/// Errors for [`Vault::open_auto`]
+#[derive(Debug, Error)]
+pub enum Error {
+    /// Invalid path to vault
+    #[error(transparent)]
+    Vault(#[from] error::Error),
+
+    /// Note in vault can't be opened
+    #[error("Note `{}` error: {source}", path.display())]
+    Note {
+        /// Path to note
+        path: PathBuf,
+
+        /// Error from [`AnyNote`]
+        source: note_any::Error,
+    },
+}
+
+impl<T> Vault<AnyNote<T>>
+where
+    T: Clone + DeserializeOwned,
+{
+    /// Open vault with [`NoteInMemory`] for small vaults (up to [`AUTO_IN_MEMORY_MAX_NOTES`] notes)
+    /// or [`NoteOnDisk`] for large ones
+    ///
+    /// # Example
+    /// ```no_run
+    /// use obsidian_parser::prelude::*;
+    ///
+    /// let vault: AnyVault = Vault::open_auto("/path/to/vault").unwrap();
+    /// println!("Count notes: {}", vault.count_notes());
+    /// ```
+    ///
+    /// # Errors
+    /// - [`Error::Vault`] if `path` is not a directory
+    /// - [`Error::Note`] for the first note, which can't be opened
+    pub fn open_auto(path: impl AsRef<Path>) -> Result<Self, Error> {
+        Self::open_auto_with_limit(path, AUTO_IN_MEMORY_MAX_NOTES)
+    }
+
+    /// Same as [`Vault::open_auto`], but with custom max count of notes in memory
+    ///
+    /// # Errors
+    /// See [`Vault::open_auto`]
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip(path), fields(path = %path.as_ref().display())))]
+    pub fn open_auto_with_limit(
+        path: impl AsRef<Path>,
+        max_in_memory_notes: usize,
+    ) -> Result<Self, Error> {
+        let path = path.as_ref();
+        if !path.is_dir() {
+            return Err(error::Error::IsNotDir(path.to_path_buf()).into());
+        }
+
+        let options = VaultOptions::new(path);
+        let count_notes = VaultBuilder::new(&options)
+            .into_iter::<NoteOnDisk<T>>()
+            .count();
+
+        let mut first_error = None;
+        let notes: Vec<AnyNote<T>> = if count_notes <= max_in_memory_notes {
+            #[cfg(feature = "tracing")]
+            tracing::debug!("Open {count_notes} notes in memory");
+
+            VaultBuilder::new(&options)
+                .on_error::<NoteInMemory<T>>(|path, error| {
+                    first_error.get_or_insert_with(|| (path, error.into()));
+                })
+                .map(AnyNote::from)
+                .collect()
+        } else {
+            #[cfg(feature = "tracing")]
+            tracing::debug!("Open {count_notes} notes on disk");
+
+            VaultBuilder::new(&options)
+                .on_error::<NoteOnDisk<T>>(|path, error| {
+                    first_error.get_or_insert_with(|| (path, error.into()));
+                })
+                .map(AnyNote::from)
+                .collect()
+        };
+
+        if let Some((path, source)) = first_error {
+            return Err(Error::Note { path, source });
+        }
+
+        Ok(notes.into_iter().build_vault(&options))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{note::Note, vault::vault_test::create_files_for_vault};
+
+    #[cfg_attr(feature = "tracing", tracing_test::traced_test)]
+    #[test]
+    fn open_auto_small_vault_in_memory() {
+        let (path, files) = create_files_for_vault().unwrap();
+
+        let vault: AnyVault = Vault::open_auto(&path).unwrap();
+
+        assert_eq!(vault.count_notes(), files.len());
+        assert_eq!(vault.path(), path.path());
+        assert!(
+            vault
+                .notes()
+                .iter()
+                .all(|note| matches!(note, AnyNote::InMemory(_)))
+        );
+    }
+
+    #[cfg_attr(feature = "tracing", tracing_test::traced_test)]
+    #[test]
+    fn open_auto_large_vault_on_disk() {
+        let (path, files) = create_files_for_vault().unwrap();
+
+        let vault: AnyVault = Vault::open_auto_with_limit(&path, 1).unwrap();
+
+        assert_eq!(vault.count_notes(), files.len());
+        assert!(
+            vault
+                .notes()
+                .iter()
+                .all(|note| matches!(note, AnyNote::OnDisk(_)))
+        );
+        assert!(vault.notes().iter().all(|note| note.content().is_ok()));
+    }
+
+    #[cfg_attr(feature = "tracing", tracing_test::traced_test)]
+    #[test]
+    fn open_auto_broken_note() {
+        let (path, _files) = create_files_for_vault().unwrap();
+        std::fs::write(path.path().join("broken.md"), "---\n").unwrap();
+
+        let error = AnyVault::<DefaultProperties>::open_auto(&path).unwrap_err();
+
+        assert!(matches!(
+            error,
+            Error::Note { path: note_path, .. } if note_path == path.path().join("broken.md")
+        ));
+    }
+
+    #[cfg_attr(feature = "tracing", tracing_test::traced_test)]
+    #[test]
+    fn open_auto_not_dir() {
+        let error = AnyVault::<DefaultProperties>::open_auto("not_exists_dir").unwrap_err();
+
+        assert!(matches!(error, Error::Vault(error::Error::IsNotDir(_))));
+    }
+}
@@ -0,0 +1,277 @@
+//! Canvas files (`.canvas`) in [`Vault`]
+//!
+//! Canvases are opened as notes, if [`VaultBuilder::extensions`] include `canvas`.
+//! Content of such note is JSON, see [`Canvas`]
+//!
+//! Canvas is not a note for links: links are never resolved to it and its JSON is not parsed
+//! for links (`get_digraph` has no edges of canvases). Edges from `file` nodes of canvases
+//! are added only by `get_digraph_with_canvases`
+//!
+//! [`VaultBuilder::extensions`]: crate::vault::vault_open::VaultBuilder::extensions
+
+use super::{Vault, index::is_canvas};
+use crate::canvas::Canvas;
+use crate::note::Note;
+use std::path::PathBuf;
+use thiserror::Error;
+
+/// Errors for [`Vault::canvases`]
+#[derive(Debug, Error)]
+pub enum CanvasError<E>
+where
+    E: std::error::Error,
+{
+    /// Error from note
+    #[error(transparent)]
+    Note(E),
+
+    /// Canvas is not valid JSON Canvas
+    #[error("Invalid canvas `{path}`: {source}")]
+    Json {
+        /// Path to canvas
+        path: PathBuf,
+
+        /// Error from JSON
+        #[source]
+        source: serde_json::Error,
+    },
+}
+
+/// Parse content of canvas
+fn parse_canvas<N>(note: &N) -> Result<Canvas, CanvasError<N::Error>>
+where
+    N: Note,
+{
+    let content = note.content().map_err(CanvasError::Note)?;
+
+    content.parse().map_err(|source| CanvasError::Json {
+        path: note.path().unwrap_or_default().into_owned(),
+        source,
+    })
+}
+
+impl<N> Vault<N>
+where
+    N: Note,
+{
+    /// Returns canvases of vault with parsed content
+    ///
+    /// Canvas is a note with `.canvas` extension, so open vault with
+    /// `extensions(&["md", "canvas"])`. Otherwise there are no canvases in vault
+    ///
+    /// # Example
+    /// ```no_run
+    /// use obsidian_parser::prelude::*;
+    ///
+    /// let options = VaultOptions::new("/path/to/vault");
+    /// let vault: VaultOnDisk = VaultBuilder::new(&options)
+    ///     .extensions(&["md", "canvas"])
+    ///     .into_iter()
+    ///     .filter_map(Result::ok)
+    ///     .build_vault(&options);
+    ///
+    /// for (note, canvas) in vault.canvases().unwrap() {
+    ///     println!("{:?}: {} files", note.path(), canvas.files().count());
+    /// }
+    /// ```
+    ///
+    /// # Errors
+    /// - [`CanvasError::Note`] if content of canvas can't be read
+    /// - [`CanvasError::Json`] if canvas is invalid
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip(self), fields(path = %self.path.display(), count_notes = %self.notes.len())))]
+    pub fn canvases(&self) -> Result<Vec<(&N, Canvas)>, CanvasError<N::Error>> {
+        let canvases = self
+            .notes()
+            .iter()
+            .filter(|note| is_canvas(*note))
+            .map(|note| Ok((note, parse_canvas(note)?)))
+            .collect::<Result<Vec<_>, _>>()?;
+
+        #[cfg(feature = "tracing")]
+        tracing::debug!("Found {} canvases", canvases.len());
+
+        Ok(canvases)
+    }
+
+    /// Builds directed graph like [`Vault::get_digraph`] and adds edges from every canvas
+    /// to notes from its `file` nodes
+    ///
+    /// Path of `file` node is relative to vault (like `dir/note.md`).
+    /// Files, which are not notes of vault (like images), are skipped
+    ///
+    /// # Example
+    /// ```no_run
+    /// use obsidian_parser::prelude::*;
+    ///
+    /// let options = VaultOptions::new("/path/to/vault");
+    /// let vault: VaultOnDisk = VaultBuilder::new(&options)
+    ///     .extensions(&["md", "canvas"])
+    ///     .into_iter()
+    ///     .filter_map(Result::ok)
+    ///     .build_vault(&options);
+    ///
+    /// let graph = vault.get_digraph_with_canvases().unwrap();
+    /// println!("Edges: {}", graph.edge_count());
+    /// ```
+    ///
+    /// # Errors
+    /// Same as [`Vault::canvases`]
+    #[cfg(feature = "petgraph")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "petgraph")))]
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip(self), fields(path = %self.path.display(), count_notes = %self.notes.len())))]
+    pub fn get_digraph_with_canvases(
+        &self,
+    ) -> Result<petgraph::graph::DiGraph<&N, ()>, CanvasError<N::Error>> {
+        use super::index::Index;
+        use petgraph::graph::NodeIndex;
+
+        let mut graph = self.get_digraph().map_err(CanvasError::Note)?;
+        let index = Index::from_vault(self);
+
+        for (from, note) in self.notes().iter().enumerate() {
+            if !is_canvas(note) {
+                continue;
+            }
+
+            for file in parse_canvas(note)?.files() {
+                if let Some(to) = index.full(file) {
+                    graph.add_edge(NodeIndex::new(from), NodeIndex::new(*to), ());
+                }
+            }
+        }
+
+        #[cfg(feature = "tracing")]
+        tracing::debug!("Graph with canvases. Edges: {}", graph.edge_count());
+
+        Ok(graph)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::prelude::*;
+    use std::io::Write;
+    use tempfile::TempDir;
+
+    const CANVAS: &str = r#"{
+        "nodes": [
+            {"id": "1", "type": "file", "file": "dir/note.md", "x": 0, "y": 0, "width": 400, "height": 400},
+            {"id": "2", "type": "file", "file": "image.png", "x": 0, "y": 0, "width": 400, "height": 400},
+            {"id": "3", "type": "text", "text": "See [[other]]", "x": 0, "y": 0, "width": 400, "height": 400}
+        ],
+        "edges": [{"id": "4", "fromNode": "3", "toNode": "1"}]
+    }"#;
+
+    fn create_vault(files: &[(&str, &str)]) -> (VaultInMemory, TempDir) {
+        let temp_dir = TempDir::new().unwrap();
+
+        for (path, data) in files {
+            let path = temp_dir.path().join(path);
+            std::fs::create_dir_all(path.parent().unwrap()).unwrap();
+            std::fs::File::create(path)
+                .unwrap()
+                .write_all(data.as_bytes())
+                .unwrap();
+        }
+
+        let options = VaultOptions::new(&temp_dir);
+        let vault = VaultBuilder::new(&options)
+            .extensions(&["md", "canvas"])
+            .into_iter()
+            .map(Result::unwrap)
+            .build_vault(&options);
+
+        (vault, temp_dir)
+    }
+
+    #[cfg_attr(feature = "tracing", tracing_test::traced_test)]
+    #[test]
+    fn canvases() {
+        let (vault, _path) = create_vault(&[
+            ("board.canvas", CANVAS),
+            ("dir/note.md", "Text"),
+            ("other.md", "Text"),
+        ]);
+
+        let canvases = vault.canvases().unwrap();
+        assert_eq!(canvases.len(), 1);
+        assert_eq!(canvases[0].0.note_name().unwrap(), "board");
+        assert_eq!(
+            canvases[0].1.files().collect::<Vec<_>>(),
+            ["dir/note.md", "image.png"]
+        );
+    }
+
+    #[cfg_attr(feature = "tracing", tracing_test::traced_test)]
+    #[test]
+    fn canvases_invalid() {
+        let (vault, path) = create_vault(&[("board.canvas", "{"), ("note.md", "Text")]);
+
+        assert!(matches!(
+            vault.canvases(),
+            Err(CanvasError::Json { path: error_path, .. }) if error_path == path.path().join("board.canvas")
+        ));
+    }
+
+    #[cfg(feature = "petgraph")]
+    #[cfg_attr(feature = "tracing", tracing_test::traced_test)]
+    #[test]
+    fn get_digraph_with_canvases() {
+        let (vault, _path) = create_vault(&[
+            ("board.canvas", CANVAS),
+            ("dir/note.md", "Text"),
+            ("other.md", "Text"),
+        ]);
+
+        let graph = vault.get_digraph_with_canvases().unwrap();
+        let mut edges: Vec<_> = graph
+            .edge_indices()
+            .map(|edge| {
+                let (from, to) = graph.edge_endpoints(edge).unwrap();
+                (
+                    graph[from].note_name().unwrap(),
+                    graph[to].note_name().unwrap(),
+                )
+            })
+            .collect();
+        edges.sort();
+
+        // JSON of canvas is not parsed as note, so `[[other]]` from text node is not a link
+        assert_eq!(edges, [("board".to_string(), "note".to_string())]);
+        assert_eq!(vault.get_digraph().unwrap().edge_count(), 0);
+    }
+
+    #[cfg(feature = "petgraph")]
+    #[cfg_attr(feature = "tracing", tracing_test::traced_test)]
+    #[test]
+    fn canvas_with_name_of_note() {
+        let (vault, path) = create_vault(&[
+            ("board.canvas", CANVAS),
+            ("board.md", "Text"),
+            ("dir/note.md", "[[board]] [[dir/board]]"),
+            ("dir/board.canvas", CANVAS),
+            ("other.md", "Text"),
+        ]);
+
+        let graph = vault.get_digraph().unwrap();
+        let edges: Vec<_> = graph
+            .edge_indices()
+            .map(|edge| {
+                let (from, to) = graph.edge_endpoints(edge).unwrap();
+                (
+                    graph[from].path().unwrap().into_owned(),
+                    graph[to].path().unwrap().into_owned(),
+                )
+            })
+            .collect();
+
+        assert_eq!(
+            edges,
+            [(
+                path.path().join("dir/note.md"),
+                path.path().join("board.md")
+            )]
+        );
+    }
+}
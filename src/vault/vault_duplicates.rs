@@ -103,6 +103,67 @@ where
     {
         Ok(!self.get_duplicates_notes_by_content::<D>()?.is_empty())
     }
+
+    /// Get pairs of notes with near-identical content and their similarity
+    ///
+    /// Similarity is normalized Levenshtein distance (from `0.0` to `1.0`, see
+    /// [`strsim::normalized_levenshtein`]) of contents without trailing whitespaces of lines.
+    /// Pairs with similarity `>= threshold` are returned, sorted by similarity descending
+    ///
+    /// # Performance
+    /// Compares every pair of notes: O(n²) comparisons, each is O(length²).
+    /// Use it for small vaults or already filtered notes
+    ///
+    /// # Example
+    /// ```no_run
+    /// use obsidian_parser::prelude::*;
+    ///
+    /// let options = VaultOptions::new("/path/to/vault");
+    /// let vault: VaultInMemory = VaultBuilder::new(&options)
+    ///     .into_iter()
+    ///     .filter_map(Result::ok)
+    ///     .build_vault(&options);
+    ///
+    /// for (a, b, score) in vault.get_near_duplicates(0.9).unwrap() {
+    ///     println!("{:?} ~ {:?}: {score:.2}", a.note_name(), b.note_name());
+    /// }
+    /// ```
+    #[cfg(feature = "similarity")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "similarity")))]
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip(self), fields(path = %self.path.display(), count_notes = %self.notes.len())))]
+    pub fn get_near_duplicates(&self, threshold: f64) -> Result<Vec<(&N, &N, f64)>, N::Error> {
+        #[cfg(feature = "tracing")]
+        tracing::debug!("Get near duplicates notes by content");
+
+        let contents = self
+            .notes()
+            .iter()
+            .map(|note| {
+                let content = note.content()?;
+                let lines: Vec<_> = content.trim().lines().map(str::trim_end).collect();
+
+                Ok(lines.join("\n"))
+            })
+            .collect::<Result<Vec<_>, N::Error>>()?;
+
+        let mut pairs = Vec::new();
+        for (i, a) in contents.iter().enumerate() {
+            for (j, b) in contents.iter().enumerate().skip(i + 1) {
+                let score = strsim::normalized_levenshtein(a, b);
+
+                if score >= threshold {
+                    pairs.push((&self.notes[i], &self.notes[j], score));
+                }
+            }
+        }
+
+        pairs.sort_by(|(_, _, x), (_, _, y)| y.total_cmp(x));
+
+        #[cfg(feature = "tracing")]
+        tracing::debug!("Found {} near duplicated pairs", pairs.len());
+
+        Ok(pairs)
+    }
 }
 
 #[cfg(test)]
@@ -234,4 +295,36 @@ mod tests {
                 .unwrap()
         );
     }
+
+    #[cfg_attr(feature = "tracing", tracing_test::traced_test)]
+    #[test]
+    #[cfg(feature = "similarity")]
+    fn get_near_duplicates() {
+        let text = "Meeting notes about the parser and the graph of links";
+        let (vault, _path) = crate::vault::vault_test::create_vault_with_files(&[
+            ("a.md", &format!("date: 2025-01-01\n{text}")),
+            ("b.md", &format!("date: 2025-01-02  \n{text}\n\n")),
+            ("c.md", &format!("{text}   \n")),
+            ("d.md", "Completely different"),
+        ])
+        .unwrap();
+
+        let pairs = vault.get_near_duplicates(0.7).unwrap();
+        let names: Vec<_> = pairs
+            .iter()
+            .map(|(a, b, _)| {
+                let mut pair = [a.note_name().unwrap(), b.note_name().unwrap()];
+                pair.sort();
+                pair
+            })
+            .collect();
+
+        assert_eq!(names.len(), 3);
+        assert_eq!(names[0], ["a", "b"]);
+        assert!(pairs[0].2 > 0.95 && pairs[0].2 < 1.0);
+        assert!(pairs.windows(2).all(|w| w[0].2 >= w[1].2));
+        assert!(!names.iter().flatten().any(|name| name == "d"));
+
+        assert!(vault.get_near_duplicates(1.0).unwrap().is_empty());
+    }
 }
@@ -0,0 +1,141 @@
+//! Export link graph of [`Vault`] as node-link JSON (for D3, cytoscape and so on)
+
+use super::{Vault, index::relative_path};
+use crate::note::Note;
+use serde_json::json;
+use std::collections::BTreeSet;
+
+impl<N> Vault<N>
+where
+    N: Note,
+{
+    /// Export link graph as node-link JSON: `{ "nodes": [{"id"}], "links": [{"source", "target"}] }`
+    ///
+    /// Id of node is name of note with path relative to vault (like `dir/note`), so notes with
+    /// the same name in different folders are different nodes. Links are resolved like in
+    /// [`Vault::linked_pairs`]; unresolved links are skipped
+    ///
+    /// - `directed = true`: every distinct link `source -> target` is an edge
+    /// - `directed = false`: links in both directions are one edge
+    ///
+    /// # Example
+    /// ```
+    /// use obsidian_parser::prelude::*;
+    ///
+    /// let dir = tempfile::tempdir().unwrap();
+    /// std::fs::write(dir.path().join("a.md"), "[[b]]").unwrap();
+    /// std::fs::write(dir.path().join("b.md"), "[[a]] [[missing]]").unwrap();
+    ///
+    /// let options = VaultOptions::new(&dir);
+    /// let vault: VaultInMemory = VaultBuilder::new(&options)
+    ///     .into_iter()
+    ///     .filter_map(Result::ok)
+    ///     .build_vault(&options);
+    ///
+    /// assert_eq!(
+    ///     vault.graph_to_json(false).unwrap(),
+    ///     r#"{"links":[{"source":"a","target":"b"}],"nodes":[{"id":"a"},{"id":"b"}]}"#
+    /// );
+    /// ```
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip(self), fields(path = %self.path.display(), count_notes = %self.notes.len())))]
+    pub fn graph_to_json(&self, directed: bool) -> Result<String, N::Error> {
+        let mut order: Vec<_> = (0..self.count_notes()).collect();
+        let ids: Vec<_> = self
+            .notes()
+            .iter()
+            .map(|note| relative_path(note, &self.path).unwrap_or_default())
+            .collect();
+        order.sort_by(|x, y| ids[*x].cmp(&ids[*y]));
+
+        let edges: BTreeSet<_> = self
+            .linked_indices()?
+            .into_iter()
+            .map(|(from, to)| (&ids[from], &ids[to]))
+            .map(|(from, to)| {
+                if directed || from <= to {
+                    (from, to)
+                } else {
+                    (to, from)
+                }
+            })
+            .collect();
+
+        let nodes: Vec<_> = order.into_iter().map(|i| json!({ "id": ids[i] })).collect();
+        let links: Vec<_> = edges
+            .into_iter()
+            .map(|(source, target)| json!({ "source": source, "target": target }))
+            .collect();
+
+        #[cfg(feature = "tracing")]
+        tracing::debug!(
+            "Export graph with {} nodes and {} links",
+            nodes.len(),
+            links.len()
+        );
+
+        Ok(json!({ "nodes": nodes, "links": links }).to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::vault::vault_test::create_vault_with_files;
+    use serde_json::json;
+
+    #[cfg_attr(feature = "tracing", tracing_test::traced_test)]
+    #[test]
+    fn graph_to_json_directed() {
+        let (vault, _path) = create_vault_with_files(&[
+            ("main.md", "[[data/main|main]]"),
+            ("link.md", "[[main]] [[missing]]"),
+            ("data/main.md", "[[link]]"),
+        ])
+        .unwrap();
+
+        let json: serde_json::Value =
+            serde_json::from_str(&vault.graph_to_json(true).unwrap()).unwrap();
+
+        assert_eq!(
+            json["nodes"],
+            json!([{"id": "data/main"}, {"id": "link"}, {"id": "main"}])
+        );
+        assert_eq!(json["links"].as_array().unwrap().len(), 3);
+        assert!(
+            json["links"]
+                .as_array()
+                .unwrap()
+                .contains(&json!({"source": "data/main", "target": "link"}))
+        );
+        assert!(
+            json["links"]
+                .as_array()
+                .unwrap()
+                .contains(&json!({"source": "main", "target": "data/main"}))
+        );
+    }
+
+    #[cfg_attr(feature = "tracing", tracing_test::traced_test)]
+    #[test]
+    fn graph_to_json_undirected() {
+        let (vault, _path) = create_vault_with_files(&[
+            ("a.md", "[[b]] [[b]] [[c]]"),
+            ("b.md", "[[a]]"),
+            ("c.md", "[[c]]"),
+        ])
+        .unwrap();
+
+        let json = |directed| -> serde_json::Value {
+            serde_json::from_str(&vault.graph_to_json(directed).unwrap()).unwrap()
+        };
+
+        assert_eq!(
+            json(false)["links"],
+            json!([
+                {"source": "a", "target": "b"},
+                {"source": "a", "target": "c"},
+                {"source": "c", "target": "c"},
+            ])
+        );
+        assert_eq!(json(true)["links"].as_array().unwrap().len(), 4);
+    }
+}
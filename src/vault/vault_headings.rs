@@ -0,0 +1,120 @@
+//! Headings of all notes in vault
+
+use super::Vault;
+use crate::note::{Note, headings::Heading};
+
+impl<N> Vault<N>
+where
+    N: Note,
+{
+    /// Returns headings of all notes with their note
+    ///
+    /// Order: notes as in [`Vault::notes`], headings as in note. See [`Note::headings`]
+    ///
+    /// # Example
+    /// ```no_run
+    /// use obsidian_parser::prelude::*;
+    ///
+    /// let options = VaultOptions::new("/path/to/vault");
+    /// let vault: VaultInMemory = VaultBuilder::new(&options)
+    ///     .into_iter()
+    ///     .filter_map(Result::ok)
+    ///     .build_vault(&options);
+    ///
+    /// for (note, heading) in vault.all_headings().unwrap() {
+    ///     println!("{:?}: {}", note.note_name(), heading.text);
+    /// }
+    /// ```
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip(self), fields(path = %self.path.display(), count_notes = %self.notes.len())))]
+    pub fn all_headings(&self) -> Result<Vec<(&N, Heading)>, N::Error> {
+        #[cfg(feature = "tracing")]
+        tracing::debug!("Get all headings...");
+
+        let mut headings = Vec::new();
+        for note in self.notes() {
+            headings.extend(note.headings()?.into_iter().map(|heading| (note, heading)));
+        }
+
+        Ok(headings)
+    }
+
+    /// Parallel version of [`Vault::all_headings`]
+    ///
+    /// Order of result is the same
+    #[cfg_attr(docsrs, doc(cfg(feature = "rayon")))]
+    #[cfg(feature = "rayon")]
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip(self), fields(path = %self.path.display(), count_notes = %self.notes.len())))]
+    pub fn par_all_headings(&self) -> Result<Vec<(&N, Heading)>, N::Error>
+    where
+        N: Sync,
+        N::Error: Send,
+    {
+        use rayon::prelude::*;
+
+        #[cfg(feature = "tracing")]
+        tracing::debug!("Get all headings in parallel...");
+
+        let headings: Vec<Vec<_>> = self
+            .notes
+            .par_iter()
+            .map(|note| {
+                let headings = note.headings()?;
+                Ok(headings
+                    .into_iter()
+                    .map(|heading| (note, heading))
+                    .collect())
+            })
+            .collect::<Result<_, N::Error>>()?;
+
+        Ok(headings.into_iter().flatten().collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{note::Note, vault::vault_test::create_vault_with_files};
+
+    const FILES: &[(&str, &str)] = &[
+        ("first.md", "# First\nText\n## Section"),
+        ("second.md", "---\ntopic: life\n---\n# Second"),
+        ("third.md", "Without headings #tag"),
+    ];
+
+    fn names(headings: &[(&impl Note, crate::note::headings::Heading)]) -> Vec<(String, String)> {
+        let mut names: Vec<_> = headings
+            .iter()
+            .map(|(note, heading)| (note.note_name().unwrap(), heading.text.clone()))
+            .collect();
+
+        names.sort();
+        names
+    }
+
+    #[cfg_attr(feature = "tracing", tracing_test::traced_test)]
+    #[test]
+    fn all_headings() {
+        let (vault, _path) = create_vault_with_files(FILES).unwrap();
+        let headings = vault.all_headings().unwrap();
+
+        assert_eq!(
+            names(&headings),
+            [
+                ("first".to_string(), "First".to_string()),
+                ("first".to_string(), "Section".to_string()),
+                ("second".to_string(), "Second".to_string()),
+            ]
+        );
+    }
+
+    #[cfg(feature = "rayon")]
+    #[cfg_attr(feature = "tracing", tracing_test::traced_test)]
+    #[test]
+    fn par_all_headings() {
+        let (vault, _path) = create_vault_with_files(FILES).unwrap();
+
+        assert_eq!(
+            names(&vault.par_all_headings().unwrap()),
+            names(&vault.all_headings().unwrap())
+        );
+    }
+}
@@ -0,0 +1,1313 @@
+//! Link analysis in vault
+//!
+//! Unlike `vault_petgraph`, it doesn't require `petgraph` feature
+
+use super::{
+    Vault,
+    index::{Index, relative_path},
+};
+use crate::note::{
+    Note,
+    parser::{normalize_link, parse_links, parse_links_detailed, parse_links_with_embed_flag},
+};
+use std::{
+    borrow::Cow,
+    collections::{HashMap, HashSet},
+    fmt::Write,
+    path::Path,
+};
+
+/// How target of link is resolved, see [`Vault::explain_link`]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum LinkStatus<'a, N> {
+    /// Target with `/` matched full path of note (like `dir/note`)
+    Full(&'a N),
+
+    /// Target matched name of exactly one note
+    Short(&'a N),
+
+    /// Several notes share the name of target
+    ///
+    /// Link is still resolved to `resolved` (the first of `candidates` in [`Vault::notes`]),
+    /// so graph may connect another note than expected. Use full path to select one
+    Ambiguous {
+        /// Note used for link
+        resolved: &'a N,
+
+        /// All notes with this name
+        candidates: Vec<&'a N>,
+    },
+
+    /// No note matches target
+    NotFound,
+}
+
+/// Explanation of link between two notes. Returned by [`Vault::explain_link`]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LinkResolution<'a, N> {
+    /// Source note. [`None`] if `from` is not found in vault
+    pub source: Option<&'a N>,
+
+    /// Source note contains link to target (headings, blocks and aliases are ignored)
+    pub linked: bool,
+
+    /// How target is resolved
+    pub status: LinkStatus<'a, N>,
+}
+
+impl<'a, N> LinkResolution<'a, N> {
+    /// Returns note used for link. [`None`] if target is not found
+    #[must_use]
+    pub const fn target(&self) -> Option<&'a N> {
+        match &self.status {
+            LinkStatus::Full(note)
+            | LinkStatus::Short(note)
+            | LinkStatus::Ambiguous { resolved: note, .. } => Some(*note),
+            LinkStatus::NotFound => None,
+        }
+    }
+
+    /// Graph has edge for this link?
+    ///
+    /// It is `true` if source is found, links to target and target is resolved
+    #[must_use]
+    pub const fn is_connected(&self) -> bool {
+        self.source.is_some() && self.linked && self.target().is_some()
+    }
+}
+
+/// Target of embed is attachment (like `image.png`), not note
+///
+/// Check that target doesn't resolve to note first: name of note can contain `.` too
+/// (like `Meeting 2024.01.05`)
+fn is_attachment(target: &str) -> bool {
+    Path::new(target)
+        .extension()
+        .is_some_and(|extension| !extension.eq_ignore_ascii_case("md"))
+}
+
+/// Content has link to note with position `target` in [`Vault::notes`]
+fn links_to(index: &Index<usize>, content: &str, target: usize) -> bool {
+    parse_links(content).any(|link| index.get(link) == Some(&target))
+}
+
+/// Targets of links in `content`, which are not resolved by `index`. Sorted and deduplicated
+fn broken_targets(index: &Index<usize>, content: &str) -> Vec<String> {
+    let mut targets: Vec<_> = parse_links_with_embed_flag(content)
+        .filter(|(link, _)| index.get(link.target).is_none())
+        .filter(|(link, is_embed)| !(*is_embed && is_attachment(link.target)))
+        .map(|(link, _)| link.target.to_string())
+        .collect();
+
+    targets.sort_unstable();
+    targets.dedup();
+    targets
+}
+
+/// Flatten broken targets of notes and sort them by path of note and target
+fn sort_broken_links<'a, N>(
+    targets: impl IntoIterator<Item = (&'a N, Vec<String>)>,
+) -> Vec<(&'a N, String)>
+where
+    N: Note,
+{
+    let mut result: Vec<_> = targets
+        .into_iter()
+        .flat_map(|(note, targets)| {
+            let path = note.path().map(|path| path.to_path_buf());
+            targets
+                .into_iter()
+                .map(move |target| (path.clone(), note, target))
+        })
+        .collect();
+
+    result.sort_by(|(path, _, target), (other_path, _, other_target)| {
+        (path, target).cmp(&(other_path, other_target))
+    });
+
+    result
+        .into_iter()
+        .map(|(_, note, target)| (note, target))
+        .collect()
+}
+
+/// Escape field of CSV: quote it if it contains `,`, `"` or line break
+fn escape_csv(field: &str) -> Cow<'_, str> {
+    if field.contains([',', '"', '\n', '\r']) {
+        Cow::Owned(format!("\"{}\"", field.replace('"', "\"\"")))
+    } else {
+        Cow::Borrowed(field)
+    }
+}
+
+impl<N> Vault<N>
+where
+    N: Note,
+{
+    /// Returns all distinct link targets from all notes in vault
+    ///
+    /// Unresolved links (to missing notes) are included too
+    ///
+    /// # Example
+    /// ```no_run
+    /// use obsidian_parser::prelude::*;
+    ///
+    /// let options = VaultOptions::new("/path/to/vault");
+    /// let vault: VaultInMemory = VaultBuilder::new(&options)
+    ///     .into_iter()
+    ///     .filter_map(Result::ok)
+    ///     .build_vault(&options);
+    ///
+    /// for target in vault.all_link_targets().unwrap() {
+    ///     println!("Link to: {target}");
+    /// }
+    /// ```
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip(self), fields(path = %self.path.display(), count_notes = %self.notes.len())))]
+    pub fn all_link_targets(&self) -> Result<HashSet<String>, N::Error> {
+        #[cfg(feature = "tracing")]
+        tracing::debug!("Get all link targets...");
+
+        let mut targets = HashSet::new();
+        for note in self.notes() {
+            let content = note.content()?;
+
+            for link in parse_links(&content) {
+                if !targets.contains(link) {
+                    targets.insert(link.to_string());
+                }
+            }
+        }
+
+        #[cfg(feature = "tracing")]
+        tracing::debug!("Found {} link targets", targets.len());
+
+        Ok(targets)
+    }
+
+    /// Returns all notes whose content links to `note_name`
+    ///
+    /// Links are resolved like in graph of `vault_petgraph` (`get_digraph`):
+    /// - `note_name` with `/` is full path relative to vault without `.md` (like `dir/note`)
+    /// - Otherwise it is name of note. If several notes share a name, the first of them
+    ///   in [`Vault::notes`] is used (as for `[[note]]` links). Use full path to select another one
+    ///
+    /// Every note is returned once, even if it has several links to `note_name`.
+    /// Returns empty [`Vec`] if `note_name` is not found in vault
+    ///
+    /// # Example
+    /// ```no_run
+    /// use obsidian_parser::prelude::*;
+    ///
+    /// let options = VaultOptions::new("/path/to/vault");
+    /// let vault: VaultOnceLock = VaultBuilder::new(&options)
+    ///     .into_iter()
+    ///     .filter_map(Result::ok)
+    ///     .build_vault(&options);
+    ///
+    /// for note in vault.backlinks("Physics").unwrap() {
+    ///     println!("Linked from: {:?}", note.path());
+    /// }
+    /// ```
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip(self), fields(path = %self.path.display(), count_notes = %self.notes.len())))]
+    pub fn backlinks(&self, note_name: &str) -> Result<Vec<&N>, N::Error> {
+        let index = Index::from_vault(self);
+
+        let Some(target) = index.get(note_name).copied() else {
+            #[cfg(feature = "tracing")]
+            tracing::debug!("Note `{note_name}` not found");
+
+            return Ok(Vec::new());
+        };
+
+        let mut result = Vec::new();
+        for note in self.notes() {
+            if links_to(&index, &note.content()?, target) {
+                result.push(note);
+            }
+        }
+
+        #[cfg(feature = "tracing")]
+        tracing::debug!("Found {} backlinks", result.len());
+
+        Ok(result)
+    }
+
+    /// Parallel version of [`Vault::backlinks`]
+    ///
+    /// Order of result is the same
+    #[cfg_attr(docsrs, doc(cfg(feature = "rayon")))]
+    #[cfg(feature = "rayon")]
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip(self), fields(path = %self.path.display(), count_notes = %self.notes.len())))]
+    pub fn par_backlinks(&self, note_name: &str) -> Result<Vec<&N>, N::Error>
+    where
+        N: Send + Sync,
+        N::Error: Send,
+    {
+        use rayon::prelude::*;
+
+        let index = Index::from_vault(self);
+
+        let Some(target) = index.get(note_name).copied() else {
+            #[cfg(feature = "tracing")]
+            tracing::debug!("Note `{note_name}` not found");
+
+            return Ok(Vec::new());
+        };
+
+        let result: Vec<_> = self
+            .notes
+            .par_iter()
+            .map(|note| Ok(links_to(&index, &note.content()?, target).then_some(note)))
+            .collect::<Result<Vec<_>, N::Error>>()?
+            .into_iter()
+            .flatten()
+            .collect();
+
+        #[cfg(feature = "tracing")]
+        tracing::debug!("Found {} backlinks", result.len());
+
+        Ok(result)
+    }
+
+    /// Returns backlinks of `note_name` grouped by heading, which they point into
+    ///
+    /// `[[note#Heading]]` is under `Some("Heading")`, `[[note]]` and `[[note^block]]`
+    /// are under [`None`]. `note_name` is resolved like in [`Vault::backlinks`]
+    ///
+    /// Note is in every group, which it links to, but only once per group.
+    /// Notes in group are in [`Vault::notes`] order. Returns empty [`HashMap`]
+    /// if `note_name` is not found in vault
+    ///
+    /// # Example
+    /// ```no_run
+    /// use obsidian_parser::prelude::*;
+    ///
+    /// let options = VaultOptions::new("/path/to/vault");
+    /// let vault: VaultOnceLock = VaultBuilder::new(&options)
+    ///     .into_iter()
+    ///     .filter_map(Result::ok)
+    ///     .build_vault(&options);
+    ///
+    /// for (heading, notes) in vault.backlinks_by_section("Physics").unwrap() {
+    ///     println!("{}: {} backlinks", heading.as_deref().unwrap_or("(whole note)"), notes.len());
+    /// }
+    /// ```
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip(self), fields(path = %self.path.display(), count_notes = %self.notes.len())))]
+    pub fn backlinks_by_section(
+        &self,
+        note_name: &str,
+    ) -> Result<HashMap<Option<String>, Vec<&N>>, N::Error> {
+        let index = Index::from_vault(self);
+
+        let Some(target) = index.get(note_name).copied() else {
+            #[cfg(feature = "tracing")]
+            tracing::debug!("Note `{note_name}` not found");
+
+            return Ok(HashMap::new());
+        };
+
+        let mut result: HashMap<Option<String>, Vec<&N>> = HashMap::new();
+        for note in self.notes() {
+            let content = note.content()?;
+
+            let headings: HashSet<_> = parse_links_detailed(&content)
+                .filter(|link| index.get(link.target) == Some(&target))
+                .map(|link| link.heading)
+                .collect();
+
+            for heading in headings {
+                result
+                    .entry(heading.map(ToString::to_string))
+                    .or_default()
+                    .push(note);
+            }
+        }
+
+        #[cfg(feature = "tracing")]
+        tracing::debug!("Found backlinks to {} sections", result.len());
+
+        Ok(result)
+    }
+
+    /// Returns resolved links as pairs `(source note, target note)`
+    ///
+    /// Edge list of graph from `vault_petgraph` (`get_digraph`), but without `petgraph`.
+    /// Links are resolved like in [`Vault::backlinks`]. Unresolved links are skipped
+    /// (see [`Vault::broken_links`])
+    ///
+    /// Every link is a pair, so a note with two links to the same note gives two equal pairs.
+    /// Pairs are in [`Vault::notes`] order, then in order of links in content
+    ///
+    /// # Example
+    /// ```no_run
+    /// use obsidian_parser::prelude::*;
+    ///
+    /// let options = VaultOptions::new("/path/to/vault");
+    /// let vault: VaultOnceLock = VaultBuilder::new(&options)
+    ///     .into_iter()
+    ///     .filter_map(Result::ok)
+    ///     .build_vault(&options);
+    ///
+    /// for (from, to) in vault.linked_pairs().unwrap() {
+    ///     println!("{:?} -> {:?}", from.note_name(), to.note_name());
+    /// }
+    /// ```
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip(self), fields(path = %self.path.display(), count_notes = %self.notes.len())))]
+    pub fn linked_pairs(&self) -> Result<Vec<(&N, &N)>, N::Error> {
+        let result: Vec<_> = self
+            .linked_indices()?
+            .into_iter()
+            .map(|(from, to)| (&self.notes[from], &self.notes[to]))
+            .collect();
+
+        #[cfg(feature = "tracing")]
+        tracing::debug!("Found {} linked pairs", result.len());
+
+        Ok(result)
+    }
+
+    /// Same as [`Vault::linked_pairs`], but with positions of notes in [`Vault::notes`]
+    pub(crate) fn linked_indices(&self) -> Result<Vec<(usize, usize)>, N::Error> {
+        let index = Index::from_vault(self);
+
+        let mut result = Vec::new();
+        for (from, note) in self.notes().iter().enumerate() {
+            let content = note.content()?;
+
+            result.extend(
+                parse_links(&content)
+                    .filter_map(|link| index.get(link))
+                    .map(|to| (from, *to)),
+            );
+        }
+
+        Ok(result)
+    }
+
+    /// Returns notes without incoming and outgoing links
+    ///
+    /// Only resolved links count (see [`Vault::linked_pairs`]). So a note with only broken links
+    /// is orphan, but a note with resolved link to itself is not
+    ///
+    /// # Example
+    /// ```no_run
+    /// use obsidian_parser::prelude::*;
+    ///
+    /// let options = VaultOptions::new("/path/to/vault");
+    /// let vault: VaultOnDisk = VaultBuilder::new(&options)
+    ///     .into_iter()
+    ///     .filter_map(Result::ok)
+    ///     .build_vault(&options);
+    ///
+    /// for note in vault.orphan_notes().unwrap() {
+    ///     println!("Orphan: {:?}", note.path());
+    /// }
+    /// ```
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip(self), fields(path = %self.path.display(), count_notes = %self.notes.len())))]
+    pub fn orphan_notes(&self) -> Result<Vec<&N>, N::Error> {
+        let mut is_linked = vec![false; self.count_notes()];
+        for (from, to) in self.linked_indices()? {
+            is_linked[from] = true;
+            is_linked[to] = true;
+        }
+
+        let result: Vec<_> = self
+            .notes()
+            .iter()
+            .zip(is_linked)
+            .filter_map(|(note, is_linked)| (!is_linked).then_some(note))
+            .collect();
+
+        #[cfg(feature = "tracing")]
+        tracing::debug!("Found {} orphan notes", result.len());
+
+        Ok(result)
+    }
+
+    /// Returns up to `top_n` notes with the most incoming links ("hubs") and count of linking notes
+    ///
+    /// Count is number of distinct other notes with resolved link to the note (see [`Vault::linked_pairs`]),
+    /// so repeated links and links to itself don't count. Notes without incoming links are skipped.
+    /// Result is sorted by count descending, then by path relative to vault
+    ///
+    /// # Example
+    /// ```no_run
+    /// use obsidian_parser::prelude::*;
+    ///
+    /// let options = VaultOptions::new("/path/to/vault");
+    /// let vault: VaultOnDisk = VaultBuilder::new(&options)
+    ///     .into_iter()
+    ///     .filter_map(Result::ok)
+    ///     .build_vault(&options);
+    ///
+    /// for (note, count) in vault.most_linked_notes(10).unwrap() {
+    ///     println!("{:?}: {count}", note.note_name());
+    /// }
+    /// ```
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip(self), fields(path = %self.path.display(), count_notes = %self.notes.len())))]
+    pub fn most_linked_notes(&self, top_n: usize) -> Result<Vec<(&N, usize)>, N::Error> {
+        let pairs: HashSet<_> = self
+            .linked_indices()?
+            .into_iter()
+            .filter(|(from, to)| from != to)
+            .collect();
+
+        let mut counts = vec![0; self.count_notes()];
+        for (_, to) in pairs {
+            counts[to] += 1;
+        }
+
+        let paths = self.sort_keys();
+        let mut result: Vec<_> = counts
+            .into_iter()
+            .enumerate()
+            .filter(|(_, count)| *count > 0)
+            .collect();
+        result.sort_by(|(x, x_count), (y, y_count)| {
+            y_count.cmp(x_count).then_with(|| paths[*x].cmp(&paths[*y]))
+        });
+        result.truncate(top_n);
+
+        #[cfg(feature = "tracing")]
+        tracing::debug!("Found {} linked notes", result.len());
+
+        Ok(result
+            .into_iter()
+            .map(|(i, count)| (&self.notes[i], count))
+            .collect())
+    }
+
+    /// Returns notes without resolved outgoing links ("dead ends")
+    ///
+    /// Only resolved links count (see [`Vault::linked_pairs`]), so a note with only broken links
+    /// is dead end. Result is sorted by path relative to vault
+    ///
+    /// # Example
+    /// ```no_run
+    /// use obsidian_parser::prelude::*;
+    ///
+    /// let options = VaultOptions::new("/path/to/vault");
+    /// let vault: VaultOnDisk = VaultBuilder::new(&options)
+    ///     .into_iter()
+    ///     .filter_map(Result::ok)
+    ///     .build_vault(&options);
+    ///
+    /// for note in vault.dead_end_notes().unwrap() {
+    ///     println!("Dead end: {:?}", note.path());
+    /// }
+    /// ```
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip(self), fields(path = %self.path.display(), count_notes = %self.notes.len())))]
+    pub fn dead_end_notes(&self) -> Result<Vec<&N>, N::Error> {
+        let mut has_outgoing = vec![false; self.count_notes()];
+        for (from, _) in self.linked_indices()? {
+            has_outgoing[from] = true;
+        }
+
+        let paths = self.sort_keys();
+        let mut result: Vec<_> = (0..self.count_notes())
+            .filter(|i| !has_outgoing[*i])
+            .collect();
+        result.sort_by(|x, y| paths[*x].cmp(&paths[*y]));
+
+        #[cfg(feature = "tracing")]
+        tracing::debug!("Found {} dead end notes", result.len());
+
+        Ok(result.into_iter().map(|i| &self.notes[i]).collect())
+    }
+
+    /// Paths of notes relative to vault, used to sort results deterministically
+    fn sort_keys(&self) -> Vec<String> {
+        self.notes()
+            .iter()
+            .map(|note| relative_path(note, &self.path).unwrap_or_default())
+            .collect()
+    }
+
+    /// Returns links which don't resolve to any note in vault
+    ///
+    /// Every item is `(source note, target of link)`. Links are resolved like in [`Vault::backlinks`],
+    /// headings, blocks and aliases are ignored (`[[note#heading|alias]]` is `note`).
+    /// Embeds of attachments (like `![[image.png]]`) are skipped, because vault contains only notes
+    ///
+    /// Result is sorted by path of source note and target. Same broken link in one note is reported once
+    ///
+    /// # Example
+    /// ```no_run
+    /// use obsidian_parser::prelude::*;
+    ///
+    /// let options = VaultOptions::new("/path/to/vault");
+    /// let vault: VaultOnDisk = VaultBuilder::new(&options)
+    ///     .into_iter()
+    ///     .filter_map(Result::ok)
+    ///     .build_vault(&options);
+    ///
+    /// for (note, target) in vault.broken_links().unwrap() {
+    ///     println!("{:?} links to missing `{target}`", note.path());
+    /// }
+    /// ```
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip(self), fields(path = %self.path.display(), count_notes = %self.notes.len())))]
+    pub fn broken_links(&self) -> Result<Vec<(&N, String)>, N::Error> {
+        let index = Index::from_vault(self);
+
+        let mut targets = Vec::new();
+        for note in self.notes() {
+            targets.push((note, broken_targets(&index, &note.content()?)));
+        }
+
+        let result = sort_broken_links(targets);
+
+        #[cfg(feature = "tracing")]
+        tracing::debug!("Found {} broken links", result.len());
+
+        Ok(result)
+    }
+
+    /// Parallel version of [`Vault::broken_links`]
+    ///
+    /// Order of result is the same
+    #[cfg_attr(docsrs, doc(cfg(feature = "rayon")))]
+    #[cfg(feature = "rayon")]
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip(self), fields(path = %self.path.display(), count_notes = %self.notes.len())))]
+    pub fn par_broken_links(&self) -> Result<Vec<(&N, String)>, N::Error>
+    where
+        N: Send + Sync,
+        N::Error: Send,
+    {
+        use rayon::prelude::*;
+
+        let index = Index::from_vault(self);
+
+        let targets = self
+            .notes
+            .par_iter()
+            .map(|note| Ok((note, broken_targets(&index, &note.content()?))))
+            .collect::<Result<Vec<_>, N::Error>>()?;
+
+        let result = sort_broken_links(targets);
+
+        #[cfg(feature = "tracing")]
+        tracing::debug!("Found {} broken links", result.len());
+
+        Ok(result)
+    }
+
+    /// Returns links and embeds, which don't resolve to any note in vault or file
+    ///
+    /// Every item is `(source note, target of link)`, where source note is path relative to vault
+    /// without `.md` (like `dir/note`). Targets, which don't resolve to note and have extension
+    /// other than `.md` (like `image.png`), are attachments: they are looked up in `attachment_dir`
+    /// and in vault root.
+    /// `attachment_dir` is relative to vault root or absolute.
+    /// Other targets are resolved like in [`Vault::broken_links`]
+    ///
+    /// Result is sorted. Same target in one note is reported once
+    ///
+    /// # Example
+    /// ```no_run
+    /// use obsidian_parser::prelude::*;
+    /// use std::path::Path;
+    ///
+    /// let options = VaultOptions::new("/path/to/vault");
+    /// let vault: VaultOnDisk = VaultBuilder::new(&options)
+    ///     .into_iter()
+    ///     .filter_map(Result::ok)
+    ///     .build_vault(&options);
+    ///
+    /// for (note, target) in vault.dangling_references(Path::new("attachments")).unwrap() {
+    ///     println!("`{note}` refers to missing `{target}`");
+    /// }
+    /// ```
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip(self), fields(path = %self.path.display(), count_notes = %self.notes.len())))]
+    pub fn dangling_references(
+        &self,
+        attachment_dir: &Path,
+    ) -> Result<Vec<(String, String)>, N::Error> {
+        let index = Index::from_vault(self);
+        let attachment_dir = self.path.join(attachment_dir);
+
+        let is_dangling = |target: &str| {
+            if index.get(target).is_some() {
+                return false;
+            }
+
+            !is_attachment(target)
+                || (!attachment_dir.join(target).is_file() && !self.path.join(target).is_file())
+        };
+
+        let mut result = Vec::new();
+        for note in self.notes() {
+            let content = note.content()?;
+            let source = relative_path(note, &self.path).unwrap_or_default();
+
+            let mut targets: Vec<_> = parse_links(&content)
+                .filter(|target| is_dangling(target))
+                .map(ToString::to_string)
+                .collect();
+
+            targets.sort_unstable();
+            targets.dedup();
+
+            result.extend(targets.into_iter().map(|target| (source.clone(), target)));
+        }
+
+        result.sort_unstable();
+
+        #[cfg(feature = "tracing")]
+        tracing::debug!("Found {} dangling references", result.len());
+
+        Ok(result)
+    }
+
+    /// Export all links of vault to CSV with columns `source,target,resolved,kind`
+    ///
+    /// - `source`: path of note relative to vault without `.md` (like `dir/note`)
+    /// - `target`: target of link without heading, block and alias
+    /// - `resolved`: `true` if target is note of vault. Attachments (like `image.png`) are `false`
+    /// - `kind`: `link` or `embed` (`![[...]]`)
+    ///
+    /// Every link is a row, in order of content. Notes are sorted by `source`.
+    /// Fields with `,`, `"` or line break are quoted
+    ///
+    /// # Example
+    /// ```no_run
+    /// use obsidian_parser::prelude::*;
+    ///
+    /// let options = VaultOptions::new("/path/to/vault");
+    /// let vault: VaultOnDisk = VaultBuilder::new(&options)
+    ///     .into_iter()
+    ///     .filter_map(Result::ok)
+    ///     .build_vault(&options);
+    ///
+    /// std::fs::write("links.csv", vault.links_to_csv().unwrap()).unwrap();
+    /// ```
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip(self), fields(path = %self.path.display(), count_notes = %self.notes.len())))]
+    pub fn links_to_csv(&self) -> Result<String, N::Error> {
+        let index = Index::from_vault(self);
+
+        let mut notes: Vec<_> = self
+            .notes()
+            .iter()
+            .map(|note| (relative_path(note, &self.path).unwrap_or_default(), note))
+            .collect();
+        notes.sort_by(|(source, _), (other_source, _)| source.cmp(other_source));
+
+        let mut csv = String::from("source,target,resolved,kind\n");
+        for (source, note) in notes {
+            let content = note.content()?;
+
+            for (link, is_embed) in parse_links_with_embed_flag(&content) {
+                let resolved = index.get(link.target).is_some();
+                let kind = if is_embed { "embed" } else { "link" };
+
+                let _ = writeln!(
+                    csv,
+                    "{},{},{resolved},{kind}",
+                    escape_csv(&source),
+                    escape_csv(link.target)
+                );
+            }
+        }
+
+        #[cfg(feature = "tracing")]
+        tracing::debug!("Exported {} links", csv.lines().count() - 1);
+
+        Ok(csv)
+    }
+
+    /// Explain how link from note `from` to `to_target` is resolved
+    ///
+    /// Useful for debugging, why graph doesn't connect notes. Both `from` and `to_target`
+    /// are resolved like in [`Vault::backlinks`]
+    ///
+    /// # Example
+    /// ```no_run
+    /// use obsidian_parser::prelude::*;
+    /// use obsidian_parser::vault::vault_links::LinkStatus;
+    ///
+    /// let options = VaultOptions::new("/path/to/vault");
+    /// let vault: VaultOnDisk = VaultBuilder::new(&options)
+    ///     .into_iter()
+    ///     .filter_map(Result::ok)
+    ///     .build_vault(&options);
+    ///
+    /// let resolution = vault.explain_link("Physics", "Math").unwrap();
+    /// if !resolution.linked {
+    ///     println!("`Physics` doesn't link to `Math`");
+    /// }
+    ///
+    /// if let LinkStatus::Ambiguous { candidates, .. } = resolution.status {
+    ///     println!("{} notes with name `Math`", candidates.len());
+    /// }
+    /// ```
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip(self), fields(path = %self.path.display(), count_notes = %self.notes.len())))]
+    pub fn explain_link(
+        &self,
+        from: &str,
+        to_target: &str,
+    ) -> Result<LinkResolution<'_, N>, N::Error> {
+        let index = Index::from_vault(self);
+        let target = normalize_link(to_target);
+
+        let status = if target.contains('/') {
+            index
+                .full(&target)
+                .map_or(LinkStatus::NotFound, |i| LinkStatus::Full(&self.notes[*i]))
+        } else {
+            let candidates: Vec<_> = self
+                .notes()
+                .iter()
+                .filter(|note| {
+                    note.path().is_some()
+                        && note
+                            .note_name()
+                            .is_some_and(|name| normalize_link(&name) == target)
+                })
+                .collect();
+
+            match (candidates.first().copied(), candidates.len()) {
+                (None, _) => LinkStatus::NotFound,
+                (Some(note), 1) => LinkStatus::Short(note),
+                (Some(resolved), _) => LinkStatus::Ambiguous {
+                    resolved,
+                    candidates,
+                },
+            }
+        };
+
+        let source = index.get(from).map(|i| &self.notes[*i]);
+        let linked = match source {
+            Some(note) => parse_links(&note.content()?).any(|link| normalize_link(link) == target),
+            None => false,
+        };
+
+        #[cfg(feature = "tracing")]
+        tracing::debug!("Source found: {}, linked: {linked}", source.is_some());
+
+        Ok(LinkResolution {
+            source,
+            linked,
+            status,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::LinkStatus;
+    use crate::note::Note;
+    use crate::vault::vault_test::{create_test_vault, create_vault_with_files};
+    use std::collections::HashSet;
+
+    fn names<N: Note>(notes: &[&N]) -> HashSet<String> {
+        notes.iter().map(|note| note.note_name().unwrap()).collect()
+    }
+
+    #[cfg_attr(feature = "tracing", tracing_test::traced_test)]
+    #[test]
+    fn all_link_targets() {
+        let (vault, _path, _files) = create_test_vault().unwrap();
+
+        let targets = vault.all_link_targets().unwrap();
+
+        assert_eq!(
+            targets,
+            HashSet::from([
+                "main".to_string(),
+                "data/main".to_string(),
+                "link".to_string()
+            ])
+        );
+    }
+
+    #[cfg_attr(feature = "tracing", tracing_test::traced_test)]
+    #[test]
+    fn backlinks() {
+        let (vault, _path, _files) = create_test_vault().unwrap();
+
+        let backlinks = vault.backlinks("link").unwrap();
+        assert_eq!(backlinks.len(), 1);
+        assert_eq!(
+            backlinks[0].path().unwrap(),
+            vault.path().join("data").join("main.md")
+        );
+
+        // `link.md` is here too, if `[[main]]` is resolved to `data/main.md`
+        let backlinks = vault.backlinks("data/main").unwrap();
+        assert!(
+            backlinks
+                .iter()
+                .any(|note| note.path().unwrap() == vault.path().join("main.md"))
+        );
+
+        assert!(vault.backlinks("not_found").unwrap().is_empty());
+    }
+
+    #[cfg_attr(feature = "tracing", tracing_test::traced_test)]
+    #[test]
+    fn backlinks_with_shared_name() {
+        let (vault, _path) = create_vault_with_files(&[
+            ("a/note.md", "A"),
+            ("b/note.md", "B"),
+            ("x.md", "[[a/note]] [[a/note|twice]]"),
+            ("y.md", "[[b/note]]"),
+            ("z.md", "[[note]]"),
+        ])
+        .unwrap();
+
+        let to_a = names(&vault.backlinks("a/note").unwrap());
+        let to_b = names(&vault.backlinks("b/note").unwrap());
+
+        assert!(to_a.contains("x") && !to_a.contains("y"));
+        assert!(to_b.contains("y") && !to_b.contains("x"));
+
+        // `[[note]]` is resolved to one of them
+        assert!(to_a.contains("z") ^ to_b.contains("z"));
+        assert!(names(&vault.backlinks("note").unwrap()).contains("z"));
+    }
+
+    #[cfg_attr(feature = "tracing", tracing_test::traced_test)]
+    #[test]
+    fn backlinks_by_section() {
+        let (vault, _path) = create_vault_with_files(&[
+            ("target.md", "# Intro\n# Usage"),
+            ("a.md", "[[target#Intro]] [[target#Intro|again]] [[target]]"),
+            ("b.md", "[[target#Usage]] ![[target#Intro]]"),
+            ("c.md", "[[target^block]] [[other#Intro]]"),
+            ("d.md", "Without links"),
+        ])
+        .unwrap();
+
+        let sections = vault.backlinks_by_section("target").unwrap();
+        let section = |heading: Option<&str>| names(&sections[&heading.map(ToString::to_string)]);
+
+        assert_eq!(sections.len(), 3);
+        assert_eq!(sections[&Some("Intro".to_string())].len(), 2);
+        assert_eq!(
+            section(Some("Intro")),
+            HashSet::from(["a".to_string(), "b".to_string()])
+        );
+        assert_eq!(section(Some("Usage")), HashSet::from(["b".to_string()]));
+        assert_eq!(
+            section(None),
+            HashSet::from(["a".to_string(), "c".to_string()])
+        );
+
+        assert!(vault.backlinks_by_section("not_found").unwrap().is_empty());
+    }
+
+    #[cfg_attr(feature = "tracing", tracing_test::traced_test)]
+    #[test]
+    fn broken_links() {
+        let (vault, path) = create_vault_with_files(&[
+            (
+                "b.md",
+                "[[missing#heading|alias]] [[a]] [[missing]] ![[image.png]] ![[gone]]",
+            ),
+            ("a.md", "[[dir/c]] [[dir/missing]] [[c^block]]"),
+            ("dir/c.md", "[[b.md]]"),
+        ])
+        .unwrap();
+
+        let broken: Vec<_> = vault
+            .broken_links()
+            .unwrap()
+            .into_iter()
+            .map(|(note, target)| (note.path().unwrap().into_owned(), target))
+            .collect();
+
+        assert_eq!(
+            broken,
+            [
+                (path.path().join("a.md"), "dir/missing".to_string()),
+                (path.path().join("b.md"), "gone".to_string()),
+                (path.path().join("b.md"), "missing".to_string()),
+            ]
+        );
+    }
+
+    #[cfg_attr(feature = "tracing", tracing_test::traced_test)]
+    #[test]
+    fn broken_links_in_test_vault() {
+        let (vault, _path, _files) = create_test_vault().unwrap();
+
+        assert!(vault.broken_links().unwrap().is_empty());
+    }
+
+    #[cfg(feature = "rayon")]
+    #[cfg_attr(feature = "tracing", tracing_test::traced_test)]
+    #[test]
+    fn par_backlinks_and_broken_links() {
+        let (vault, _path) = create_vault_with_files(&[
+            ("a.md", "[[b]] [[missing]] [[b#heading]]"),
+            ("b.md", "[[a]] [[gone]] [[missing]]"),
+            ("c.md", "[[b]]"),
+            ("dir/d.md", "[[b]] ![[image.png]]"),
+        ])
+        .unwrap();
+
+        for name in ["a", "b", "dir/d", "not_found"] {
+            let sequential: Vec<_> = vault.backlinks(name).unwrap();
+            let parallel: Vec<_> = vault.par_backlinks(name).unwrap();
+
+            assert!(
+                sequential
+                    .iter()
+                    .zip(&parallel)
+                    .all(|(x, y)| std::ptr::eq(*x, *y))
+            );
+            assert_eq!(sequential.len(), parallel.len());
+        }
+        assert_eq!(vault.par_backlinks("b").unwrap().len(), 3);
+
+        let sequential = vault.broken_links().unwrap();
+        let parallel = vault.par_broken_links().unwrap();
+
+        assert_eq!(parallel.len(), 3);
+        assert!(
+            sequential
+                .iter()
+                .zip(&parallel)
+                .all(|((x, target), (y, other_target))| std::ptr::eq(*x, *y)
+                    && target == other_target)
+        );
+    }
+
+    #[cfg_attr(feature = "tracing", tracing_test::traced_test)]
+    #[test]
+    fn linked_pairs() {
+        let (vault, _path, _files) = create_test_vault().unwrap();
+        let note = |path: &[&str]| {
+            let path = path
+                .iter()
+                .fold(vault.path().to_path_buf(), |p, x| p.join(x));
+            vault
+                .notes()
+                .iter()
+                .find(|note| note.path().unwrap() == path)
+                .unwrap()
+        };
+
+        // `[[main]]` is resolved to first note with name `main`
+        let first_main = vault
+            .notes()
+            .iter()
+            .find(|note| note.note_name().unwrap() == "main")
+            .unwrap();
+
+        let pairs = vault.linked_pairs().unwrap();
+        let expected = [
+            (note(&["main.md"]), note(&["data", "main.md"])),
+            (note(&["link.md"]), first_main),
+            (note(&["data", "main.md"]), note(&["link.md"])),
+        ];
+
+        assert_eq!(pairs.len(), expected.len());
+        for (from, to) in expected {
+            assert!(
+                pairs
+                    .iter()
+                    .any(|(x, y)| std::ptr::eq(*x, from) && std::ptr::eq(*y, to))
+            );
+        }
+    }
+
+    #[cfg_attr(feature = "tracing", tracing_test::traced_test)]
+    #[test]
+    fn linked_pairs_skip_broken() {
+        let (vault, _path) =
+            create_vault_with_files(&[("a.md", "[[b]] [[missing]] [[b|again]]"), ("b.md", "B")])
+                .unwrap();
+
+        let pairs: Vec<_> = vault
+            .linked_pairs()
+            .unwrap()
+            .into_iter()
+            .map(|(from, to)| (from.note_name().unwrap(), to.note_name().unwrap()))
+            .collect();
+
+        assert_eq!(
+            pairs,
+            [
+                ("a".to_string(), "b".to_string()),
+                ("a".to_string(), "b".to_string())
+            ]
+        );
+    }
+
+    #[cfg_attr(feature = "tracing", tracing_test::traced_test)]
+    #[test]
+    fn orphan_notes() {
+        let (vault, _path) = create_vault_with_files(&[
+            ("a.md", "[[b]]"),
+            ("b.md", "Only incoming"),
+            ("alone.md", "No links"),
+            ("broken.md", "[[missing]]"),
+            ("self.md", "[[self]]"),
+            ("self_broken.md", "[[self_broken_typo]]"),
+        ])
+        .unwrap();
+
+        assert_eq!(
+            names(&vault.orphan_notes().unwrap()),
+            HashSet::from([
+                "alone".to_string(),
+                "broken".to_string(),
+                "self_broken".to_string()
+            ])
+        );
+    }
+
+    #[cfg_attr(feature = "tracing", tracing_test::traced_test)]
+    #[test]
+    fn most_linked_and_dead_end_notes() {
+        let (vault, _path) = create_vault_with_files(&[
+            ("hub.md", "[[hub]] [[a]]"),
+            ("a.md", "[[hub]] [[hub]] [[b]]"),
+            ("b.md", "[[hub]] [[missing]]"),
+            ("c.md", "[[b]]"),
+            ("dir/end.md", "[[missing]]"),
+            ("alone.md", "No links"),
+        ])
+        .unwrap();
+
+        let ranked = |top_n| -> Vec<_> {
+            vault
+                .most_linked_notes(top_n)
+                .unwrap()
+                .into_iter()
+                .map(|(note, count)| (note.note_name().unwrap(), count))
+                .collect()
+        };
+
+        assert_eq!(
+            ranked(10),
+            [
+                ("b".to_string(), 2),
+                ("hub".to_string(), 2),
+                ("a".to_string(), 1)
+            ]
+        );
+        assert_eq!(ranked(1), [("b".to_string(), 2)]);
+        assert!(ranked(0).is_empty());
+
+        let dead_ends: Vec<_> = vault
+            .dead_end_notes()
+            .unwrap()
+            .into_iter()
+            .map(|note| note.note_name().unwrap())
+            .collect();
+        assert_eq!(dead_ends, ["alone", "end"]);
+    }
+
+    #[cfg_attr(feature = "tracing", tracing_test::traced_test)]
+    #[test]
+    fn orphan_notes_in_test_vault() {
+        let (vault, _path, _files) = create_test_vault().unwrap();
+
+        assert!(vault.orphan_notes().unwrap().is_empty());
+    }
+
+    #[cfg_attr(feature = "tracing", tracing_test::traced_test)]
+    #[test]
+    fn explain_link_resolved() {
+        let (vault, _path) = create_vault_with_files(&[
+            ("a.md", "[[b]] [[dir/c|alias]]"),
+            ("b.md", "B"),
+            ("dir/c.md", "C"),
+        ])
+        .unwrap();
+
+        let resolution = vault.explain_link("a", "b").unwrap();
+        assert!(resolution.is_connected());
+        assert!(
+            matches!(resolution.status, LinkStatus::Short(note) if note.note_name().unwrap() == "b")
+        );
+        assert_eq!(resolution.source.unwrap().note_name().unwrap(), "a");
+
+        let resolution = vault.explain_link("a", "dir/c.md").unwrap();
+        assert!(resolution.is_connected());
+        assert!(
+            matches!(resolution.status, LinkStatus::Full(note) if note.note_name().unwrap() == "c")
+        );
+
+        // Resolved, but `b` doesn't link to `a`
+        let resolution = vault.explain_link("b", "a").unwrap();
+        assert!(!resolution.linked);
+        assert!(!resolution.is_connected());
+        assert!(matches!(resolution.status, LinkStatus::Short(_)));
+    }
+
+    #[cfg_attr(feature = "tracing", tracing_test::traced_test)]
+    #[test]
+    fn explain_link_unresolved() {
+        let (vault, _path) =
+            create_vault_with_files(&[("a.md", "[[missing]] [[dir/b]]"), ("b.md", "B")]).unwrap();
+
+        let resolution = vault.explain_link("a", "missing").unwrap();
+        assert!(resolution.linked);
+        assert_eq!(resolution.status, LinkStatus::NotFound);
+        assert!(resolution.target().is_none());
+        assert!(!resolution.is_connected());
+
+        // Full path doesn't match, though note with this name exists
+        let resolution = vault.explain_link("a", "dir/b").unwrap();
+        assert!(resolution.linked);
+        assert_eq!(resolution.status, LinkStatus::NotFound);
+
+        let resolution = vault.explain_link("not_found", "b").unwrap();
+        assert!(resolution.source.is_none());
+        assert!(!resolution.linked);
+        assert!(matches!(resolution.status, LinkStatus::Short(_)));
+    }
+
+    #[cfg_attr(feature = "tracing", tracing_test::traced_test)]
+    #[test]
+    fn explain_link_ambiguous() {
+        let (vault, _path) = create_vault_with_files(&[
+            ("a/note.md", "A"),
+            ("b/note.md", "B"),
+            ("x.md", "[[note]]"),
+        ])
+        .unwrap();
+
+        let resolution = vault.explain_link("x", "note").unwrap();
+        assert!(resolution.is_connected());
+
+        let LinkStatus::Ambiguous {
+            resolved,
+            candidates,
+        } = &resolution.status
+        else {
+            panic!("expected ambiguous link, got {:?}", resolution.status);
+        };
+
+        assert_eq!(candidates.len(), 2);
+        assert_eq!(candidates[0], *resolved);
+        assert_eq!(
+            names(candidates),
+            ["note".to_string()].into_iter().collect()
+        );
+
+        // `backlinks` resolves `[[note]]` to the same note
+        let full_path = resolved.path().unwrap();
+        let full_path = full_path
+            .strip_prefix(vault.path())
+            .unwrap()
+            .with_extension("");
+        assert_eq!(
+            names(&vault.backlinks(full_path.to_str().unwrap()).unwrap()),
+            ["x".to_string()].into_iter().collect()
+        );
+    }
+
+    #[cfg_attr(feature = "tracing", tracing_test::traced_test)]
+    #[test]
+    fn dangling_references() {
+        let (vault, path) = create_vault_with_files(&[
+            (
+                "a.md",
+                "[[b]] [[missing]] ![[image.png]] ![[gone.png]] [[doc.pdf]] [[missing|alias]]",
+            ),
+            ("b.md", "![[root.png]] ![[assets/nested.png]] ![[b]]"),
+            ("dir/c.md", "[[dir/missing]] ![[gone.png]]"),
+        ])
+        .unwrap();
+
+        std::fs::create_dir_all(path.path().join("attachments")).unwrap();
+        std::fs::create_dir_all(path.path().join("assets")).unwrap();
+        std::fs::write(path.path().join("attachments/image.png"), "").unwrap();
+        std::fs::write(path.path().join("attachments/doc.pdf"), "").unwrap();
+        std::fs::write(path.path().join("root.png"), "").unwrap();
+        std::fs::write(path.path().join("assets/nested.png"), "").unwrap();
+
+        let dangling = vault
+            .dangling_references(std::path::Path::new("attachments"))
+            .unwrap();
+
+        let expected = [
+            ("a", "gone.png"),
+            ("a", "missing"),
+            ("dir/c", "dir/missing"),
+            ("dir/c", "gone.png"),
+        ]
+        .map(|(note, target)| (note.to_string(), target.to_string()));
+
+        assert_eq!(dangling, expected);
+
+        // Absolute path of attachment dir works too
+        assert_eq!(
+            vault
+                .dangling_references(&path.path().join("attachments"))
+                .unwrap(),
+            expected
+        );
+    }
+
+    #[cfg_attr(feature = "tracing", tracing_test::traced_test)]
+    #[test]
+    fn dangling_references_with_dot_in_note_name() {
+        let (vault, _path) = create_vault_with_files(&[
+            (
+                "a.md",
+                "[[Meeting 2024.01.05]] ![[Meeting 2024.01.05]] [[Missing 2024.01.06]]",
+            ),
+            ("Meeting 2024.01.05.md", "Notes"),
+        ])
+        .unwrap();
+
+        assert_eq!(
+            vault
+                .dangling_references(std::path::Path::new("attachments"))
+                .unwrap(),
+            [("a".to_string(), "Missing 2024.01.06".to_string())]
+        );
+        assert_eq!(
+            vault
+                .broken_links()
+                .unwrap()
+                .into_iter()
+                .map(|(_, target)| target)
+                .collect::<Vec<_>>(),
+            ["Missing 2024.01.06"]
+        );
+    }
+
+    #[cfg_attr(feature = "tracing", tracing_test::traced_test)]
+    #[test]
+    fn links_to_csv() {
+        let (vault, _path) = create_vault_with_files(&[
+            ("b.md", "[[a]] ![[image.png]] [[missing|Alias]]"),
+            ("a.md", "[[b#Heading]] [[x, \"y\"]]"),
+            ("dir/c.md", "![[a]]"),
+        ])
+        .unwrap();
+
+        assert_eq!(
+            vault.links_to_csv().unwrap(),
+            "source,target,resolved,kind\n\
+             a,b,true,link\n\
+             a,\"x, \"\"y\"\"\",false,link\n\
+             b,a,true,link\n\
+             b,image.png,false,embed\n\
+             b,missing,false,link\n\
+             dir/c,a,true,embed\n"
+        );
+    }
+
+    #[cfg_attr(feature = "tracing", tracing_test::traced_test)]
+    #[test]
+    fn links_to_csv_test_vault() {
+        let (vault, _path, _files) = create_test_vault().unwrap();
+        let csv = vault.links_to_csv().unwrap();
+
+        assert_eq!(
+            csv.lines().collect::<Vec<_>>(),
+            [
+                "source,target,resolved,kind",
+                "data/main,link,true,link",
+                "link,main,true,link",
+                "main,data/main,true,link",
+            ]
+        );
+    }
+}
@@ -0,0 +1,130 @@
+//! Open notes of vault as map `note name -> note`
+
+use super::{Vault, error};
+use crate::{
+    note::note_read::NoteFromFile,
+    prelude::{VaultBuilder, VaultOptions},
+};
+use serde::de::DeserializeOwned;
+use std::{collections::HashMap, path::PathBuf};
+use thiserror::Error;
+
+/// Errors for [`Vault::open_as_map`]
+#[derive(Debug, Error)]
+pub enum Error<E>
+where
+    E: std::error::Error,
+{
+    /// Invalid path to vault
+    #[error(transparent)]
+    Vault(#[from] error::Error),
+
+    /// Note in vault can't be opened
+    #[error("Note `{}` error: {source}", path.display())]
+    Note {
+        /// Path to note
+        path: PathBuf,
+
+        /// Error from note
+        source: E,
+    },
+}
+
+impl<N> Vault<N>
+where
+    N: NoteFromFile,
+    N::Properties: DeserializeOwned,
+    N::Error: From<std::io::Error>,
+{
+    /// Open notes of vault as map `note name -> note` with path to vault
+    ///
+    /// Names are the same as [`Note::note_name`](crate::note::Note::note_name).
+    /// If several notes have the same name, the last opened one wins
+    /// (see [`Vault::get_duplicates_notes_by_name`] to find them)
+    ///
+    /// # Example
+    /// ```no_run
+    /// use obsidian_parser::prelude::*;
+    ///
+    /// let options = VaultOptions::new("/path/to/vault");
+    /// let (notes, path) = <VaultInMemory>::open_as_map(&options).unwrap();
+    ///
+    /// if let Some(note) = notes.get("Physics") {
+    ///     println!("{}", note.content().unwrap());
+    /// }
+    /// ```
+    ///
+    /// # Errors
+    /// - [`Error::Vault`] if path of `options` is not a directory
+    /// - [`Error::Note`] for the first note, which can't be opened
+    #[allow(clippy::type_complexity)]
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip(options), fields(path = %options.path().display())))]
+    pub fn open_as_map(
+        options: &VaultOptions,
+    ) -> Result<(HashMap<String, N>, PathBuf), Error<N::Error>> {
+        let path = options.path();
+        if !path.is_dir() {
+            return Err(error::Error::IsNotDir(path.to_path_buf()).into());
+        }
+
+        let mut first_error = None;
+        let notes: HashMap<_, _> = VaultBuilder::new(options)
+            .on_error::<N>(|path, error| {
+                first_error.get_or_insert((path, error));
+            })
+            .filter_map(|note| Some((note.note_name()?, note)))
+            .collect();
+
+        if let Some((path, source)) = first_error {
+            return Err(Error::Note { path, source });
+        }
+
+        #[cfg(feature = "tracing")]
+        tracing::debug!("Opened {} notes", notes.len());
+
+        Ok((notes, path.to_path_buf()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        prelude::{Note, VaultInMemory},
+        vault::vault_test::create_files_for_vault,
+    };
+    use std::collections::HashSet;
+
+    #[cfg_attr(feature = "tracing", tracing_test::traced_test)]
+    #[test]
+    fn open_as_map() {
+        let (path, _files) = create_files_for_vault().unwrap();
+
+        let options = VaultOptions::new(&path);
+        let (notes, vault_path) = <VaultInMemory>::open_as_map(&options).unwrap();
+
+        assert_eq!(vault_path, path.path());
+        assert_eq!(
+            notes.keys().cloned().collect::<HashSet<_>>(),
+            HashSet::from(["main".to_string(), "link".to_string()])
+        );
+
+        for (name, note) in &notes {
+            assert_eq!(note.note_name().as_ref(), Some(name));
+        }
+    }
+
+    #[cfg_attr(feature = "tracing", tracing_test::traced_test)]
+    #[test]
+    fn open_as_map_not_dir() {
+        let (path, _files) = create_files_for_vault().unwrap();
+
+        let options = VaultOptions::new(path.path().join("main.md"));
+        let result = <VaultInMemory>::open_as_map(&options);
+
+        assert!(matches!(
+            result,
+            Err(Error::Vault(error::Error::IsNotDir(_)))
+        ));
+    }
+}
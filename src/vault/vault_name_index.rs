@@ -0,0 +1,129 @@
+//! Find notes by name in vault
+
+use super::Vault;
+use crate::note::Note;
+use std::collections::{HashMap, hash_map::Entry};
+
+/// Index `note name -> note` for fast repeated lookups
+///
+/// Built by [`Vault::build_name_index`]. Names are the same as [`Note::note_name`]
+/// (used by [`Vault::get_duplicates_notes_by_name`] too).
+/// If several notes have the same name, the first one in [`Vault::notes`] wins
+#[derive(Debug, Clone)]
+pub struct NameIndex<'a, N> {
+    notes: HashMap<String, &'a N>,
+}
+
+impl<'a, N> NameIndex<'a, N> {
+    /// Get note by name
+    #[must_use]
+    pub fn get(&self, name: &str) -> Option<&'a N> {
+        self.notes.get(name).copied()
+    }
+
+    /// Count unique names in index
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.notes.len()
+    }
+
+    /// Index is empty?
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.notes.is_empty()
+    }
+}
+
+impl<N> Vault<N>
+where
+    N: Note,
+{
+    /// Returns note with name `name`
+    ///
+    /// If several notes have the same name, the first one in [`Vault::notes`] is returned
+    ///
+    /// # Performance
+    /// Operates in O(n) time. For many lookups use [`Vault::build_name_index`]
+    #[must_use]
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip(self), fields(path = %self.path.display(), count_notes = %self.notes.len())))]
+    pub fn get_note_by_name(&self, name: &str) -> Option<&N> {
+        self.notes()
+            .iter()
+            .find(|note| note.note_name().as_deref() == Some(name))
+    }
+
+    /// Build [`NameIndex`] for fast lookups by note name
+    ///
+    /// Index borrows vault, so it can't be outdated
+    ///
+    /// # Example
+    /// ```no_run
+    /// use obsidian_parser::prelude::*;
+    ///
+    /// let options = VaultOptions::new("/path/to/vault");
+    /// let vault: VaultInMemory = VaultBuilder::new(&options)
+    ///     .into_iter()
+    ///     .filter_map(Result::ok)
+    ///     .build_vault(&options);
+    ///
+    /// let index = vault.build_name_index();
+    /// for name in ["Physics", "Math"] {
+    ///     println!("{name}: {:?}", index.get(name).map(|note| note.path()));
+    /// }
+    /// ```
+    #[must_use]
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip(self), fields(path = %self.path.display(), count_notes = %self.notes.len())))]
+    pub fn build_name_index(&self) -> NameIndex<'_, N> {
+        #[cfg(feature = "tracing")]
+        tracing::debug!("Build name index...");
+
+        let mut notes = HashMap::with_capacity(self.count_notes());
+        for note in self.notes() {
+            if let Some(name) = note.note_name()
+                && let Entry::Vacant(entry) = notes.entry(name)
+            {
+                entry.insert(note);
+            }
+        }
+
+        NameIndex { notes }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{note::Note, vault::vault_test::create_vault_with_files};
+
+    const FILES: &[(&str, &str)] = &[
+        ("first.md", "First"),
+        ("second.md", "Second"),
+        ("dir/first.md", "Other first"),
+    ];
+
+    #[cfg_attr(feature = "tracing", tracing_test::traced_test)]
+    #[test]
+    fn get_note_by_name() {
+        let (vault, _path) = create_vault_with_files(FILES).unwrap();
+
+        let second = vault.get_note_by_name("second").unwrap();
+        assert_eq!(second.content().unwrap(), "Second");
+        assert!(vault.get_note_by_name("unknown").is_none());
+    }
+
+    #[cfg_attr(feature = "tracing", tracing_test::traced_test)]
+    #[test]
+    fn build_name_index() {
+        let (vault, _path) = create_vault_with_files(FILES).unwrap();
+        let index = vault.build_name_index();
+
+        assert_eq!(index.len(), 2);
+        assert!(index.get("unknown").is_none());
+
+        for name in ["first", "second"] {
+            assert!(std::ptr::eq(
+                index.get(name).unwrap(),
+                vault.get_note_by_name(name).unwrap()
+            ));
+        }
+    }
+}
@@ -0,0 +1,215 @@
+//! Display names of notes in [`Vault`]
+//!
+//! Link resolution always uses raw file stem ([`Note::note_name`]),
+//! transform changes only [`Vault::display_name`] and [`Vault::get_note_by_display_name`]
+
+use super::Vault;
+use crate::note::Note;
+use std::{fmt::Debug, sync::Arc};
+
+/// Function for transform note name to display name
+type TransformFn = dyn Fn(&str) -> String + Send + Sync;
+
+/// Transform of note name, set by [`Vault::set_name_transform`]
+///
+/// Identity if not set
+#[derive(Default, Clone)]
+pub struct NameTransform(Option<Arc<TransformFn>>);
+
+impl NameTransform {
+    /// Apply transform to `name`
+    #[must_use]
+    pub fn apply(&self, name: &str) -> String {
+        self.0
+            .as_ref()
+            .map_or_else(|| name.to_string(), |transform| transform(name))
+    }
+}
+
+impl Debug for NameTransform {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_tuple("NameTransform")
+            .field(&self.0.as_ref().map(|_| "Fn"))
+            .finish()
+    }
+}
+
+impl PartialEq for NameTransform {
+    fn eq(&self, other: &Self) -> bool {
+        match (&self.0, &other.0) {
+            (Some(transform), Some(other)) => Arc::ptr_eq(transform, other),
+            (None, None) => true,
+            _ => false,
+        }
+    }
+}
+
+impl Eq for NameTransform {}
+
+impl<N> Vault<N>
+where
+    N: Note,
+{
+    /// Set transform of note names for [`Vault::display_name`]
+    ///
+    /// Link resolution (graph, [`Vault::backlinks`], etc.) still uses raw [`Note::note_name`]
+    ///
+    /// # Example
+    /// ```no_run
+    /// use obsidian_parser::prelude::*;
+    ///
+    /// let options = VaultOptions::new("/path/to/vault");
+    /// let mut vault: VaultOnDisk = VaultBuilder::new(&options)
+    ///     .into_iter()
+    ///     .filter_map(Result::ok)
+    ///     .build_vault(&options);
+    ///
+    /// vault.set_name_transform(|name| name.to_uppercase());
+    /// ```
+    pub fn set_name_transform<F>(&mut self, transform: F)
+    where
+        F: Fn(&str) -> String + Send + Sync + 'static,
+    {
+        self.name_transform = NameTransform(Some(Arc::new(transform)));
+    }
+
+    /// Remove transform of note names. [`Vault::display_name`] will return [`Note::note_name`]
+    pub fn clear_name_transform(&mut self) {
+        self.name_transform = NameTransform::default();
+    }
+
+    /// Get display name of note: [`Note::note_name`] with transform from [`Vault::set_name_transform`]
+    ///
+    /// Returns [`None`] if note has no path
+    ///
+    /// # Example
+    /// ```
+    /// use obsidian_parser::prelude::*;
+    ///
+    /// let mut note = NoteInMemory::from_string_default("Data").unwrap();
+    /// note.set_path(Some("2025-01-01 Meeting.md".into()));
+    ///
+    /// let mut vault = Vault::default();
+    /// vault.set_name_transform(|name| name.trim_start_matches(|c: char| c.is_ascii_digit() || c == '-').trim().to_string());
+    ///
+    /// assert_eq!(vault.display_name(&note).unwrap(), "Meeting");
+    /// ```
+    #[must_use]
+    pub fn display_name(&self, note: &N) -> Option<String> {
+        note.note_name()
+            .map(|name| self.name_transform.apply(&name))
+    }
+
+    /// Returns note with display name `display_name` (see [`Vault::display_name`])
+    ///
+    /// If several notes have the same display name, the first one in [`Vault::notes`] is returned
+    ///
+    /// # Performance
+    /// Operates in O(n) time: transform is applied to name of every note
+    ///
+    /// # Example
+    /// ```
+    /// use obsidian_parser::prelude::*;
+    ///
+    /// let mut note = NoteInMemory::from_string_default("Data").unwrap();
+    /// note.set_path(Some("2025-01-01 Meeting.md".into()));
+    ///
+    /// let mut vault = Vault::from_notes(vec![note], "");
+    /// vault.set_name_transform(|name| name.trim_start_matches(|c: char| c.is_ascii_digit() || c == '-').trim().to_string());
+    ///
+    /// assert!(vault.get_note_by_display_name("Meeting").is_some());
+    /// assert!(vault.get_note_by_name("Meeting").is_none());
+    /// ```
+    #[must_use]
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip(self), fields(path = %self.path.display(), count_notes = %self.notes.len())))]
+    pub fn get_note_by_display_name(&self, display_name: &str) -> Option<&N> {
+        self.notes()
+            .iter()
+            .find(|note| self.display_name(note).as_deref() == Some(display_name))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::note::Note;
+    use crate::vault::vault_test::create_vault_with_files;
+
+    fn strip_date_prefix(name: &str) -> String {
+        let is_date = name.len() >= 10
+            && name.chars().take(10).enumerate().all(|(i, c)| {
+                if i == 4 || i == 7 {
+                    c == '-'
+                } else {
+                    c.is_ascii_digit()
+                }
+            });
+
+        if is_date {
+            name[10..].trim_start().to_string()
+        } else {
+            name.to_string()
+        }
+    }
+
+    #[cfg_attr(feature = "tracing", tracing_test::traced_test)]
+    #[test]
+    fn display_name() {
+        let (mut vault, _path) = create_vault_with_files(&[
+            ("2025-01-01 Meeting.md", "[[Plan]]"),
+            ("Plan.md", "[[2025-01-01 Meeting]]"),
+        ])
+        .unwrap();
+
+        let meeting = |vault: &crate::vault::Vault| {
+            vault
+                .notes()
+                .iter()
+                .find(|note| note.note_name().unwrap() == "2025-01-01 Meeting")
+                .cloned()
+                .unwrap()
+        };
+
+        let note = meeting(&vault);
+        assert_eq!(vault.display_name(&note).unwrap(), "2025-01-01 Meeting");
+
+        vault.set_name_transform(strip_date_prefix);
+        assert_eq!(vault.display_name(&note).unwrap(), "Meeting");
+
+        // Resolution still uses raw name
+        assert_eq!(vault.backlinks("2025-01-01 Meeting").unwrap().len(), 1);
+        assert!(vault.backlinks("Meeting").unwrap().is_empty());
+
+        // Lookup by display name
+        assert_eq!(*vault.get_note_by_display_name("Meeting").unwrap(), note);
+        assert_eq!(
+            vault
+                .get_note_by_display_name("Plan")
+                .unwrap()
+                .note_name()
+                .unwrap(),
+            "Plan"
+        );
+        assert!(
+            vault
+                .get_note_by_display_name("2025-01-01 Meeting")
+                .is_none()
+        );
+
+        vault.clear_name_transform();
+        assert_eq!(
+            vault.display_name(&meeting(&vault)).unwrap(),
+            "2025-01-01 Meeting"
+        );
+    }
+
+    #[cfg_attr(feature = "tracing", tracing_test::traced_test)]
+    #[test]
+    fn clone_and_eq() {
+        let (mut vault, _path) = create_vault_with_files(&[("note.md", "Data")]).unwrap();
+        vault.set_name_transform(|name| name.to_uppercase());
+
+        let cloned = vault.clone();
+        assert_eq!(cloned, vault);
+        assert_eq!(cloned.display_name(&cloned.notes()[0]).unwrap(), "NOTE");
+    }
+}
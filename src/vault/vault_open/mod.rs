@@ -3,16 +3,53 @@
 pub mod options;
 
 use super::Vault;
+use super::error::{CollectedError, OpenError};
+use super::vault_name_transform::NameTransform;
 use crate::note::{Note, note_on_disk::NoteOnDisk};
 pub use options::VaultOptions;
 use serde::de::DeserializeOwned;
 use std::{
     fmt::Debug,
     path::{Path, PathBuf},
+    sync::{
+        Arc,
+        atomic::{AtomicBool, Ordering},
+    },
 };
 use walkdir::{DirEntry, WalkDir};
 
-type FilterEntry = dyn FnMut(&DirEntry) -> bool;
+type LocalFilterEntry = dyn FnMut(&DirEntry) -> bool;
+type SendFilterEntry = dyn FnMut(&DirEntry) -> bool + Send;
+type OnProgress = dyn FnMut(usize) + Send;
+
+/// Custom filter of [`VaultBuilder::filter_entry`] or [`VaultBuilder::par_filter_entry`]
+enum FilterEntry {
+    Local(Box<LocalFilterEntry>),
+    Send(Box<SendFilterEntry>),
+}
+
+/// Running count of opened notes for [`VaultBuilder::on_progress`]
+struct Progress {
+    count: usize,
+    on_progress: Option<Box<OnProgress>>,
+}
+
+impl Progress {
+    fn new(on_progress: Option<Box<OnProgress>>) -> Self {
+        Self {
+            count: 0,
+            on_progress,
+        }
+    }
+
+    fn tick(&mut self) {
+        self.count += 1;
+
+        if let Some(on_progress) = &mut self.on_progress {
+            on_progress(self.count);
+        }
+    }
+}
 
 /// Builder for [`Vault`]
 pub struct VaultBuilder<'a> {
@@ -22,7 +59,21 @@ pub struct VaultBuilder<'a> {
     follow_root_links: bool,
     max_depth: Option<usize>,
     min_depth: Option<usize>,
-    filter_entry: Option<Box<FilterEntry>>,
+    filter_entry: Option<FilterEntry>,
+    on_progress: Option<Box<OnProgress>>,
+
+    /// Extensions of notes. [`None`] is only `md`
+    extensions: Option<Vec<String>>,
+
+    #[cfg(feature = "globset")]
+    include_globs: Vec<globset::GlobMatcher>,
+
+    #[cfg(feature = "globset")]
+    exclude_globs: Vec<globset::GlobMatcher>,
+
+    /// Files and directories to skip while walking
+    #[cfg(feature = "globset")]
+    ignore_globs: Vec<globset::GlobMatcher>,
 }
 
 impl Debug for VaultBuilder<'_> {
@@ -35,7 +86,7 @@ impl Debug for VaultBuilder<'_> {
 
 impl PartialEq for VaultBuilder<'_> {
     fn eq(&self, other: &Self) -> bool {
-        (
+        let eq = (
             self.options,
             self.include_hidden,
             self.follow_links,
@@ -43,6 +94,8 @@ impl PartialEq for VaultBuilder<'_> {
             self.max_depth,
             self.min_depth,
             self.filter_entry.is_some(),
+            self.on_progress.is_some(),
+            &self.extensions,
         ) == (
             other.options,
             other.include_hidden,
@@ -51,7 +104,14 @@ impl PartialEq for VaultBuilder<'_> {
             other.max_depth,
             other.min_depth,
             other.filter_entry.is_some(),
-        )
+            other.on_progress.is_some(),
+            &other.extensions,
+        );
+
+        #[cfg(feature = "globset")]
+        let eq = eq && self.glob_patterns() == other.glob_patterns();
+
+        eq
     }
 }
 
@@ -63,10 +123,30 @@ fn is_hidden(path: impl AsRef<Path>) -> bool {
         .is_some_and(|e| e.to_str().is_some_and(|name| name.starts_with('.')))
 }
 
-fn is_md_file(path: impl AsRef<Path>) -> bool {
+/// Default extension of notes
+const DEFAULT_EXTENSION: &str = "md";
+
+fn has_extension(path: impl AsRef<Path>, extensions: &[String]) -> bool {
+    path.as_ref().extension().is_some_and(|extension| {
+        extensions
+            .iter()
+            .any(|expected| extension.eq_ignore_ascii_case(expected))
+    })
+}
+
+pub(crate) fn is_md_file(path: impl AsRef<Path>) -> bool {
     path.as_ref()
         .extension()
-        .is_some_and(|p| p.eq_ignore_ascii_case("md"))
+        .is_some_and(|p| p.eq_ignore_ascii_case(DEFAULT_EXTENSION))
+}
+
+/// Tick [`Progress`] shared between threads
+#[cfg(feature = "rayon")]
+fn tick(progress: &std::sync::Mutex<Progress>) {
+    progress
+        .lock()
+        .unwrap_or_else(std::sync::PoisonError::into_inner)
+        .tick();
 }
 
 macro_rules! impl_setter {
@@ -92,6 +172,17 @@ impl<'a> VaultBuilder<'a> {
             max_depth: None,
             min_depth: None,
             filter_entry: None,
+            on_progress: None,
+            extensions: None,
+
+            #[cfg(feature = "globset")]
+            include_globs: Vec::new(),
+
+            #[cfg(feature = "globset")]
+            exclude_globs: Vec::new(),
+
+            #[cfg(feature = "globset")]
+            ignore_globs: Vec::new(),
         }
     }
 
@@ -114,15 +205,184 @@ impl<'a> VaultBuilder<'a> {
     }
 
     /// Set custom filter entry
+    ///
+    /// Parallel iterators can't stream paths with this filter, because it is not [`Send`]:
+    /// they collect all paths first. Use [`VaultBuilder::par_filter_entry`] to avoid it
     #[must_use]
     pub fn filter_entry<F>(mut self, f: F) -> Self
     where
         F: FnMut(&DirEntry) -> bool + 'static,
     {
-        self.filter_entry = Some(Box::new(f));
+        self.filter_entry = Some(FilterEntry::Local(Box::new(f)));
+        self
+    }
+
+    /// Same as [`VaultBuilder::filter_entry`], but for [`Send`] filter
+    ///
+    /// With this filter [`VaultBuilder::into_par_iter`] and [`VaultBuilder::par_on_error`]
+    /// stream paths into rayon instead of collecting them
+    #[must_use]
+    pub fn par_filter_entry<F>(mut self, f: F) -> Self
+    where
+        F: FnMut(&DirEntry) -> bool + Send + 'static,
+    {
+        self.filter_entry = Some(FilterEntry::Send(Box::new(f)));
         self
     }
 
+    /// Set extensions of notes (without `.`). Default is `["md"]`
+    ///
+    /// Matching is case-insensitive
+    ///
+    /// # Example
+    /// ```no_run
+    /// use obsidian_parser::prelude::*;
+    ///
+    /// let options = VaultOptions::new("/path/to/vault");
+    /// let vault: VaultInMemory = VaultBuilder::new(&options)
+    ///     .extensions(&["md", "markdown"])
+    ///     .into_iter()
+    ///     .filter_map(Result::ok)
+    ///     .build_vault(&options);
+    /// ```
+    #[must_use]
+    pub fn extensions(mut self, extensions: &[&str]) -> Self {
+        self.extensions = Some(extensions.iter().map(ToString::to_string).collect());
+        self
+    }
+
+    /// Call `f` with running count of opened notes (with broken ones) after every note
+    ///
+    /// Iterators are lazy, so `f` is called while notes are consumed.
+    /// For parallel iterators `f` is called under lock, so count always increases
+    ///
+    /// # Example
+    /// ```no_run
+    /// use obsidian_parser::prelude::*;
+    ///
+    /// let options = VaultOptions::new("/path/to/vault");
+    /// let vault: VaultOnDisk = VaultBuilder::new(&options)
+    ///     .on_progress(|count| {
+    ///         if count % 1000 == 0 {
+    ///             println!("Opened {count} notes");
+    ///         }
+    ///     })
+    ///     .into_iter()
+    ///     .filter_map(Result::ok)
+    ///     .build_vault(&options);
+    /// ```
+    #[must_use]
+    pub fn on_progress<F>(mut self, f: F) -> Self
+    where
+        F: FnMut(usize) + Send + 'static,
+    {
+        self.on_progress = Some(Box::new(f));
+        self
+    }
+
+    /// Include only notes, which path (relative to vault) matches `pattern`
+    ///
+    /// Several include globs are composed with "or". `*` doesn't match `/`, use `**` for any directories.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use obsidian_parser::prelude::*;
+    ///
+    /// let options = VaultOptions::new("/path/to/vault");
+    /// let vault: VaultInMemory = VaultBuilder::new(&options)
+    ///     .include_glob("**/journal/**/*.md")
+    ///     .unwrap()
+    ///     .exclude_glob("**/drafts/**")
+    ///     .unwrap()
+    ///     .into_iter()
+    ///     .filter_map(Result::ok)
+    ///     .build_vault(&options);
+    /// ```
+    ///
+    /// # Errors
+    /// If `pattern` is invalid glob
+    #[cfg_attr(docsrs, doc(cfg(feature = "globset")))]
+    #[cfg(feature = "globset")]
+    pub fn include_glob(mut self, pattern: &str) -> Result<Self, globset::Error> {
+        self.include_globs.push(Self::compile_glob(pattern)?);
+        Ok(self)
+    }
+
+    /// Exclude notes, which path (relative to vault) matches `pattern`
+    ///
+    /// Exclude globs take precedence over include globs. See [`VaultBuilder::include_glob`]
+    ///
+    /// # Errors
+    /// If `pattern` is invalid glob
+    #[cfg_attr(docsrs, doc(cfg(feature = "globset")))]
+    #[cfg(feature = "globset")]
+    pub fn exclude_glob(mut self, pattern: &str) -> Result<Self, globset::Error> {
+        self.exclude_globs.push(Self::compile_glob(pattern)?);
+        Ok(self)
+    }
+
+    /// Skip files and directories, which path (relative to vault) matches any of `patterns`
+    ///
+    /// Unlike [`VaultBuilder::exclude_glob`], matching is done while walking, so a matched
+    /// directory is not visited at all (like `.gitignore`). Use `**/name` to match at any depth.
+    /// Works together with [`VaultBuilder::include_hidden`] and [`VaultBuilder::filter_entry`]:
+    /// entry is visited only if all of them allow it
+    ///
+    /// # Example
+    /// ```no_run
+    /// use obsidian_parser::prelude::*;
+    ///
+    /// let options = VaultOptions::new("/path/to/vault");
+    /// let vault: VaultInMemory = VaultBuilder::new(&options)
+    ///     .ignore_globs(&["templates", "**/archive", "*.excalidraw.md"])
+    ///     .unwrap()
+    ///     .into_iter()
+    ///     .filter_map(Result::ok)
+    ///     .build_vault(&options);
+    /// ```
+    ///
+    /// # Errors
+    /// If any of `patterns` is invalid glob
+    #[cfg_attr(docsrs, doc(cfg(feature = "globset")))]
+    #[cfg(feature = "globset")]
+    pub fn ignore_globs(mut self, patterns: &[&str]) -> Result<Self, globset::Error> {
+        for pattern in patterns {
+            self.ignore_globs.push(Self::compile_glob(pattern)?);
+        }
+
+        Ok(self)
+    }
+
+    #[cfg(feature = "globset")]
+    fn compile_glob(pattern: &str) -> Result<globset::GlobMatcher, globset::Error> {
+        Ok(globset::GlobBuilder::new(pattern)
+            .literal_separator(true)
+            .build()?
+            .compile_matcher())
+    }
+
+    /// Patterns of include, exclude and ignore globs
+    #[cfg(feature = "globset")]
+    fn glob_patterns(&self) -> (Vec<&str>, Vec<&str>, Vec<&str>) {
+        fn patterns(globs: &[globset::GlobMatcher]) -> Vec<&str> {
+            globs.iter().map(|glob| glob.glob().glob()).collect()
+        }
+
+        (
+            patterns(&self.include_globs),
+            patterns(&self.exclude_globs),
+            patterns(&self.ignore_globs),
+        )
+    }
+
+    /// Entry matches [`VaultBuilder::ignore_globs`]
+    #[cfg(feature = "globset")]
+    fn ignored_by_globs(root: &Path, globs: &[globset::GlobMatcher], entry: &DirEntry) -> bool {
+        let path = entry.path();
+        let relative = path.strip_prefix(root).unwrap_or(path);
+        globs.iter().any(|glob| glob.is_match(relative))
+    }
+
     fn ignored_hidden_files(include_hidden: bool, entry: &DirEntry) -> bool {
         if !include_hidden && is_hidden(entry.path()) {
             return false;
@@ -131,45 +391,340 @@ impl<'a> VaultBuilder<'a> {
         true
     }
 
-    fn get_files_from_walkdir(self) -> impl Iterator<Item = PathBuf> {
+    /// Take callback of [`VaultBuilder::on_progress`]
+    fn progress(&mut self) -> Progress {
+        Progress::new(self.on_progress.take())
+    }
+
+    /// Same as [`VaultBuilder::progress`], but for parallel iterators
+    #[cfg(feature = "rayon")]
+    fn par_progress(&mut self) -> std::sync::Mutex<Progress> {
+        std::sync::Mutex::new(self.progress())
+    }
+
+    fn get_files_from_walkdir(mut self) -> impl Iterator<Item = PathBuf> {
+        let custom_filter_entry: Box<LocalFilterEntry> = match self.filter_entry.take() {
+            Some(FilterEntry::Local(f)) => f,
+            Some(FilterEntry::Send(f)) => f,
+            None => Box::new(|_| true),
+        };
+
+        self.walk_files(custom_filter_entry)
+    }
+
+    /// Paths of notes for parallel iterators
+    ///
+    /// Paths are streamed into rayon, if filter is [`Send`]. Otherwise they are collected first
+    #[cfg(feature = "rayon")]
+    fn par_get_files_from_walkdir(mut self) -> impl rayon::iter::ParallelIterator<Item = PathBuf> {
+        use rayon::iter::Either;
+        use rayon::prelude::*;
+
+        match self.filter_entry.take() {
+            Some(FilterEntry::Local(f)) => {
+                let files: Vec<_> = self.walk_files(f).collect();
+                Either::Left(files.into_par_iter())
+            }
+            Some(FilterEntry::Send(f)) => Either::Right(self.walk_files(f).par_bridge()),
+            None => {
+                let f: Box<SendFilterEntry> = Box::new(|_| true);
+                Either::Right(self.walk_files(f).par_bridge())
+            }
+        }
+    }
+
+    /// Walk vault in order of file names
+    fn walk_files<E>(self, mut custom_filter_entry: E) -> impl Iterator<Item = PathBuf>
+    where
+        E: FnMut(&DirEntry) -> bool,
+    {
         let include_hidden = self.include_hidden;
-        let mut custom_filter_entry = self.filter_entry.unwrap_or_else(|| Box::new(|_| true));
+        let extensions = self.extensions;
+
+        #[cfg(feature = "globset")]
+        let (root, include_globs, exclude_globs) = (
+            self.options.path().to_path_buf(),
+            self.include_globs,
+            self.exclude_globs,
+        );
 
-        WalkDir::new(self.options.path())
+        #[cfg(feature = "globset")]
+        let (ignore_root, ignore_globs) = (root.clone(), self.ignore_globs);
+
+        let files = WalkDir::new(self.options.path())
             .follow_links(self.follow_links)
             .follow_root_links(self.follow_root_links)
             .max_depth(self.max_depth.unwrap_or(usize::MAX))
             .min_depth(self.min_depth.unwrap_or(1))
+            .sort_by_file_name()
             .into_iter()
             .filter_entry(move |entry| {
+                #[cfg(feature = "globset")]
+                if Self::ignored_by_globs(&ignore_root, &ignore_globs, entry) {
+                    return false;
+                }
+
                 Self::ignored_hidden_files(include_hidden, entry) && custom_filter_entry(entry)
             })
             .filter_map(Result::ok)
             .filter(|entry| entry.file_type().is_file())
             .map(DirEntry::into_path)
-            .filter(|path| is_md_file(path))
+            .filter(move |path| {
+                extensions.as_ref().map_or_else(
+                    || is_md_file(path),
+                    |extensions| has_extension(path, extensions),
+                )
+            });
+
+        #[cfg(feature = "globset")]
+        let files = files.filter(move |path| {
+            let relative = path.strip_prefix(&root).unwrap_or(path);
+            let is_match =
+                |globs: &[globset::GlobMatcher]| globs.iter().any(|glob| glob.is_match(relative));
+
+            !is_match(&exclude_globs) && (include_globs.is_empty() || is_match(&include_globs))
+        });
+
+        files
     }
 
     /// Into [`VaultBuilder`] to iterator
     #[allow(clippy::should_implement_trait)]
     #[cfg(not(target_family = "wasm"))]
-    pub fn into_iter<F>(self) -> impl Iterator<Item = Result<F, F::Error>>
+    pub fn into_iter<F>(mut self) -> impl Iterator<Item = Result<F, F::Error>>
     where
         F: crate::note::note_read::NoteFromFile,
         F::Properties: DeserializeOwned,
         F::Error: From<std::io::Error>,
     {
+        let mut progress = self.progress();
         let files = self.get_files_from_walkdir();
 
-        files.map(|path| F::from_file(path))
+        files
+            .map(|path| F::from_file(path))
+            .inspect(move |_| progress.tick())
+    }
+
+    /// Into [`VaultBuilder`] to iterator of successfully parsed notes
+    ///
+    /// Errors are reported to `on_error` with path to the broken note
+    ///
+    /// # Example
+    /// ```no_run
+    /// use obsidian_parser::prelude::*;
+    ///
+    /// let options = VaultOptions::new("/path/to/vault");
+    /// let vault: VaultInMemory = VaultBuilder::new(&options)
+    ///     .on_error(|path, error| eprintln!("{}: {error}", path.display()))
+    ///     .build_vault(&options);
+    /// ```
+    #[cfg(not(target_family = "wasm"))]
+    pub fn on_error<F>(
+        mut self,
+        mut on_error: impl FnMut(PathBuf, F::Error),
+    ) -> impl Iterator<Item = F>
+    where
+        F: crate::note::note_read::NoteFromFile,
+        F::Properties: DeserializeOwned,
+        F::Error: From<std::io::Error>,
+    {
+        let mut progress = self.progress();
+        let files = self.get_files_from_walkdir();
+
+        files
+            .map(|path| (F::from_file(&path), path))
+            .inspect(move |_| progress.tick())
+            .filter_map(move |(result, path)| match result {
+                Ok(note) => Some(note),
+                Err(error) => {
+                    on_error(path, error);
+                    None
+                }
+            })
+    }
+
+    /// Into [`VaultBuilder`] to parallel iterator of successfully parsed notes
+    ///
+    /// Errors are reported to `on_error` with path to the broken note
+    ///
+    /// # Order
+    /// Notes are yielded in arbitrary order, see [`VaultBuilder::into_par_iter`]
+    #[cfg_attr(docsrs, doc(cfg(feature = "rayon")))]
+    #[cfg(feature = "rayon")]
+    #[cfg(not(target_family = "wasm"))]
+    pub fn par_on_error<F>(
+        mut self,
+        on_error: impl Fn(PathBuf, F::Error) + Send + Sync,
+    ) -> impl rayon::iter::ParallelIterator<Item = F>
+    where
+        F: crate::prelude::NoteFromFile + Send,
+        F::Properties: DeserializeOwned,
+        F::Error: From<std::io::Error> + Send,
+    {
+        use rayon::prelude::*;
+
+        let progress = self.par_progress();
+        self.par_get_files_from_walkdir()
+            .map(move |path| {
+                let result = F::from_file(&path);
+                tick(&progress);
+
+                (result, path)
+            })
+            .filter_map(move |(result, path)| match result {
+                Ok(note) => Some(note),
+                Err(error) => {
+                    on_error(path, error);
+                    None
+                }
+            })
+    }
+
+    /// Build [`Vault`] from successfully parsed notes and collect errors with paths of broken notes
+    ///
+    /// Same as [`VaultBuilder::on_error`] and [`IteratorVaultBuilder::build_vault`], but
+    /// without collecting errors by hand
+    ///
+    /// # Example
+    /// ```no_run
+    /// use obsidian_parser::prelude::*;
+    ///
+    /// let options = VaultOptions::new("/path/to/vault");
+    /// let (vault, errors) = VaultBuilder::new(&options).build_collecting::<NoteInMemory>();
+    ///
+    /// for error in errors {
+    ///     eprintln!("{error}");
+    /// }
+    /// println!("Opened {} notes", vault.count_notes());
+    /// ```
+    #[cfg(not(target_family = "wasm"))]
+    #[must_use]
+    pub fn build_collecting<F>(self) -> (Vault<F>, Vec<CollectedError<F::Error>>)
+    where
+        F: crate::note::note_read::NoteFromFile,
+        F::Properties: DeserializeOwned,
+        F::Error: From<std::io::Error> + 'static,
+    {
+        let options = self.options;
+
+        let mut errors = Vec::new();
+        let vault = self
+            .on_error(|path, source| errors.push(CollectedError { path, source }))
+            .build_vault(options);
+
+        #[cfg(feature = "tracing")]
+        tracing::debug!("Collected {} errors", errors.len());
+
+        (vault, errors)
+    }
+
+    /// Parallel version of [`VaultBuilder::build_collecting`]
+    ///
+    /// Errors are in order of files, like in [`VaultBuilder::build_collecting`]
+    #[cfg_attr(docsrs, doc(cfg(feature = "rayon")))]
+    #[cfg(feature = "rayon")]
+    #[cfg(not(target_family = "wasm"))]
+    #[must_use]
+    pub fn par_build_collecting<F>(mut self) -> (Vault<F>, Vec<CollectedError<F::Error>>)
+    where
+        F: crate::prelude::NoteFromFile + Send,
+        F::Properties: DeserializeOwned,
+        F::Error: From<std::io::Error> + Send + 'static,
+    {
+        use rayon::iter::Either;
+        use rayon::prelude::*;
+
+        let options = self.options;
+        let progress = self.par_progress();
+        let files: Vec<_> = self.get_files_from_walkdir().collect();
+
+        let (notes, errors): (Vec<_>, Vec<_>) = files.into_par_iter().partition_map(|path| {
+            let result = F::from_file(&path);
+            tick(&progress);
+
+            match result {
+                Ok(note) => Either::Left(note),
+                Err(source) => Either::Right(CollectedError { path, source }),
+            }
+        });
+
+        #[cfg(feature = "tracing")]
+        tracing::debug!("Collected {} errors", errors.len());
+
+        (Vault::impl_build_vault(notes, options.clone()), errors)
+    }
+
+    /// Into [`VaultBuilder`] to iterator, which can be cancelled with `cancel_flag`
+    ///
+    /// The flag is checked between files. When it is set, iterator yields
+    /// [`OpenError::Cancelled`] and stops
+    ///
+    /// # Example
+    /// ```no_run
+    /// use obsidian_parser::prelude::*;
+    /// use std::sync::{Arc, atomic::AtomicBool};
+    ///
+    /// let cancel_flag = Arc::new(AtomicBool::new(false));
+    ///
+    /// // Set `cancel_flag` from other thread (for example, from GUI)
+    /// let options = VaultOptions::new("/path/to/vault");
+    /// let notes: Result<Vec<NoteInMemory>, _> = VaultBuilder::new(&options)
+    ///     .into_iter_cancellable(cancel_flag.clone())
+    ///     .collect();
+    ///
+    /// let vault = notes.unwrap().into_iter().build_vault(&options);
+    /// ```
+    #[cfg(not(target_family = "wasm"))]
+    pub fn into_iter_cancellable<F>(
+        mut self,
+        cancel_flag: Arc<AtomicBool>,
+    ) -> impl Iterator<Item = Result<F, OpenError<F::Error>>>
+    where
+        F: crate::note::note_read::NoteFromFile,
+        F::Properties: DeserializeOwned,
+        F::Error: From<std::io::Error>,
+    {
+        let mut progress = self.progress();
+        let mut files = self.get_files_from_walkdir();
+        let mut cancelled = false;
+
+        std::iter::from_fn(move || {
+            if cancelled {
+                return None;
+            }
+
+            if cancel_flag.load(Ordering::Relaxed) {
+                #[cfg(feature = "tracing")]
+                tracing::debug!("Opening vault was cancelled");
+
+                cancelled = true;
+                return Some(Err(OpenError::Cancelled));
+            }
+
+            let result = files
+                .next()
+                .map(|path| F::from_file(path).map_err(OpenError::Note))?;
+
+            progress.tick();
+            Some(result)
+        })
     }
 
     /// Into [`VaultBuilder`] to parallel iterator
+    ///
+    /// Paths are streamed from walkdir to rayon, so they are never collected into memory at once
+    /// (except with [`VaultBuilder::filter_entry`], see [`VaultBuilder::par_filter_entry`])
+    ///
+    /// # Order
+    /// Notes are yielded in arbitrary order. [`ParallelIteratorVaultBuilder::build_vault`] sorts
+    /// them by path, so [`Vault::notes`] has the same order as with [`VaultBuilder::into_iter`]
     #[cfg_attr(docsrs, doc(cfg(feature = "rayon")))]
     #[cfg(feature = "rayon")]
     #[cfg(not(target_family = "wasm"))]
     #[must_use]
-    pub fn into_par_iter<F>(self) -> impl rayon::iter::ParallelIterator<Item = Result<F, F::Error>>
+    pub fn into_par_iter<F>(
+        mut self,
+    ) -> impl rayon::iter::ParallelIterator<Item = Result<F, F::Error>>
     where
         F: crate::prelude::NoteFromFile + Send,
         F::Properties: DeserializeOwned,
@@ -177,8 +732,13 @@ impl<'a> VaultBuilder<'a> {
     {
         use rayon::prelude::*;
 
-        let files: Vec<_> = self.get_files_from_walkdir().collect();
-        files.into_par_iter().map(|path| F::from_file(path))
+        let progress = self.par_progress();
+        self.par_get_files_from_walkdir().map(move |path| {
+            let result = F::from_file(path);
+            tick(&progress);
+
+            result
+        })
     }
 }
 
@@ -194,6 +754,7 @@ where
         Self {
             notes,
             path: options.into_path(),
+            name_transform: NameTransform::default(),
         }
     }
 
@@ -205,6 +766,9 @@ where
     }
 
     /// Build vault from parallel iterator
+    ///
+    /// Notes are sorted by path (notes without path go first), because order of parallel
+    /// iterator may be arbitrary. It is the order of [`VaultBuilder::into_iter`]
     #[cfg_attr(docsrs, doc(cfg(feature = "rayon")))]
     #[cfg(feature = "rayon")]
     pub fn par_build_vault(
@@ -214,12 +778,71 @@ where
     where
         N: Send,
     {
-        let notes: Vec<_> = iter.collect();
+        use rayon::slice::ParallelSliceMut;
+
+        let mut notes: Vec<_> = iter.collect();
+        notes.par_sort_by(|a, b| a.path().cmp(&b.path()));
 
         Self::impl_build_vault(notes, options.clone())
     }
 }
 
+#[cfg(not(target_family = "wasm"))]
+impl<N> Vault<N>
+where
+    N: crate::note::note_read::NoteFromFile,
+    N::Properties: DeserializeOwned,
+    N::Error: From<std::io::Error>,
+{
+    /// Open vault with [`VaultBuilder`] configured by `configure`
+    ///
+    /// Same as `VaultBuilder::new(options)`, `configure` and [`VaultBuilder::build_collecting`].
+    /// Notes, which can't be opened, are skipped and returned as [`CollectedError`]
+    ///
+    /// # Example
+    /// ```no_run
+    /// use obsidian_parser::prelude::*;
+    ///
+    /// let options = VaultOptions::new("/path/to/vault");
+    /// let (vault, errors) =
+    ///     <VaultInMemory>::open_with(&options, |builder| builder.include_hidden(true).max_depth(2))
+    ///         .unwrap();
+    ///
+    /// for error in errors {
+    ///     eprintln!("{error}");
+    /// }
+    /// println!("Opened {} notes", vault.count_notes());
+    /// ```
+    ///
+    /// # Errors
+    /// [`Error::IsNotDir`](super::error::Error::IsNotDir) if path of `options` is not a directory
+    #[allow(clippy::type_complexity)]
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip(options, configure), fields(path = %options.path().display())))]
+    pub fn open_with(
+        options: &VaultOptions,
+        configure: impl FnOnce(VaultBuilder<'_>) -> VaultBuilder<'_>,
+    ) -> Result<(Self, Vec<CollectedError<N::Error>>), super::error::Error>
+    where
+        N::Error: 'static,
+    {
+        let path = options.path();
+        if !path.is_dir() {
+            return Err(super::error::Error::IsNotDir(path.to_path_buf()));
+        }
+
+        let (vault, errors) = configure(VaultBuilder::new(options)).build_collecting();
+
+        #[cfg(feature = "tracing")]
+        tracing::debug!(
+            "Opened {} notes, {} errors",
+            vault.count_notes(),
+            errors.len()
+        );
+
+        Ok((vault, errors))
+    }
+}
+
 /// Trait for build [`Vault`] from iterator
 pub trait IteratorVaultBuilder<N = NoteOnDisk>: Iterator<Item = N>
 where
@@ -248,6 +871,8 @@ where
     N: Note + Send,
 {
     /// Build [`Vault`] from parallel iterator
+    ///
+    /// Notes are sorted by path, see [`Vault::par_build_vault`]
     #[cfg_attr(docsrs, doc(cfg(feature = "rayon")))]
     fn build_vault(self, options: &VaultOptions) -> Vault<N> {
         Vault::par_build_vault(self, options)
@@ -316,6 +941,17 @@ mod tests {
         assert_eq!(vault.path(), path.path());
     }
 
+    #[tracing_test::traced_test]
+    #[test]
+    #[cfg(feature = "tracing")]
+    fn open_emits_tracing_events() {
+        let (path, _vault_notes) = create_files_for_vault().unwrap();
+
+        let _vault: VaultInMemory = impl_open(&path);
+
+        assert!(logs_contain("Building vault..."));
+    }
+
     #[cfg_attr(feature = "tracing", tracing_test::traced_test)]
     #[test]
     #[cfg(feature = "rayon")]
@@ -353,6 +989,145 @@ mod tests {
         assert_eq!(vault.path(), path.path());
     }
 
+    #[cfg(feature = "globset")]
+    fn open_with_globs(include: &[&str], exclude: &[&str]) -> Result<Vec<PathBuf>, globset::Error> {
+        open_with_all_globs(include, exclude, &[])
+    }
+
+    #[cfg(feature = "globset")]
+    fn open_with_all_globs(
+        include: &[&str],
+        exclude: &[&str],
+        ignore: &[&str],
+    ) -> Result<Vec<PathBuf>, globset::Error> {
+        use crate::note::Note;
+
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        for path in [
+            "main.md",
+            "journal/2025-01-01.md",
+            "journal/drafts/idea.md",
+            "work/journal/meeting.md",
+            "templates/daily.md",
+            "work/archive/old.md",
+        ] {
+            let path = temp_dir.path().join(path);
+            std::fs::create_dir_all(path.parent().unwrap()).unwrap();
+            File::create(path).unwrap();
+        }
+
+        let options = VaultOptions::new(&temp_dir);
+        let mut builder = VaultBuilder::new(&options);
+        for pattern in include {
+            builder = builder.include_glob(pattern)?;
+        }
+        for pattern in exclude {
+            builder = builder.exclude_glob(pattern)?;
+        }
+        builder = builder.ignore_globs(ignore)?;
+
+        let vault: VaultInMemory = builder
+            .into_iter()
+            .map(Result::unwrap)
+            .build_vault(&options);
+        let mut paths: Vec<_> = vault
+            .notes()
+            .iter()
+            .map(|note| {
+                note.path()
+                    .unwrap()
+                    .strip_prefix(&temp_dir)
+                    .unwrap()
+                    .to_path_buf()
+            })
+            .collect();
+        paths.sort();
+
+        Ok(paths)
+    }
+
+    #[cfg(feature = "globset")]
+    #[cfg_attr(feature = "tracing", tracing_test::traced_test)]
+    #[test]
+    fn include_glob() {
+        let paths = open_with_globs(&["**/journal/**/*.md"], &[]).unwrap();
+
+        assert_eq!(
+            paths,
+            [
+                PathBuf::from("journal/2025-01-01.md"),
+                PathBuf::from("journal/drafts/idea.md"),
+                PathBuf::from("work/journal/meeting.md"),
+            ]
+        );
+    }
+
+    #[cfg(feature = "globset")]
+    #[cfg_attr(feature = "tracing", tracing_test::traced_test)]
+    #[test]
+    fn include_and_exclude_globs() {
+        let paths = open_with_globs(&["journal/**", "*.md"], &["**/drafts/**"]).unwrap();
+
+        assert_eq!(
+            paths,
+            [
+                PathBuf::from("journal/2025-01-01.md"),
+                PathBuf::from("main.md"),
+            ]
+        );
+    }
+
+    #[cfg(feature = "globset")]
+    #[cfg_attr(feature = "tracing", tracing_test::traced_test)]
+    #[test]
+    fn ignore_globs() {
+        let paths = open_with_all_globs(
+            &[],
+            &[],
+            &["templates", "**/archive", "journal/drafts/*.md"],
+        )
+        .unwrap();
+
+        assert_eq!(
+            paths,
+            [
+                PathBuf::from("journal/2025-01-01.md"),
+                PathBuf::from("main.md"),
+                PathBuf::from("work/journal/meeting.md"),
+            ]
+        );
+
+        let paths = open_with_all_globs(&["**/*.md"], &["main.md"], &["work"]).unwrap();
+        assert_eq!(
+            paths,
+            [
+                PathBuf::from("journal/2025-01-01.md"),
+                PathBuf::from("journal/drafts/idea.md"),
+                PathBuf::from("templates/daily.md"),
+            ]
+        );
+
+        assert!(open_with_all_globs(&[], &[], &["[a"]).is_err());
+    }
+
+    #[cfg(feature = "globset")]
+    #[cfg_attr(feature = "tracing", tracing_test::traced_test)]
+    #[test]
+    fn invalid_glob() {
+        assert!(open_with_globs(&["journal/[a"], &[]).is_err());
+    }
+
+    #[cfg(feature = "globset")]
+    #[cfg_attr(feature = "tracing", tracing_test::traced_test)]
+    #[test]
+    fn eq_with_globs() {
+        let options = VaultOptions::new("path");
+        let builder = || VaultBuilder::new(&options).include_glob("*.md").unwrap();
+
+        assert_eq!(builder(), builder());
+        assert_ne!(builder(), VaultBuilder::new(&options));
+    }
+
     #[cfg_attr(feature = "tracing", tracing_test::traced_test)]
     #[test]
     fn open_with_error() {
@@ -396,6 +1171,50 @@ mod tests {
         ));
     }
 
+    #[cfg_attr(feature = "tracing", tracing_test::traced_test)]
+    #[test]
+    fn build_collecting() {
+        let (path, files) = create_files_for_vault().unwrap();
+        std::fs::write(path.path().join("broken.md"), "---").unwrap();
+
+        let options = VaultOptions::new(&path);
+        let (vault, errors) = VaultBuilder::new(&options).build_collecting::<NoteInMemory>();
+
+        assert_eq!(vault.count_notes(), files.len());
+        assert_eq!(errors.len(), 1);
+        assert_eq!(errors[0].path, path.path().join("broken.md"));
+        assert!(matches!(
+            errors[0].source,
+            note_in_memory::Error::InvalidFormat(_)
+        ));
+        assert!(errors[0].to_string().contains("broken.md"));
+    }
+
+    #[cfg_attr(feature = "tracing", tracing_test::traced_test)]
+    #[test]
+    #[cfg(feature = "rayon")]
+    fn par_build_collecting() {
+        let (path, files) = create_files_for_vault().unwrap();
+        std::fs::write(path.path().join("broken.md"), "---").unwrap();
+        std::fs::write(path.path().join("dir_broken.md"), "---\ntopic: life").unwrap();
+
+        let options = VaultOptions::new(&path);
+        let (vault, errors) = VaultBuilder::new(&options).par_build_collecting::<NoteInMemory>();
+
+        let (sequential, _) = VaultBuilder::new(&options).build_collecting::<NoteInMemory>();
+        assert_eq!(vault, sequential);
+        assert_eq!(vault.count_notes(), files.len());
+        let mut paths: Vec<_> = errors.into_iter().map(|error| error.path).collect();
+        paths.sort();
+        assert_eq!(
+            paths,
+            [
+                path.path().join("broken.md"),
+                path.path().join("dir_broken.md")
+            ]
+        );
+    }
+
     #[cfg_attr(feature = "tracing", tracing_test::traced_test)]
     #[test]
     fn open_with_error_but_ignored() {
@@ -464,6 +1283,97 @@ mod tests {
         ));
     }
 
+    #[cfg_attr(feature = "tracing", tracing_test::traced_test)]
+    #[test]
+    fn on_error() {
+        let (path, vault_notes) = create_files_for_vault().unwrap();
+        let bad_path = path.path().join("not_file.md");
+        let mut file = File::create(&bad_path).unwrap();
+        file.write_all(b"---").unwrap();
+
+        let options = VaultOptions::new(&path);
+
+        let mut errors = Vec::new();
+        let vault: VaultInMemory = VaultBuilder::new(&options)
+            .on_error(|path, error| errors.push((path, error)))
+            .build_vault(&options);
+
+        assert_eq!(vault.count_notes(), vault_notes.len());
+        assert_eq!(errors.len(), 1);
+        assert_eq!(errors[0].0, bad_path);
+        assert!(matches!(
+            errors[0].1,
+            note_in_memory::Error::InvalidFormat(_)
+        ));
+    }
+
+    #[cfg_attr(feature = "tracing", tracing_test::traced_test)]
+    #[test]
+    #[cfg(feature = "rayon")]
+    fn par_on_error() {
+        use std::sync::Mutex;
+
+        let (path, vault_notes) = create_files_for_vault().unwrap();
+        let bad_path = path.path().join("not_file.md");
+        let mut file = File::create(&bad_path).unwrap();
+        file.write_all(b"---").unwrap();
+
+        let options = VaultOptions::new(&path);
+
+        let errors = Mutex::new(Vec::new());
+        let vault: VaultInMemory = VaultBuilder::new(&options)
+            .par_on_error(|path, _| errors.lock().unwrap().push(path))
+            .build_vault(&options);
+
+        assert_eq!(vault.count_notes(), vault_notes.len());
+        assert_eq!(errors.into_inner().unwrap(), [bad_path]);
+    }
+
+    #[cfg_attr(feature = "tracing", tracing_test::traced_test)]
+    #[test]
+    fn into_iter_cancellable() {
+        let (path, files) = create_files_for_vault().unwrap();
+
+        let options = VaultOptions::new(&path);
+        let cancel_flag = Arc::new(AtomicBool::new(false));
+
+        let mut count_notes = 0;
+        let mut cancelled = false;
+        for note in
+            VaultBuilder::new(&options).into_iter_cancellable::<NoteInMemory>(cancel_flag.clone())
+        {
+            match note {
+                Ok(_) => count_notes += 1,
+                Err(OpenError::Cancelled) => cancelled = true,
+                Err(OpenError::Note(error)) => panic!("{error}"),
+            }
+
+            if count_notes == 2 {
+                cancel_flag.store(true, Ordering::Relaxed);
+            }
+        }
+
+        assert!(cancelled);
+        assert_eq!(count_notes, 2);
+        assert!(count_notes < files.len());
+    }
+
+    #[cfg_attr(feature = "tracing", tracing_test::traced_test)]
+    #[test]
+    fn into_iter_cancellable_without_cancel() {
+        let (path, files) = create_files_for_vault().unwrap();
+
+        let options = VaultOptions::new(&path);
+        let cancel_flag = Arc::new(AtomicBool::new(false));
+
+        let notes: Vec<NoteInMemory> = VaultBuilder::new(&options)
+            .into_iter_cancellable(cancel_flag)
+            .collect::<Result<_, _>>()
+            .unwrap();
+
+        assert_eq!(notes.len(), files.len());
+    }
+
     #[cfg_attr(feature = "tracing", tracing_test::traced_test)]
     #[test]
     fn include_hidden() {
@@ -490,6 +1400,48 @@ mod tests {
         assert_eq!(vault_without_hidden.count_notes(), files.len());
     }
 
+    #[cfg_attr(feature = "tracing", tracing_test::traced_test)]
+    #[test]
+    fn open_with() {
+        let (path, files) = create_files_for_vault().unwrap();
+
+        File::create_new(path.path().join(".hidden.md"))
+            .unwrap()
+            .write_all(b"hidden information")
+            .unwrap();
+        File::create_new(path.path().join("broken.md"))
+            .unwrap()
+            .write_all(b"---\nnot closed")
+            .unwrap();
+
+        let options = VaultOptions::new(&path);
+
+        let (vault, errors) =
+            <VaultInMemory>::open_with(&options, |builder| builder.include_hidden(true)).unwrap();
+        assert_eq!(vault.count_notes(), files.len() + 1);
+        assert_eq!(vault.path(), path.path());
+        assert_eq!(errors.len(), 1);
+        assert_eq!(errors[0].path, path.path().join("broken.md"));
+
+        let (vault, _) = <VaultInMemory>::open_with(&options, |builder| builder).unwrap();
+        assert_eq!(vault.count_notes(), files.len());
+
+        let (vault, _) = <VaultInMemory>::open_with(&options, |builder| {
+            builder.include_hidden(true).max_depth(1)
+        })
+        .unwrap();
+        assert_eq!(vault.count_notes(), files.len());
+    }
+
+    #[cfg_attr(feature = "tracing", tracing_test::traced_test)]
+    #[test]
+    fn open_with_not_dir() {
+        let (path, _files) = create_files_for_vault().unwrap();
+        let options = VaultOptions::new(path.path().join("main.md"));
+
+        assert!(<VaultInMemory>::open_with(&options, |builder| builder).is_err());
+    }
+
     #[cfg_attr(feature = "tracing", tracing_test::traced_test)]
     #[test]
     fn max_depth() {
@@ -534,4 +1486,182 @@ mod tests {
 
         assert_eq!(vault.count_notes(), 1);
     }
+
+    #[cfg_attr(feature = "tracing", tracing_test::traced_test)]
+    #[test]
+    #[cfg(feature = "rayon")]
+    fn par_filter_entry() {
+        use std::{cell::Cell, rc::Rc};
+
+        let (path, _) = create_files_for_vault().unwrap();
+        let options = VaultOptions::new(&path);
+
+        // Not `Send` filter: paths are collected
+        let visited = Rc::new(Cell::new(0));
+        let vault: VaultInMemory = VaultBuilder::new(&options)
+            .filter_entry({
+                let visited = visited.clone();
+                move |entry| {
+                    visited.set(visited.get() + 1);
+                    !entry.file_name().eq_ignore_ascii_case("main.md")
+                }
+            })
+            .par_on_error(|_, _| {})
+            .build_vault(&options);
+        assert_eq!(vault.count_notes(), 1);
+        assert!(visited.get() > 0);
+
+        // `Send` filter: paths are streamed
+        let vault: VaultInMemory = VaultBuilder::new(&options)
+            .par_filter_entry(|entry| !entry.file_name().eq_ignore_ascii_case("main.md"))
+            .par_on_error(|_, _| {})
+            .build_vault(&options);
+        assert_eq!(vault.count_notes(), 1);
+    }
+
+    #[cfg_attr(feature = "tracing", tracing_test::traced_test)]
+    #[test]
+    #[cfg(feature = "rayon")]
+    fn par_open_keeps_order() {
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        for path in [
+            "b.md", "a/z.md", "a/b/c.md", "dup.md", "c/dup.md", "A.md", "a.b.md",
+        ] {
+            let path = temp_dir.path().join(path);
+            std::fs::create_dir_all(path.parent().unwrap()).unwrap();
+            std::fs::write(path, "text").unwrap();
+        }
+
+        let sequential: VaultInMemory = impl_open(&temp_dir);
+        let paths: Vec<_> = sequential
+            .notes()
+            .iter()
+            .map(|note| note.path().unwrap().into_owned())
+            .collect();
+        assert!(paths.is_sorted());
+
+        for _ in 0..10 {
+            let parallel: VaultInMemory = impl_par_open(&temp_dir);
+            assert_eq!(parallel, sequential);
+        }
+    }
+
+    #[cfg_attr(feature = "tracing", tracing_test::traced_test)]
+    #[test]
+    fn on_progress() {
+        let (path, files) = create_files_for_vault().unwrap();
+        let counts = Arc::new(std::sync::Mutex::new(Vec::new()));
+
+        let options = VaultOptions::new(&path);
+        let mut iter = VaultBuilder::new(&options)
+            .on_progress({
+                let counts = counts.clone();
+                move |count| counts.lock().unwrap().push(count)
+            })
+            .into_iter::<NoteInMemory>();
+
+        // Iterator is lazy
+        assert!(counts.lock().unwrap().is_empty());
+        iter.next().unwrap().unwrap();
+        assert_eq!(*counts.lock().unwrap(), [1]);
+
+        let rest: Vec<_> = iter.map(|file| file.unwrap()).collect();
+        assert_eq!(rest.len() + 1, files.len());
+
+        let expected: Vec<_> = (1..=files.len()).collect();
+        assert_eq!(*counts.lock().unwrap(), expected);
+    }
+
+    #[cfg_attr(feature = "tracing", tracing_test::traced_test)]
+    #[test]
+    fn on_progress_with_errors() {
+        let (path, files) = create_files_for_vault().unwrap();
+        File::create(path.path().join("broken.md"))
+            .unwrap()
+            .write_all(b"---\ninvalid: [\n---\n")
+            .unwrap();
+
+        let last = Arc::new(std::sync::atomic::AtomicUsize::new(0));
+
+        let options = VaultOptions::new(&path);
+        let vault: VaultInMemory = VaultBuilder::new(&options)
+            .on_progress({
+                let last = last.clone();
+                move |count| last.store(count, Ordering::Relaxed)
+            })
+            .on_error(|_, _| {})
+            .build_vault(&options);
+
+        assert_eq!(vault.count_notes(), files.len());
+        assert_eq!(last.load(Ordering::Relaxed), files.len() + 1);
+    }
+
+    #[cfg_attr(feature = "tracing", tracing_test::traced_test)]
+    #[test]
+    #[cfg(feature = "rayon")]
+    fn par_on_progress() {
+        use rayon::prelude::*;
+
+        let (path, files) = create_files_for_vault().unwrap();
+        let counts = Arc::new(std::sync::Mutex::new(Vec::new()));
+
+        let options = VaultOptions::new(&path);
+        let vault: VaultInMemory = VaultBuilder::new(&options)
+            .on_progress({
+                let counts = counts.clone();
+                move |count| counts.lock().unwrap().push(count)
+            })
+            .into_par_iter()
+            .map(|file| file.unwrap())
+            .build_vault(&options);
+
+        let expected: Vec<_> = (1..=files.len()).collect();
+        assert_eq!(vault.count_notes(), files.len());
+        assert_eq!(*counts.lock().unwrap(), expected);
+    }
+
+    #[cfg_attr(feature = "tracing", tracing_test::traced_test)]
+    #[test]
+    fn extensions() {
+        let (path, files) = create_files_for_vault().unwrap();
+        File::create(path.path().join("other.MARKDOWN")).unwrap();
+        File::create(path.path().join("board.canvas")).unwrap();
+
+        let open = |builder: VaultBuilder| -> VaultInMemory {
+            builder
+                .into_iter()
+                .map(|file| file.unwrap())
+                .build_vault(&VaultOptions::new(&path))
+        };
+
+        let options = VaultOptions::new(&path);
+        assert_eq!(open(VaultBuilder::new(&options)).count_notes(), files.len());
+        assert_eq!(
+            open(VaultBuilder::new(&options).extensions(&["md", "markdown"])).count_notes(),
+            files.len() + 1
+        );
+        assert_eq!(
+            open(VaultBuilder::new(&options).extensions(&["canvas"])).count_notes(),
+            1
+        );
+        assert_eq!(
+            open(VaultBuilder::new(&options).extensions(&[])).count_notes(),
+            0
+        );
+    }
+
+    #[cfg_attr(feature = "tracing", tracing_test::traced_test)]
+    #[test]
+    fn eq_with_extensions() {
+        let options = VaultOptions::new("/path/to/vault");
+
+        assert_eq!(
+            VaultBuilder::new(&options).extensions(&["md"]),
+            VaultBuilder::new(&options).extensions(&["md"])
+        );
+        assert_ne!(
+            VaultBuilder::new(&options),
+            VaultBuilder::new(&options).extensions(&["canvas"])
+        );
+    }
 }
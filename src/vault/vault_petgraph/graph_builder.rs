@@ -1,25 +1,37 @@
-use super::index::Index;
-use crate::note::parser::parse_links;
+use super::{EdgeKind, GraphError, GraphOptions};
+use crate::note::parser::{normalize_link, parse_links_with_embed_flag};
+use crate::vault::index::{self, Index};
 use crate::{note::Note, vault::Vault};
-use petgraph::{EdgeType, Graph};
-use std::path::Path;
+use petgraph::{EdgeType, Graph, graph::NodeIndex};
+use std::{collections::HashMap, path::Path};
 
 pub struct GraphBuilder<'a, F>
 where
     F: Note,
 {
     vault: &'a Vault<F>,
+    options: GraphOptions,
 }
 
 impl<'a, F> GraphBuilder<'a, F>
 where
     F: Note,
 {
-    pub(crate) const fn new(vault: &'a Vault<F>) -> Self {
-        Self { vault }
+    pub(crate) fn new(vault: &'a Vault<F>) -> Self {
+        Self::with_options(vault, GraphOptions::default())
     }
 
-    pub(crate) fn build<Ty>(self) -> Result<Graph<&'a F, (), Ty>, F::Error>
+    pub(crate) const fn with_options(vault: &'a Vault<F>, options: GraphOptions) -> Self {
+        Self { vault, options }
+    }
+
+    /// Build graph
+    ///
+    /// `edge` maps kind of link to weight of edge. If it returns [`None`], edge is skipped
+    pub(crate) fn build<Ty, E>(
+        self,
+        edge: impl Fn(EdgeKind) -> Option<E>,
+    ) -> Result<Graph<&'a F, E, Ty>, F::Error>
     where
         Ty: EdgeType,
     {
@@ -31,7 +43,87 @@ where
         );
 
         let (index, mut graph) = self.create_index_with_graph();
-        self.create_edges(&index, &mut graph)?;
+        self.create_edges(&index, &mut graph, edge)?;
+
+        #[cfg(feature = "tracing")]
+        tracing::debug!("Graph construction complete. Edges: {}", graph.edge_count());
+
+        Ok(graph)
+    }
+
+    /// Build graph with one edge for all links from one note to another
+    ///
+    /// Weight of edge is count of links (embeds included)
+    pub(crate) fn build_weighted<Ty>(self) -> Result<Graph<&'a F, usize, Ty>, F::Error>
+    where
+        Ty: EdgeType,
+    {
+        #[cfg(feature = "tracing")]
+        tracing::debug!(
+            "Building weighted graph for vault: {} ({} notes)",
+            self.vault.path.display(),
+            self.vault.count_notes()
+        );
+
+        let (index, mut graph) = self.create_index_with_graph();
+
+        let mut counts: Vec<_> = self.count_edges(&index)?.into_iter().collect();
+        counts.sort_unstable();
+
+        for ((node_from, node_to), count) in counts {
+            graph.add_edge(node_from, node_to, count);
+        }
+
+        #[cfg(feature = "tracing")]
+        tracing::debug!("Graph construction complete. Edges: {}", graph.edge_count());
+
+        Ok(graph)
+    }
+
+    /// Build graph like [`GraphBuilder::build`] with `()` edges, but fail on ambiguous link
+    ///
+    /// Link is ambiguous if it is name of note (without `/`) and several notes have this name
+    pub(crate) fn try_build<Ty>(self) -> Result<Graph<&'a F, (), Ty>, GraphError<F::Error>>
+    where
+        Ty: EdgeType,
+    {
+        #[cfg(feature = "tracing")]
+        tracing::debug!(
+            "Building strict graph for vault: {} ({} notes)",
+            self.vault.path.display(),
+            self.vault.count_notes()
+        );
+
+        let (index, mut graph) = self.create_index_with_graph();
+        let ambiguous = self.ambiguous_names();
+
+        for file in self.notes_with_links() {
+            let path = Self::relative_path(file, &self.vault.path);
+
+            if let Some(node_from) = index.full(&path) {
+                let content = file.content().map_err(GraphError::Note)?;
+
+                for (link, _) in parse_links_with_embed_flag(&content) {
+                    let key = normalize_link(link.target);
+
+                    if !key.contains('/')
+                        && let Some(candidates) = ambiguous.get(&key)
+                    {
+                        #[cfg(feature = "tracing")]
+                        tracing::debug!("Link `{}` is ambiguous", link.target);
+
+                        return Err(GraphError::AmbiguousLink {
+                            link: link.target.to_string(),
+                            candidates: candidates.clone(),
+                        });
+                    }
+
+                    if let Some(node_to) = index.get(link.target) {
+                        graph.add_edge(*node_from, *node_to, ());
+                    }
+                }
+            }
+        }
 
         #[cfg(feature = "tracing")]
         tracing::debug!("Graph construction complete. Edges: {}", graph.edge_count());
@@ -40,10 +132,14 @@ where
     }
 
     #[cfg(feature = "rayon")]
-    pub(crate) fn par_build<Ty>(self) -> Result<Graph<&'a F, (), Ty>, F::Error>
+    pub(crate) fn par_build<Ty, E>(
+        self,
+        edge: impl Fn(EdgeKind) -> Option<E> + Sync,
+    ) -> Result<Graph<&'a F, E, Ty>, F::Error>
     where
         F: Send + Sync,
         F::Error: Send,
+        E: Send,
         Ty: EdgeType + Send,
     {
         #[cfg(feature = "tracing")]
@@ -54,7 +150,7 @@ where
         );
 
         let (index, mut graph) = self.create_index_with_graph();
-        self.par_create_edges(&index, &mut graph)?;
+        self.par_create_edges(&index, &mut graph, edge)?;
 
         #[cfg(feature = "tracing")]
         tracing::debug!("Graph construction complete. Edges: {}", graph.edge_count());
@@ -76,16 +172,18 @@ where
     )]
     #[inline]
     fn relative_path(file: &F, strip_prefix: &Path) -> String {
-        file.path()
-            .unwrap()
-            .strip_prefix(strip_prefix)
-            .unwrap()
-            .with_extension("")
-            .to_string_lossy()
-            .to_string()
+        index::relative_path(file, strip_prefix).unwrap()
+    }
+
+    /// Notes of vault without canvases: their content is JSON, not links
+    fn notes_with_links(&self) -> impl Iterator<Item = &'a F> {
+        self.vault
+            .notes()
+            .iter()
+            .filter(|note| !index::is_canvas(*note))
     }
 
-    fn create_index_with_graph<Ty>(&self) -> (Index, Graph<&'a F, (), Ty>)
+    fn create_index_with_graph<Ty, E>(&self) -> (Index<NodeIndex>, Graph<&'a F, E, Ty>)
     where
         Ty: EdgeType,
     {
@@ -93,18 +191,28 @@ where
         tracing::debug!("Creating index...");
 
         let mut graph = Graph::default();
-        let mut index = Index::default();
+        let mut index = Index::new(
+            self.options.case_insensitive,
+            self.options.normalize_unicode,
+        );
 
         #[allow(
             clippy::unwrap_used,
             reason = "When creating a Vault, the path will be mandatory"
         )]
+        // Nodes must be inserted in `notes()` order: `Vault::node_note` relies on it
         for note in self.vault.notes() {
+            let node = graph.add_node(note);
+
+            // Canvas is node without links, its edges are added by `get_digraph_with_canvases`
+            if index::is_canvas(note) {
+                continue;
+            }
+
             let full = Self::relative_path(note, &self.vault.path);
             let short = note.note_name().unwrap();
 
-            let node = graph.add_node(note);
-            index.insert(full, short, node);
+            index.insert(&full, &short, node);
         }
 
         #[cfg(feature = "tracing")]
@@ -117,17 +225,18 @@ where
     ///
     /// Uses parallel processing when `rayon` feature is enabled
     #[cfg(feature = "rayon")]
-    fn par_create_edges<Ty>(
+    fn par_create_edges<Ty, E>(
         &self,
-        index: &Index,
-        graph: &mut Graph<&'a F, (), Ty>,
+        index: &Index<NodeIndex>,
+        graph: &mut Graph<&'a F, E, Ty>,
+        edge: impl Fn(EdgeKind) -> Option<E> + Sync,
     ) -> Result<(), F::Error>
     where
         F: Send + Sync,
         F::Error: Send,
+        E: Send,
         Ty: EdgeType + Send,
     {
-        use petgraph::graph::NodeIndex;
         use rayon::prelude::*;
 
         const CHUNK_SIZE: usize = 10;
@@ -136,14 +245,15 @@ where
         tracing::debug!("Using parallel edge builder (rayon enabled)");
 
         #[allow(clippy::items_after_statements)]
-        enum Data<'a, E: Send> {
-            Successful(Vec<(&'a NodeIndex, NodeIndex)>),
+        enum Data<'a, W: Send, E: Send> {
+            Successful(Vec<(&'a NodeIndex, NodeIndex, W)>),
             Error(E),
         }
 
         let (tx, rx) = crossbeam_channel::unbounded();
         let notes = &self.vault.notes();
         let strip_prefix = &self.vault.path;
+        let edge = &edge;
         let mut result = Ok(());
 
         rayon::scope(|s| {
@@ -155,13 +265,21 @@ where
                         let mut result = Vec::with_capacity(10 * CHUNK_SIZE);
 
                         for note in notes {
+                            if index::is_canvas(note) {
+                                continue;
+                            }
+
                             let path = Self::relative_path(note, strip_prefix);
 
                             if let Some(node_to) = index.full(&path) {
                                 match note.content() {
-                                    Ok(content) => parse_links(&content)
-                                        .filter_map(|link| index.get(link))
-                                        .map(|node_from| (node_to, *node_from))
+                                    Ok(content) => parse_links_with_embed_flag(&content)
+                                        .filter_map(|(link, is_embed)| {
+                                            let weight = edge(EdgeKind::new(is_embed))?;
+                                            let node_from = index.get(link.target)?;
+
+                                            Some((node_to, *node_from, weight))
+                                        })
                                         .for_each(|x| result.push(x)),
                                     Err(error) => tx.send(Data::Error(error)).expect("Send error"),
                                 }
@@ -177,8 +295,8 @@ where
                 while let Ok(recv) = rx.recv() {
                     match recv {
                         Data::Successful(notes) => {
-                            for (note_to, note_from) in notes {
-                                graph.add_edge(*note_to, note_from, ());
+                            for (note_to, note_from, weight) in notes {
+                                graph.add_edge(*note_to, note_from, weight);
                             }
                         }
                         Data::Error(error) => result = Err(error),
@@ -193,10 +311,11 @@ where
     /// Builds edges between nodes in the graph
     ///
     /// Uses parallel processing when `rayon` feature is enabled
-    fn create_edges<Ty>(
+    fn create_edges<Ty, E>(
         &self,
-        index: &Index,
-        graph: &mut Graph<&'a F, (), Ty>,
+        index: &Index<NodeIndex>,
+        graph: &mut Graph<&'a F, E, Ty>,
+        edge: impl Fn(EdgeKind) -> Option<E>,
     ) -> Result<(), F::Error>
     where
         Ty: EdgeType,
@@ -204,21 +323,72 @@ where
         #[cfg(feature = "tracing")]
         tracing::debug!("Using sequential edge builder");
 
-        for file in self.vault.notes() {
+        for file in self.notes_with_links() {
             let path = Self::relative_path(file, &self.vault.path);
 
             if let Some(node_to) = index.full(&path) {
                 let content = file.content()?;
 
-                parse_links(&content)
-                    .filter_map(|link| index.get(link))
-                    .map(|node_from| (node_to, *node_from))
-                    .for_each(|(node_to, node_from)| {
-                        graph.add_edge(*node_to, node_from, ());
+                parse_links_with_embed_flag(&content)
+                    .filter_map(|(link, is_embed)| {
+                        let weight = edge(EdgeKind::new(is_embed))?;
+                        let node_from = index.get(link.target)?;
+
+                        Some((node_to, *node_from, weight))
+                    })
+                    .for_each(|(node_to, node_from, weight)| {
+                        graph.add_edge(*node_to, node_from, weight);
                     });
             }
         }
 
         Ok(())
     }
+
+    /// Count links between every pair of nodes
+    fn count_edges(
+        &self,
+        index: &Index<NodeIndex>,
+    ) -> Result<HashMap<(NodeIndex, NodeIndex), usize>, F::Error> {
+        let mut counts = HashMap::new();
+
+        for file in self.notes_with_links() {
+            let path = Self::relative_path(file, &self.vault.path);
+
+            if let Some(node_from) = index.full(&path) {
+                let content = file.content()?;
+
+                for (link, _) in parse_links_with_embed_flag(&content) {
+                    if let Some(node_to) = index.get(link.target) {
+                        *counts.entry((*node_from, *node_to)).or_insert(0) += 1;
+                    }
+                }
+            }
+        }
+
+        Ok(counts)
+    }
+
+    /// Names of notes shared by several notes: normalized name -> sorted full paths
+    #[allow(
+        clippy::unwrap_used,
+        reason = "When creating a Vault, the path will be mandatory"
+    )]
+    fn ambiguous_names(&self) -> HashMap<String, Vec<String>> {
+        let mut names: HashMap<String, Vec<String>> = HashMap::new();
+
+        for note in self.notes_with_links() {
+            let full = Self::relative_path(note, &self.vault.path);
+            let short = normalize_link(&note.note_name().unwrap());
+
+            names.entry(short).or_default().push(full);
+        }
+
+        names.retain(|_, candidates| candidates.len() > 1);
+        for candidates in names.values_mut() {
+            candidates.sort_unstable();
+        }
+
+        names
+    }
 }
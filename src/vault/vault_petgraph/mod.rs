@@ -24,26 +24,98 @@
 //! [dependencies]
 //! obsidian-parser = { version = "0.", features = ["petgraph"] }
 //! ```
+//!
+//! # Embeds
+//! Embeds (`![[image.png]]`) are edges too. Use [`Vault::get_digraph_without_embeds`] to skip them
+//! or [`Vault::get_digraph_with_kinds`] to get [`EdgeKind`] for every edge
+//!
+//! # Node indices
+//! Nodes are inserted in [`Vault::notes`] order, so the note of node `idx` is always
+//! `vault.notes()[idx.index()]`. See [`Vault::node_note`]
 
 mod graph_builder;
-mod index;
+pub mod vault_graph;
 
-use super::Vault;
+use super::{
+    Vault,
+    index::{Index, relative_path},
+};
 use crate::note::Note;
 use graph_builder::GraphBuilder;
 use petgraph::{
     EdgeType, Graph,
-    graph::{DiGraph, UnGraph},
+    graph::{DiGraph, NodeIndex, UnGraph},
 };
 use std::marker::{Send, Sync};
+use thiserror::Error;
+
+/// Kind of edge in graph
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum EdgeKind {
+    /// Link like `[[Note]]`
+    Link,
+
+    /// Embed like `![[Note]]` or `![[image.png]]`
+    Embed,
+}
+
+/// Options of link resolution for [`Vault::get_digraph_with_options`]
+///
+/// Default is strict: links must match names of notes exactly (after trimming and `.md`)
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct GraphOptions {
+    /// Resolve links ignoring case, like Obsidian does: `[[note]]` links to `Note.md`
+    pub case_insensitive: bool,
+
+    /// Normalize links and names of notes to Unicode NFC, so `é` as one char and
+    /// `e` + combining accent are equal
+    ///
+    /// Requires `unicode` feature, without it the option is ignored
+    pub normalize_unicode: bool,
+}
+
+/// Errors for [`Vault::try_get_digraph`], [`Vault::try_get_ungraph`] and [`Vault::shortest_path`]
+#[derive(Debug, Error)]
+pub enum GraphError<E>
+where
+    E: std::error::Error,
+{
+    /// Error from note
+    #[error(transparent)]
+    Note(E),
+
+    /// Link by name (like `[[note]]`) matches several notes
+    #[error("Link `{link}` is ambiguous, candidates: {candidates:?}")]
+    AmbiguousLink {
+        /// Target of link
+        link: String,
+
+        /// Full paths of matched notes (like `dir/note`), sorted. Use one of them in link
+        candidates: Vec<String>,
+    },
+
+    /// Note with this name or path is not found in vault
+    #[error("Note `{0}` is not found")]
+    NoteNotFound(String),
+}
+
+impl EdgeKind {
+    #[inline]
+    pub(crate) const fn new(is_embed: bool) -> Self {
+        if is_embed { Self::Embed } else { Self::Link }
+    }
+}
 
 impl<F> Vault<F>
 where
     F: Note,
 {
     #[cfg_attr(docsrs, doc(cfg(feature = "petgraph")))]
-    #[cfg_attr(feature = "tracing", tracing::instrument(skip(self), fields(path = %self.path.display(), count_notes = %self.notes.len())))]
-    fn get_graph<Ty>(&self) -> Result<Graph<&F, (), Ty>, F::Error>
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip_all, fields(path = %self.path.display(), count_notes = %self.notes.len())))]
+    fn get_graph<Ty, E>(
+        &self,
+        edge: impl Fn(EdgeKind) -> Option<E>,
+    ) -> Result<Graph<&F, E, Ty>, F::Error>
     where
         Ty: EdgeType,
     {
@@ -51,23 +123,67 @@ where
         tracing::debug!("Building graph");
 
         let graph_builder = GraphBuilder::new(self);
-        graph_builder.build()
+        graph_builder.build(edge)
     }
 
     #[cfg_attr(docsrs, doc(cfg(feature = "petgraph")))]
     #[cfg_attr(docsrs, doc(cfg(feature = "rayon")))]
     #[cfg(feature = "rayon")]
-    fn par_get_graph<Ty>(&self) -> Result<Graph<&F, (), Ty>, F::Error>
+    fn par_get_graph<Ty, E>(
+        &self,
+        edge: impl Fn(EdgeKind) -> Option<E> + Sync,
+    ) -> Result<Graph<&F, E, Ty>, F::Error>
     where
         F: Send + Sync,
         F::Error: Send,
+        E: Send,
         Ty: EdgeType + Send,
     {
         #[cfg(feature = "tracing")]
         tracing::debug!("Building graph with parallel");
 
         let graph_builder = GraphBuilder::new(self);
-        graph_builder.par_build()
+        graph_builder.par_build(edge)
+    }
+
+    /// Returns note for node in graph built from this vault
+    ///
+    /// Node indices are aligned with [`Vault::notes`], so `idx.index()` is also
+    /// the position of the note in [`Vault::notes`]
+    ///
+    /// # Panics
+    /// If `idx` is not a node of `graph`
+    ///
+    /// # Example
+    /// ```no_run
+    /// use obsidian_parser::prelude::*;
+    ///
+    /// let options = VaultOptions::new("/path/to/vault");
+    /// let vault: VaultInMemory = VaultBuilder::new(&options)
+    ///     .into_iter()
+    ///     .filter_map(Result::ok)
+    ///     .build_vault(&options);
+    ///
+    /// let graph = vault.get_digraph().unwrap();
+    /// for idx in graph.node_indices() {
+    ///     let note = vault.node_note(&graph, idx);
+    ///     assert!(std::ptr::eq(note, &vault.notes()[idx.index()]));
+    /// }
+    /// ```
+    #[cfg_attr(docsrs, doc(cfg(feature = "petgraph")))]
+    #[must_use]
+    pub fn node_note<'a, E, Ty>(&'a self, graph: &Graph<&'a F, E, Ty>, idx: NodeIndex) -> &'a F
+    where
+        Ty: EdgeType,
+    {
+        let note = graph[idx];
+        debug_assert!(
+            self.notes
+                .get(idx.index())
+                .is_some_and(|expected| std::ptr::eq(expected, note))
+        );
+
+        note
     }
 
     /// Builds directed graph representing note relationships
@@ -78,6 +194,8 @@ where
     /// - For vaults with 1000+ notes, enable `rayon` feature
     /// - Uses [`NoteOnDisk`](crate::prelude::NoteOnDisk) for minimal memory footprint
     ///
+    /// Node indices are aligned with [`Vault::notes`] (see [`Vault::node_note`])
+    ///
     /// # Other
     /// See [`get_ungraph`](Vault::get_ungraph)
     #[cfg_attr(docsrs, doc(cfg(feature = "petgraph")))]
@@ -86,7 +204,180 @@ where
         #[cfg(feature = "tracing")]
         tracing::debug!("Building directed graph");
 
-        self.get_graph()
+        self.get_graph(|_| Some(()))
+    }
+
+    /// Builds directed graph like [`get_digraph`](Vault::get_digraph), but fails on ambiguous links
+    ///
+    /// [`Vault::get_digraph`] resolves `[[note]]` to the first note named `note`, even if several
+    /// notes share this name. Here such link is an error. Links with full path (`[[dir/note]]`)
+    /// are never ambiguous
+    ///
+    /// # Example
+    /// ```no_run
+    /// use obsidian_parser::prelude::*;
+    /// use obsidian_parser::vault::vault_petgraph::GraphError;
+    ///
+    /// let options = VaultOptions::new("/path/to/vault");
+    /// let vault: VaultOnDisk = VaultBuilder::new(&options)
+    ///     .into_iter()
+    ///     .filter_map(Result::ok)
+    ///     .build_vault(&options);
+    ///
+    /// match vault.try_get_digraph() {
+    ///     Ok(graph) => println!("Edges: {}", graph.edge_count()),
+    ///     Err(GraphError::AmbiguousLink { link, candidates }) => {
+    ///         println!("`{link}` may be any of {candidates:?}");
+    ///     }
+    ///     Err(error) => eprintln!("{error}"),
+    /// }
+    /// ```
+    ///
+    /// # Errors
+    /// - [`GraphError::AmbiguousLink`] for the first ambiguous link
+    /// - [`GraphError::Note`] if content of note can't be read
+    #[cfg_attr(docsrs, doc(cfg(feature = "petgraph")))]
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip(self), fields(path = %self.path.display(), count_notes = %self.notes.len())))]
+    pub fn try_get_digraph(&self) -> Result<DiGraph<&F, ()>, GraphError<F::Error>> {
+        #[cfg(feature = "tracing")]
+        tracing::debug!("Building strict directed graph");
+
+        GraphBuilder::new(self).try_build()
+    }
+
+    /// Builds undirected graph like [`get_ungraph`](Vault::get_ungraph), but fails on ambiguous links
+    ///
+    /// See [`Vault::try_get_digraph`]
+    ///
+    /// # Errors
+    /// - [`GraphError::AmbiguousLink`] for the first ambiguous link
+    /// - [`GraphError::Note`] if content of note can't be read
+    #[cfg_attr(docsrs, doc(cfg(feature = "petgraph")))]
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip(self), fields(path = %self.path.display(), count_notes = %self.notes.len())))]
+    pub fn try_get_ungraph(&self) -> Result<UnGraph<&F, ()>, GraphError<F::Error>> {
+        #[cfg(feature = "tracing")]
+        tracing::debug!("Building strict undirected graph");
+
+        GraphBuilder::new(self).try_build()
+    }
+
+    /// Builds directed graph like [`get_digraph`](Vault::get_digraph), but without embeds
+    ///
+    /// Embeds (`![[image.png]]`, `![[Note]]`) don't create edges, only links (`[[Note]]`) do
+    ///
+    /// # Example
+    /// ```no_run
+    /// use obsidian_parser::prelude::*;
+    ///
+    /// let options = VaultOptions::new("/path/to/vault");
+    /// let vault: VaultOnDisk = VaultBuilder::new(&options)
+    ///     .into_iter()
+    ///     .filter_map(Result::ok)
+    ///     .build_vault(&options);
+    ///
+    /// let graph = vault.get_digraph_without_embeds().unwrap();
+    /// println!("Links: {}", graph.edge_count());
+    /// ```
+    #[cfg_attr(docsrs, doc(cfg(feature = "petgraph")))]
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip(self), fields(path = %self.path.display(), count_notes = %self.notes.len())))]
+    pub fn get_digraph_without_embeds(&self) -> Result<DiGraph<&F, ()>, F::Error> {
+        #[cfg(feature = "tracing")]
+        tracing::debug!("Building directed graph without embeds");
+
+        self.get_graph(|kind| (kind == EdgeKind::Link).then_some(()))
+    }
+
+    /// Builds directed graph like [`get_digraph`](Vault::get_digraph), where weight of edge is [`EdgeKind`]
+    ///
+    /// # Example
+    /// ```no_run
+    /// use obsidian_parser::prelude::*;
+    /// use obsidian_parser::vault::vault_petgraph::EdgeKind;
+    ///
+    /// let options = VaultOptions::new("/path/to/vault");
+    /// let vault: VaultOnDisk = VaultBuilder::new(&options)
+    ///     .into_iter()
+    ///     .filter_map(Result::ok)
+    ///     .build_vault(&options);
+    ///
+    /// let graph = vault.get_digraph_with_kinds().unwrap();
+    /// let embeds = graph
+    ///     .edge_weights()
+    ///     .filter(|kind| **kind == EdgeKind::Embed)
+    ///     .count();
+    ///
+    /// println!("Embeds: {embeds}");
+    /// ```
+    #[cfg_attr(docsrs, doc(cfg(feature = "petgraph")))]
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip(self), fields(path = %self.path.display(), count_notes = %self.notes.len())))]
+    pub fn get_digraph_with_kinds(&self) -> Result<DiGraph<&F, EdgeKind>, F::Error> {
+        #[cfg(feature = "tracing")]
+        tracing::debug!("Building directed graph with kinds of edges");
+
+        self.get_graph(Some)
+    }
+
+    /// Builds directed graph with one edge for every pair of linked notes
+    ///
+    /// [`Vault::get_digraph`] adds edge for every link, so three links from `A` to `B`
+    /// are three edges. Here they are one edge `A → B` with weight `3`.
+    /// Embeds are counted as links
+    ///
+    /// # Example
+    /// ```no_run
+    /// use obsidian_parser::prelude::*;
+    ///
+    /// let options = VaultOptions::new("/path/to/vault");
+    /// let vault: VaultOnDisk = VaultBuilder::new(&options)
+    ///     .into_iter()
+    ///     .filter_map(Result::ok)
+    ///     .build_vault(&options);
+    ///
+    /// let graph = vault.get_digraph_weighted().unwrap();
+    /// for edge in graph.raw_edges() {
+    ///     println!("{:?} -> {:?}: {} links", edge.source(), edge.target(), edge.weight);
+    /// }
+    /// ```
+    #[cfg_attr(docsrs, doc(cfg(feature = "petgraph")))]
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip(self), fields(path = %self.path.display(), count_notes = %self.notes.len())))]
+    pub fn get_digraph_weighted(&self) -> Result<DiGraph<&F, usize>, F::Error> {
+        #[cfg(feature = "tracing")]
+        tracing::debug!("Building weighted directed graph");
+
+        GraphBuilder::new(self).build_weighted()
+    }
+
+    /// Builds directed graph like [`get_digraph`](Vault::get_digraph) with [`GraphOptions`]
+    ///
+    /// # Example
+    /// ```no_run
+    /// use obsidian_parser::prelude::*;
+    /// use obsidian_parser::vault::vault_petgraph::GraphOptions;
+    ///
+    /// let options = VaultOptions::new("/path/to/vault");
+    /// let vault: VaultOnDisk = VaultBuilder::new(&options)
+    ///     .into_iter()
+    ///     .filter_map(Result::ok)
+    ///     .build_vault(&options);
+    ///
+    /// let graph = vault
+    ///     .get_digraph_with_options(GraphOptions {
+    ///         case_insensitive: true,
+    ///         normalize_unicode: true,
+    ///     })
+    ///     .unwrap();
+    /// println!("Edges: {}", graph.edge_count());
+    /// ```
+    #[cfg_attr(docsrs, doc(cfg(feature = "petgraph")))]
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip(self), fields(path = %self.path.display(), count_notes = %self.notes.len())))]
+    pub fn get_digraph_with_options(
+        &self,
+        options: GraphOptions,
+    ) -> Result<DiGraph<&F, ()>, F::Error> {
+        #[cfg(feature = "tracing")]
+        tracing::debug!("Building directed graph with options");
+
+        GraphBuilder::with_options(self, options).build(|_| Some(()))
     }
 
     /// Parallel builds directed graph representing note relationships
@@ -111,7 +402,172 @@ where
         #[cfg(feature = "tracing")]
         tracing::debug!("Building directed graph");
 
-        self.par_get_graph()
+        self.par_get_graph(|_| Some(()))
+    }
+
+    /// Check directed graph of links has no cycles
+    ///
+    /// Same as [`petgraph::algo::is_cyclic_directed`] for [`get_digraph`](Vault::get_digraph).
+    /// Link of note to itself is cycle too
+    ///
+    /// # Example
+    /// ```no_run
+    /// use obsidian_parser::prelude::*;
+    ///
+    /// let options = VaultOptions::new("/path/to/vault");
+    /// let vault: VaultOnDisk = VaultBuilder::new(&options)
+    ///     .into_iter()
+    ///     .filter_map(Result::ok)
+    ///     .build_vault(&options);
+    ///
+    /// if vault.is_acyclic().unwrap() {
+    ///     println!("Notes can be ordered by links");
+    /// }
+    /// ```
+    #[cfg_attr(docsrs, doc(cfg(feature = "petgraph")))]
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip(self), ret, fields(path = %self.path.display(), count_notes = %self.notes.len())))]
+    pub fn is_acyclic(&self) -> Result<bool, F::Error> {
+        let graph = self.get_digraph()?;
+
+        Ok(!petgraph::algo::is_cyclic_directed(&graph))
+    }
+
+    /// Returns the shortest chain of links from note `from` to note `to`
+    ///
+    /// `from` and `to` are resolved like links: name of note (`note`) or path relative to vault
+    /// (`dir/note`). Path is returned as paths of notes relative to vault without `.md`, from `from`
+    /// to `to` inclusive, so count of hops is `path.len() - 1`. Returns [`None`] if `to` is not
+    /// reachable by links (see [`Vault::get_digraph`])
+    ///
+    /// # Example
+    /// ```no_run
+    /// use obsidian_parser::prelude::*;
+    ///
+    /// let options = VaultOptions::new("/path/to/vault");
+    /// let vault: VaultOnDisk = VaultBuilder::new(&options)
+    ///     .into_iter()
+    ///     .filter_map(Result::ok)
+    ///     .build_vault(&options);
+    ///
+    /// if let Some(path) = vault.shortest_path("Physics", "Math").unwrap() {
+    ///     println!("{} hops: {}", path.len() - 1, path.join(" -> "));
+    /// }
+    /// ```
+    ///
+    /// # Errors
+    /// - [`GraphError::NoteNotFound`] if `from` or `to` is not found
+    /// - [`GraphError::Note`] if content of note can't be read
+    #[cfg_attr(docsrs, doc(cfg(feature = "petgraph")))]
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip(self), fields(path = %self.path.display(), count_notes = %self.notes.len())))]
+    pub fn shortest_path(
+        &self,
+        from: &str,
+        to: &str,
+    ) -> Result<Option<Vec<String>>, GraphError<F::Error>> {
+        let index = Index::from_vault(self);
+        let find = |name: &str| {
+            index
+                .get(name)
+                .map(|i| NodeIndex::new(*i))
+                .ok_or_else(|| GraphError::NoteNotFound(name.to_string()))
+        };
+
+        let (start, goal) = (find(from)?, find(to)?);
+        let graph = self.get_digraph().map_err(GraphError::Note)?;
+
+        let path = petgraph::algo::astar(&graph, start, |idx| idx == goal, |_| 1_usize, |_| 0).map(
+            |(_, path)| {
+                path.into_iter()
+                    .map(|idx| {
+                        relative_path(self.node_note(&graph, idx), &self.path).unwrap_or_default()
+                    })
+                    .collect::<Vec<_>>()
+            },
+        );
+
+        #[cfg(feature = "tracing")]
+        tracing::debug!("Shortest path: {path:?}");
+
+        Ok(path)
+    }
+
+    /// Builds directed graph of notes within `depth` links of note `note_name` ("local graph")
+    ///
+    /// `note_name` is resolved like link: name of note (`note`) or path relative to vault
+    /// (`dir/note`). Direction of links is ignored when counting depth: `depth = 0` is only the
+    /// seed note, `depth = 1` adds notes linked from it and notes linking to it, and so on.
+    /// Edges between kept notes are the same as in [`Vault::get_digraph`]
+    ///
+    /// If `note_name` is not found, returns empty graph
+    ///
+    /// Node indices are **not** aligned with [`Vault::notes`]; use node weights to get notes
+    ///
+    /// # Example
+    /// ```no_run
+    /// use obsidian_parser::prelude::*;
+    ///
+    /// let options = VaultOptions::new("/path/to/vault");
+    /// let vault: VaultOnDisk = VaultBuilder::new(&options)
+    ///     .into_iter()
+    ///     .filter_map(Result::ok)
+    ///     .build_vault(&options);
+    ///
+    /// let graph = vault.neighborhood_graph("Physics", 2).unwrap();
+    /// for note in graph.node_weights() {
+    ///     println!("{}", note.note_name().unwrap());
+    /// }
+    /// ```
+    #[cfg_attr(docsrs, doc(cfg(feature = "petgraph")))]
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip(self), fields(path = %self.path.display(), count_notes = %self.notes.len())))]
+    pub fn neighborhood_graph(
+        &self,
+        note_name: &str,
+        depth: usize,
+    ) -> Result<DiGraph<&F, ()>, F::Error> {
+        let Some(seed) = Index::from_vault(self).get(note_name).copied() else {
+            #[cfg(feature = "tracing")]
+            tracing::debug!("Note `{note_name}` is not found");
+
+            return Ok(DiGraph::new());
+        };
+
+        let graph = self.get_digraph()?;
+
+        let mut keep = vec![false; graph.node_count()];
+        keep[seed] = true;
+
+        let mut frontier = vec![NodeIndex::new(seed)];
+        for _ in 0..depth {
+            let mut next = Vec::new();
+            for idx in frontier {
+                for neighbor in graph.neighbors_undirected(idx) {
+                    if !keep[neighbor.index()] {
+                        keep[neighbor.index()] = true;
+                        next.push(neighbor);
+                    }
+                }
+            }
+
+            if next.is_empty() {
+                break;
+            }
+
+            frontier = next;
+        }
+
+        let subgraph = graph.filter_map(
+            |idx, note| keep[idx.index()].then_some(*note),
+            |_, ()| Some(()),
+        );
+
+        #[cfg(feature = "tracing")]
+        tracing::debug!(
+            "Neighborhood has {} notes and {} links",
+            subgraph.node_count(),
+            subgraph.edge_count()
+        );
+
+        Ok(subgraph)
     }
 
     /// Builds undirected graph showing note connections
@@ -123,7 +579,7 @@ where
         #[cfg(feature = "tracing")]
         tracing::debug!("Building undirected graph");
 
-        self.get_graph()
+        self.get_graph(|_| Some(()))
     }
 
     /// Parallel builds undirected graph showing note connections
@@ -141,13 +597,17 @@ where
         #[cfg(feature = "tracing")]
         tracing::debug!("Building undirected graph");
 
-        self.par_get_graph()
+        self.par_get_graph(|_| Some(()))
     }
 }
 
 #[cfg(test)]
 mod tests {
-    use crate::vault::vault_test::create_test_vault;
+    use super::{EdgeKind, GraphError, GraphOptions};
+    use crate::note::Note;
+    use crate::prelude::{NoteDefault, NoteInMemory, Vault, VaultInMemory};
+    use crate::vault::vault_test::{create_test_vault, create_vault_with_files};
+    use petgraph::graph::NodeIndex;
 
     #[cfg_attr(feature = "tracing", tracing_test::traced_test)]
     #[test]
@@ -161,6 +621,20 @@ mod tests {
         assert_eq!(graph.node_count(), files.len());
     }
 
+    #[cfg_attr(feature = "tracing", tracing_test::traced_test)]
+    #[test]
+    #[cfg(feature = "petgraph")]
+    fn node_note() {
+        let (vault, _temp_dir, _files) = create_test_vault().unwrap();
+
+        let graph = vault.get_digraph().unwrap();
+
+        for idx in graph.node_indices() {
+            let note = vault.node_note(&graph, idx);
+            assert!(std::ptr::eq(note, &vault.notes()[idx.index()]));
+        }
+    }
+
     #[cfg_attr(feature = "tracing", tracing_test::traced_test)]
     #[test]
     #[cfg(feature = "petgraph")]
@@ -185,4 +659,300 @@ mod tests {
         assert_eq!(graph.edge_count(), 3);
         assert_eq!(graph.node_count(), files.len());
     }
+
+    #[cfg_attr(feature = "tracing", tracing_test::traced_test)]
+    #[test]
+    #[cfg(feature = "petgraph")]
+    fn is_acyclic() {
+        let (vault, _temp_dir) =
+            create_vault_with_files(&[("a.md", "[[b]]"), ("b.md", "[[c]]"), ("c.md", "End")])
+                .unwrap();
+        assert!(vault.is_acyclic().unwrap());
+
+        let (vault, _temp_dir) =
+            create_vault_with_files(&[("a.md", "[[b]]"), ("b.md", "[[a]]")]).unwrap();
+        assert!(!vault.is_acyclic().unwrap());
+
+        let (vault, _temp_dir) = create_vault_with_files(&[("a.md", "[[a]]")]).unwrap();
+        assert!(!vault.is_acyclic().unwrap());
+    }
+
+    #[cfg_attr(feature = "tracing", tracing_test::traced_test)]
+    #[test]
+    #[cfg(feature = "petgraph")]
+    fn get_digraph_without_embeds() {
+        let (vault, _temp_dir) = create_vault_with_files(&[
+            ("main.md", "[[link]] ![[image]] ![[link]]"),
+            ("link.md", "[[main]]"),
+            ("image.md", "Not image, but note"),
+        ])
+        .unwrap();
+
+        assert_eq!(vault.get_digraph().unwrap().edge_count(), 4);
+        assert_eq!(vault.get_digraph_without_embeds().unwrap().edge_count(), 2);
+    }
+
+    #[cfg_attr(feature = "tracing", tracing_test::traced_test)]
+    #[test]
+    #[cfg(feature = "petgraph")]
+    fn get_digraph_with_kinds() {
+        let (vault, _temp_dir) =
+            create_vault_with_files(&[("main.md", "[[link]] ![[link]]"), ("link.md", "Link")])
+                .unwrap();
+
+        let graph = vault.get_digraph_with_kinds().unwrap();
+        let mut kinds: Vec<_> = graph.edge_weights().copied().collect();
+        kinds.sort_by_key(|kind| *kind == EdgeKind::Embed);
+
+        assert_eq!(kinds, [EdgeKind::Link, EdgeKind::Embed]);
+    }
+
+    #[cfg_attr(feature = "tracing", tracing_test::traced_test)]
+    #[test]
+    #[cfg(feature = "petgraph")]
+    fn get_digraph_weighted() {
+        let (vault, _temp_dir) = create_vault_with_files(&[
+            ("a.md", "[[b]] [[b|B]] ![[b]] [[c]] [[missing]]"),
+            ("b.md", "[[a]]"),
+            ("c.md", "End"),
+        ])
+        .unwrap();
+
+        let graph = vault.get_digraph_weighted().unwrap();
+        let mut edges: Vec<_> = graph
+            .raw_edges()
+            .iter()
+            .map(|edge| {
+                let name = |idx| vault.node_note(&graph, idx).note_name().unwrap();
+                (name(edge.source()), name(edge.target()), edge.weight)
+            })
+            .collect();
+        edges.sort();
+
+        assert_eq!(
+            edges,
+            [
+                ("a".to_string(), "b".to_string(), 3),
+                ("a".to_string(), "c".to_string(), 1),
+                ("b".to_string(), "a".to_string(), 1),
+            ]
+        );
+        assert_eq!(graph.node_count(), 3);
+        assert_eq!(vault.get_digraph().unwrap().edge_count(), 5);
+    }
+
+    #[cfg_attr(feature = "tracing", tracing_test::traced_test)]
+    #[test]
+    #[cfg(feature = "petgraph")]
+    fn try_get_digraph() {
+        let (vault, _temp_dir) = create_vault_with_files(&[
+            ("a.md", "[[b]] [[dir/note]] [[missing]]"),
+            ("b.md", "[[a]]"),
+            ("note.md", "[[a]]"),
+            ("dir/note.md", "[[b]]"),
+        ])
+        .unwrap();
+
+        let graph = vault.try_get_digraph().unwrap();
+        assert_eq!(graph.edge_count(), 5);
+        assert_eq!(
+            graph.edge_count(),
+            vault.get_digraph().unwrap().edge_count()
+        );
+        assert_eq!(vault.try_get_ungraph().unwrap().edge_count(), 5);
+    }
+
+    #[cfg_attr(feature = "tracing", tracing_test::traced_test)]
+    #[test]
+    #[cfg(feature = "petgraph")]
+    fn try_get_digraph_ambiguous() {
+        let (vault, _temp_dir) = create_vault_with_files(&[
+            ("a.md", "[[b]] [[ note.md |alias]]"),
+            ("b.md", "Text"),
+            ("note.md", "Text"),
+            ("dir/note.md", "Text"),
+        ])
+        .unwrap();
+
+        let Err(GraphError::AmbiguousLink { link, candidates }) = vault.try_get_digraph() else {
+            panic!("Link must be ambiguous");
+        };
+        assert_eq!(link, "note.md");
+        assert_eq!(candidates, ["dir/note", "note"]);
+
+        assert!(matches!(
+            vault.try_get_ungraph(),
+            Err(GraphError::AmbiguousLink { .. })
+        ));
+        assert_eq!(vault.get_digraph().unwrap().edge_count(), 2);
+    }
+
+    #[cfg_attr(feature = "tracing", tracing_test::traced_test)]
+    #[test]
+    #[cfg(feature = "petgraph")]
+    fn shortest_path() {
+        let (vault, _temp_dir, _files) = create_test_vault().unwrap();
+
+        assert_eq!(
+            vault.shortest_path("data/main", "link").unwrap().unwrap(),
+            ["data/main", "link"]
+        );
+        assert_eq!(
+            vault.shortest_path("link", "link").unwrap().unwrap(),
+            ["link"]
+        );
+        assert!(matches!(
+            vault.shortest_path("link", "missing"),
+            Err(GraphError::NoteNotFound(name)) if name == "missing"
+        ));
+        assert!(matches!(
+            vault.shortest_path("missing", "link"),
+            Err(GraphError::NoteNotFound(name)) if name == "missing"
+        ));
+    }
+
+    #[cfg_attr(feature = "tracing", tracing_test::traced_test)]
+    #[test]
+    #[cfg(feature = "petgraph")]
+    fn shortest_path_unreachable() {
+        let (vault, _temp_dir) = create_vault_with_files(&[
+            ("a.md", "[[b]] [[dir/d]]"),
+            ("b.md", "[[c]]"),
+            ("c.md", "End"),
+            ("dir/d.md", "[[c]]"),
+            ("e.md", "[[a]]"),
+        ])
+        .unwrap();
+
+        let path = vault.shortest_path("e", "c").unwrap().unwrap();
+        assert_eq!(path.len(), 4);
+        assert_eq!(path[..2], ["e", "a"]);
+        assert_eq!(path[3], "c");
+
+        assert_eq!(vault.shortest_path("c", "a").unwrap(), None);
+    }
+
+    #[cfg_attr(feature = "tracing", tracing_test::traced_test)]
+    #[test]
+    #[cfg(feature = "petgraph")]
+    fn neighborhood_graph() {
+        let (vault, _temp_dir) = create_vault_with_files(&[
+            ("a.md", "[[b]]"),
+            ("b.md", "[[c]]"),
+            ("c.md", "[[d]]"),
+            ("d.md", "End"),
+            ("e.md", "[[b]]"),
+            ("f.md", "Alone"),
+        ])
+        .unwrap();
+
+        let names = |depth| {
+            let graph = vault.neighborhood_graph("b", depth).unwrap();
+            let mut names: Vec<_> = graph
+                .node_weights()
+                .map(|note| note.note_name().unwrap())
+                .collect();
+            names.sort();
+
+            (names, graph.edge_count())
+        };
+
+        assert_eq!(names(0), (vec!["b".to_string()], 0));
+        assert_eq!(
+            names(1),
+            (
+                vec![
+                    "a".to_string(),
+                    "b".to_string(),
+                    "c".to_string(),
+                    "e".to_string()
+                ],
+                3
+            )
+        );
+        assert_eq!(names(2).0.len(), 5);
+        assert_eq!(names(2).1, 4);
+        assert_eq!(names(10), names(2));
+
+        assert_eq!(
+            vault.neighborhood_graph("missing", 1).unwrap().node_count(),
+            0
+        );
+    }
+
+    #[cfg_attr(feature = "tracing", tracing_test::traced_test)]
+    #[test]
+    #[cfg(feature = "petgraph")]
+    fn get_digraph_from_str_notes() {
+        let vault = <VaultInMemory>::from_str_notes([
+            ("main", "[[data/main|main]]"),
+            ("link", "[[main.md]]"),
+            ("data/main.md", "[[link]] [[missing]]"),
+        ])
+        .unwrap();
+
+        let graph = vault.get_digraph().unwrap();
+        assert_eq!(graph.node_count(), 3);
+        assert_eq!(graph.edge_count(), 3);
+        assert!(graph.contains_edge(NodeIndex::new(0), NodeIndex::new(2)));
+        assert!(graph.contains_edge(NodeIndex::new(2), NodeIndex::new(1)));
+
+        assert_eq!(
+            vault.shortest_path("main", "link").unwrap().unwrap(),
+            ["main", "data/main", "link"]
+        );
+    }
+
+    #[cfg_attr(feature = "tracing", tracing_test::traced_test)]
+    #[test]
+    #[cfg(feature = "petgraph")]
+    fn get_digraph_from_notes() {
+        let notes = ["a", "b"].map(|name| {
+            let mut note = NoteInMemory::from_string_default("[[a]] [[b]]").unwrap();
+            note.set_path(Some(format!("/vault/{name}.md").into()));
+            note
+        });
+        let vault = Vault::from_notes(notes.to_vec(), "/vault");
+
+        let graph = vault.get_digraph().unwrap();
+        assert_eq!(graph.node_count(), 2);
+        assert_eq!(graph.edge_count(), 4);
+    }
+
+    #[cfg_attr(feature = "tracing", tracing_test::traced_test)]
+    #[test]
+    #[cfg(feature = "petgraph")]
+    fn get_digraph_with_options() {
+        let vault = <VaultInMemory>::from_str_notes([
+            ("Note", "[[other]] [[CAFE\u{301}]]"),
+            ("Other", "[[note]]"),
+            ("Caf\u{e9}", "[[dir/deep]]"),
+            ("Dir/Deep", "[[Note]]"),
+        ])
+        .unwrap();
+
+        let edges = |case_insensitive, normalize_unicode| {
+            vault
+                .get_digraph_with_options(GraphOptions {
+                    case_insensitive,
+                    normalize_unicode,
+                })
+                .unwrap()
+                .edge_count()
+        };
+
+        assert_eq!(edges(false, false), 1);
+        assert_eq!(
+            edges(false, false),
+            vault.get_digraph().unwrap().edge_count()
+        );
+        assert_eq!(edges(true, false), 4);
+        assert_eq!(edges(false, true), 1);
+
+        #[cfg(feature = "unicode")]
+        assert_eq!(edges(true, true), 5);
+
+        #[cfg(not(feature = "unicode"))]
+        assert_eq!(edges(true, true), 4);
+    }
 }
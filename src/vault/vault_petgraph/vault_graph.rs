@@ -0,0 +1,460 @@
+//! Graph of vault with incremental updates
+//!
+//! [`Vault::get_digraph_with_kinds`] rebuilds index and graph from scratch. When notes arrive
+//! one by one (like from file watcher), use [`VaultGraph`]: it updates only edges of changed note
+
+use super::EdgeKind;
+use crate::note::{
+    Note,
+    parser::{normalize_link, parse_links_with_embed_flag},
+};
+use crate::vault::{
+    Vault,
+    index::{is_canvas, relative_path},
+};
+use petgraph::stable_graph::{NodeIndex, StableDiGraph};
+use std::collections::HashMap;
+use std::path::PathBuf;
+
+/// Index keys and link targets of node
+#[derive(Debug, Clone)]
+struct NodeEntry {
+    /// Full path relative to vault without `.md`
+    full: Option<String>,
+
+    /// Name of note
+    short: Option<String>,
+
+    /// Normalized targets of all links from note
+    targets: Vec<String>,
+}
+
+/// Directed graph of vault with incremental updates
+///
+/// Links are resolved like in [`Vault::get_digraph_with_kinds`]. After any sequence of
+/// [`VaultGraph::add_note`] and [`VaultGraph::remove_note`] graph has the same edges as full rebuild
+/// of vault with the same notes in the same order of adding.
+///
+/// Graph is [`StableDiGraph`], so [`NodeIndex`] of note doesn't change after removing other notes
+///
+/// # Example
+/// ```no_run
+/// use obsidian_parser::prelude::*;
+/// use obsidian_parser::vault::vault_petgraph::vault_graph::VaultGraph;
+///
+/// let new_note = NoteOnDisk::from_file_default("/path/to/vault/new.md").unwrap();
+/// let mut graph = VaultGraph::new("/path/to/vault");
+///
+/// graph.add_note(&new_note).unwrap();
+/// println!("Edges: {}", graph.graph().edge_count());
+///
+/// graph.remove_note("new");
+/// assert_eq!(graph.graph().node_count(), 0);
+/// ```
+#[derive(Debug, Clone)]
+pub struct VaultGraph<'a, N> {
+    graph: StableDiGraph<&'a N, EdgeKind>,
+
+    /// Path to vault root directory
+    path: PathBuf,
+
+    /// Full path -> node
+    full: HashMap<String, NodeIndex>,
+
+    /// Name -> nodes in order of adding. The first one is target of links
+    short: HashMap<String, Vec<NodeIndex>>,
+
+    /// Normalized target -> sources of links to it (resolved or not)
+    links: HashMap<String, Vec<(NodeIndex, EdgeKind)>>,
+
+    nodes: HashMap<NodeIndex, NodeEntry>,
+}
+
+impl<'a, N> VaultGraph<'a, N>
+where
+    N: Note,
+{
+    /// Create empty graph for vault in `path`
+    pub fn new(path: impl Into<PathBuf>) -> Self {
+        Self {
+            graph: StableDiGraph::default(),
+            path: path.into(),
+            full: HashMap::new(),
+            short: HashMap::new(),
+            links: HashMap::new(),
+            nodes: HashMap::new(),
+        }
+    }
+
+    /// Get graph
+    #[must_use]
+    #[inline]
+    pub const fn graph(&self) -> &StableDiGraph<&'a N, EdgeKind> {
+        &self.graph
+    }
+
+    /// Get node of note by link target (full path with `/` or name)
+    #[must_use]
+    pub fn node(&self, target: &str) -> Option<NodeIndex> {
+        let target = normalize_link(target);
+
+        if target.contains('/') {
+            self.full.get(&target).copied()
+        } else {
+            self.short.get(&target)?.first().copied()
+        }
+    }
+
+    /// Add note and its links to graph
+    ///
+    /// Links from other notes to this note are resolved too. If graph already has note with
+    /// the same path, it is replaced, so file change is just `add_note`
+    ///
+    /// Notes without [`Note::path`] are added, but links can't point to them.
+    /// Canvases (`.canvas` files) are added like notes without path and without links
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip_all, fields(path = %self.path.display(), note = ?note.path())))]
+    pub fn add_note(&mut self, note: &'a N) -> Result<NodeIndex, N::Error> {
+        if is_canvas(note) {
+            let idx = self.graph.add_node(note);
+            self.nodes.insert(
+                idx,
+                NodeEntry {
+                    full: None,
+                    short: None,
+                    targets: Vec::new(),
+                },
+            );
+
+            return Ok(idx);
+        }
+
+        let content = note.content()?;
+
+        let full = relative_path(note, &self.path).map(|full| normalize_link(&full));
+        if let Some(old) = full.as_ref().and_then(|full| self.full.get(full).copied()) {
+            #[cfg(feature = "tracing")]
+            tracing::debug!("Replace note");
+
+            self.remove_node(old);
+        }
+
+        let idx = self.graph.add_node(note);
+        let short = note.note_name().map(|short| normalize_link(&short));
+
+        if let Some(full) = &full {
+            self.full.insert(full.clone(), idx);
+
+            if full.contains('/') {
+                self.connect_sources(full, idx);
+            }
+        }
+
+        if let Some(short) = &short {
+            let nodes = self.short.entry(short.clone()).or_default();
+            nodes.push(idx);
+
+            if nodes.len() == 1 {
+                self.connect_sources(short, idx);
+            }
+        }
+
+        let mut targets = Vec::new();
+        for (link, is_embed) in parse_links_with_embed_flag(&content) {
+            let target = normalize_link(link.target);
+            let kind = EdgeKind::new(is_embed);
+
+            if let Some(to) = self.node(&target) {
+                self.graph.add_edge(idx, to, kind);
+            }
+
+            self.links
+                .entry(target.clone())
+                .or_default()
+                .push((idx, kind));
+            targets.push(target);
+        }
+
+        targets.sort_unstable();
+        targets.dedup();
+
+        self.nodes.insert(
+            idx,
+            NodeEntry {
+                full,
+                short,
+                targets,
+            },
+        );
+
+        #[cfg(feature = "tracing")]
+        tracing::debug!("Note added. Edges: {}", self.graph.edge_count());
+
+        Ok(idx)
+    }
+
+    /// Remove note and all its edges from graph
+    ///
+    /// `name` is resolved like link: full path with `/` or name of note. If several notes share
+    /// the name, links to it are resolved to the next one
+    ///
+    /// Returns removed note or [`None`] if it is not found
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip(self), fields(path = %self.path.display())))]
+    pub fn remove_note(&mut self, name: &str) -> Option<&'a N> {
+        let node = self.node(name)?;
+        self.remove_node(node)
+    }
+
+    fn remove_node(&mut self, node: NodeIndex) -> Option<&'a N> {
+        let removed = self.graph.remove_node(node)?;
+        let entry = self.nodes.remove(&node)?;
+
+        for target in &entry.targets {
+            if let Some(sources) = self.links.get_mut(target) {
+                sources.retain(|(source, _)| *source != node);
+
+                if sources.is_empty() {
+                    self.links.remove(target);
+                }
+            }
+        }
+
+        if let Some(full) = &entry.full {
+            self.full.remove(full);
+        }
+
+        if let Some(short) = &entry.short
+            && let Some(nodes) = self.short.get_mut(short)
+        {
+            let was_first = nodes.first() == Some(&node);
+            nodes.retain(|other| *other != node);
+
+            match nodes.first().copied() {
+                Some(next) if was_first => self.connect_sources(short, next),
+                Some(_) => {}
+                None => {
+                    self.short.remove(short);
+                }
+            }
+        }
+
+        #[cfg(feature = "tracing")]
+        tracing::debug!("Note removed. Edges: {}", self.graph.edge_count());
+
+        Some(removed)
+    }
+
+    /// Add edges from all sources of links to `target`
+    fn connect_sources(&mut self, target: &str, to: NodeIndex) {
+        for (from, kind) in self.links.get(target).into_iter().flatten() {
+            self.graph.add_edge(*from, to, *kind);
+        }
+    }
+}
+
+impl<N> Vault<N>
+where
+    N: Note,
+{
+    /// Build [`VaultGraph`] from all notes of vault
+    ///
+    /// # Example
+    /// ```no_run
+    /// use obsidian_parser::prelude::*;
+    ///
+    /// let options = VaultOptions::new("/path/to/vault");
+    /// let vault: VaultOnDisk = VaultBuilder::new(&options)
+    ///     .into_iter()
+    ///     .filter_map(Result::ok)
+    ///     .build_vault(&options);
+    ///
+    /// let mut graph = vault.get_vault_graph().unwrap();
+    /// graph.remove_note("deleted");
+    /// ```
+    #[cfg_attr(docsrs, doc(cfg(feature = "petgraph")))]
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip(self), fields(path = %self.path.display(), count_notes = %self.notes.len())))]
+    pub fn get_vault_graph(&self) -> Result<VaultGraph<'_, N>, N::Error> {
+        #[cfg(feature = "tracing")]
+        tracing::debug!("Building vault graph");
+
+        let mut graph = VaultGraph::new(&self.path);
+        for note in self.notes() {
+            graph.add_note(note)?;
+        }
+
+        Ok(graph)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::prelude::*;
+    use crate::vault::vault_test::create_vault_with_files;
+    use petgraph::visit::{EdgeRef, IntoEdgeReferences};
+    use std::path::Path;
+
+    type Edges = Vec<(String, String, bool)>;
+
+    /// Sorted edges as `(from, to, is_embed)` with paths relative to `root`
+    fn sorted_edges<'a>(
+        edges: impl Iterator<Item = (&'a NoteInMemory, &'a NoteInMemory, EdgeKind)>,
+        root: &Path,
+    ) -> Edges {
+        let mut result: Vec<_> = edges
+            .map(|(from, to, kind)| {
+                (
+                    relative_path(from, root).unwrap(),
+                    relative_path(to, root).unwrap(),
+                    kind == EdgeKind::Embed,
+                )
+            })
+            .collect();
+
+        result.sort_unstable();
+        result
+    }
+
+    fn edges(graph: &VaultGraph<'_, NoteInMemory>, root: &Path) -> Edges {
+        let graph = graph.graph();
+        let edges = graph
+            .edge_references()
+            .map(|edge| (graph[edge.source()], graph[edge.target()], *edge.weight()));
+
+        sorted_edges(edges, root)
+    }
+
+    /// Edges of full rebuild of vault with `notes`
+    fn rebuild_edges(notes: &[&NoteInMemory], root: &Path) -> Edges {
+        let vault: VaultInMemory = notes
+            .iter()
+            .map(|note| (*note).clone())
+            .build_vault(&VaultOptions::new(root));
+
+        let graph = vault.get_digraph_with_kinds().unwrap();
+        let edges = graph
+            .edge_references()
+            .map(|edge| (graph[edge.source()], graph[edge.target()], *edge.weight()));
+
+        sorted_edges(edges, root)
+    }
+
+    const FILES: &[(&str, &str)] = &[
+        ("a.md", "[[b]] ![[c]] [[missing]] [[dir/note]]"),
+        ("b.md", "[[a]] [[note]] [[b]]"),
+        ("dir/note.md", "[[a]]"),
+        ("other/note.md", "[[c]]"),
+        ("c.md", "[[b]] [[b]]"),
+    ];
+
+    #[cfg_attr(feature = "tracing", tracing_test::traced_test)]
+    #[test]
+    fn get_vault_graph() {
+        let (vault, path) = create_vault_with_files(FILES).unwrap();
+        let graph = vault.get_vault_graph().unwrap();
+        let notes: Vec<_> = vault.notes().iter().collect();
+
+        assert_eq!(graph.graph().node_count(), vault.count_notes());
+        assert_eq!(
+            edges(&graph, path.path()),
+            rebuild_edges(&notes, path.path())
+        );
+    }
+
+    #[cfg_attr(feature = "tracing", tracing_test::traced_test)]
+    #[test]
+    fn add_notes() {
+        let (vault, path) = create_vault_with_files(FILES).unwrap();
+        let mut graph = VaultGraph::new(path.path());
+        let mut added = Vec::new();
+
+        // Links to notes, which are not added yet, are resolved later
+        for note in vault.notes().iter().rev() {
+            graph.add_note(note).unwrap();
+            added.push(note);
+
+            assert_eq!(
+                edges(&graph, path.path()),
+                rebuild_edges(&added, path.path())
+            );
+        }
+    }
+
+    #[cfg_attr(feature = "tracing", tracing_test::traced_test)]
+    #[test]
+    fn remove_notes() {
+        let (vault, path) = create_vault_with_files(FILES).unwrap();
+        let mut graph = vault.get_vault_graph().unwrap();
+        let mut left: Vec<_> = vault.notes().iter().collect();
+
+        for name in ["missing", "b", "dir/note", "note", "a", "c"] {
+            let removed = graph.remove_note(name);
+
+            match left.iter().position(|note| Some(*note) == removed) {
+                Some(position) => {
+                    left.remove(position);
+                }
+                None => assert!(removed.is_none()),
+            }
+
+            assert_eq!(graph.graph().node_count(), left.len());
+            assert_eq!(
+                edges(&graph, path.path()),
+                rebuild_edges(&left, path.path())
+            );
+        }
+
+        assert_eq!(graph.graph().node_count(), 0);
+    }
+
+    #[cfg_attr(feature = "tracing", tracing_test::traced_test)]
+    #[test]
+    fn remove_shared_name() {
+        let (vault, path) = create_vault_with_files(FILES).unwrap();
+        let mut graph = vault.get_vault_graph().unwrap();
+
+        let first = graph.node("note").unwrap();
+        let removed = graph.remove_note("note").unwrap();
+        assert_eq!(graph.graph().node_count(), vault.count_notes() - 1);
+
+        // `[[note]]` is resolved to the other note now
+        let next = graph.node("note").unwrap();
+        assert_ne!(first, next);
+        assert_ne!(graph.graph()[next].path(), removed.path());
+
+        let left: Vec<_> = vault
+            .notes()
+            .iter()
+            .filter(|note| note.path() != removed.path())
+            .collect();
+        assert_eq!(
+            edges(&graph, path.path()),
+            rebuild_edges(&left, path.path())
+        );
+    }
+
+    #[cfg_attr(feature = "tracing", tracing_test::traced_test)]
+    #[test]
+    fn replace_note() {
+        let (vault, path) = create_vault_with_files(FILES).unwrap();
+        let mut graph = vault.get_vault_graph().unwrap();
+
+        let mut changed = NoteInMemory::from_string_default("[[c]] [[dir/note]]").unwrap();
+        changed.set_path(Some(path.path().join("b.md")));
+
+        graph.add_note(&changed).unwrap();
+        assert_eq!(graph.graph().node_count(), vault.count_notes());
+
+        // Changed note is the last one now
+        let mut notes: Vec<_> = vault
+            .notes()
+            .iter()
+            .filter(|note| note.note_name().unwrap() != "b")
+            .collect();
+        notes.push(&changed);
+
+        assert_eq!(
+            edges(&graph, path.path()),
+            rebuild_edges(&notes, path.path())
+        );
+    }
+}
@@ -0,0 +1,131 @@
+//! Types of properties from Obsidian settings (`.obsidian/types.json`)
+
+use super::Vault;
+use crate::note::Note;
+use serde::Deserialize;
+use std::{collections::HashMap, path::PathBuf};
+use thiserror::Error;
+
+/// Errors for [`Vault::property_types`]
+#[derive(Debug, Error)]
+pub enum Error {
+    /// I/O operation failed (reading `types.json`)
+    #[error("IO error: {0}")]
+    IO(#[from] std::io::Error),
+
+    /// `types.json` is invalid
+    #[error("JSON error: {0}")]
+    Json(#[from] serde_json::Error),
+}
+
+/// Content of `.obsidian/types.json`
+#[derive(Debug, Deserialize)]
+struct TypesFile {
+    #[serde(default)]
+    types: HashMap<String, String>,
+}
+
+impl<N> Vault<N>
+where
+    N: Note,
+{
+    /// Path to `.obsidian/types.json` of vault
+    fn types_path(&self) -> PathBuf {
+        self.path.join(".obsidian").join("types.json")
+    }
+
+    /// Returns declared types of properties: name of property -> type
+    /// (`text`, `number`, `date`, `multitext` and so on)
+    ///
+    /// Obsidian (1.4+) stores them in `.obsidian/types.json`. If file doesn't exist,
+    /// no types are declared, so map is empty
+    ///
+    /// # Example
+    /// ```no_run
+    /// use obsidian_parser::prelude::*;
+    ///
+    /// let options = VaultOptions::new("/path/to/vault");
+    /// let vault: VaultOnDisk = VaultBuilder::new(&options)
+    ///     .into_iter()
+    ///     .filter_map(Result::ok)
+    ///     .build_vault(&options);
+    ///
+    /// for (property, kind) in vault.property_types().unwrap() {
+    ///     println!("{property}: {kind}");
+    /// }
+    /// ```
+    ///
+    /// # Errors
+    /// - [`Error::IO`] if file can't be read
+    /// - [`Error::Json`] if file is invalid
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip(self), fields(path = %self.path.display(), count_notes = %self.notes.len())))]
+    pub fn property_types(&self) -> Result<HashMap<String, String>, Error> {
+        let path = self.types_path();
+
+        let text = match std::fs::read_to_string(&path) {
+            Ok(text) => text,
+            Err(error) if error.kind() == std::io::ErrorKind::NotFound => {
+                #[cfg(feature = "tracing")]
+                tracing::debug!("File `{}` not found", path.display());
+
+                return Ok(HashMap::new());
+            }
+            Err(error) => return Err(error.into()),
+        };
+
+        let file: TypesFile = serde_json::from_str(&text)?;
+
+        #[cfg(feature = "tracing")]
+        tracing::debug!("Found {} types of properties", file.types.len());
+
+        Ok(file.types)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::vault::vault_test::create_vault_with_files;
+
+    #[cfg_attr(feature = "tracing", tracing_test::traced_test)]
+    #[test]
+    fn property_types() {
+        let (vault, _path) = create_vault_with_files(&[
+            ("note.md", "Note"),
+            (
+                ".obsidian/types.json",
+                r#"{"types": {"aliases": "aliases", "rating": "number", "created": "date", "topics": "multitext"}}"#,
+            ),
+        ])
+        .unwrap();
+
+        let expected: HashMap<_, _> = [
+            ("aliases", "aliases"),
+            ("rating", "number"),
+            ("created", "date"),
+            ("topics", "multitext"),
+        ]
+        .into_iter()
+        .map(|(name, kind)| (name.to_string(), kind.to_string()))
+        .collect();
+
+        assert_eq!(vault.property_types().unwrap(), expected);
+    }
+
+    #[cfg_attr(feature = "tracing", tracing_test::traced_test)]
+    #[test]
+    fn property_types_without_file() {
+        let (vault, _path) = create_vault_with_files(&[("note.md", "Note")]).unwrap();
+
+        assert!(vault.property_types().unwrap().is_empty());
+    }
+
+    #[cfg_attr(feature = "tracing", tracing_test::traced_test)]
+    #[test]
+    fn property_types_invalid() {
+        let (vault, _path) =
+            create_vault_with_files(&[(".obsidian/types.json", "{\"types\": [1, 2]}")]).unwrap();
+
+        assert!(matches!(vault.property_types(), Err(Error::Json(_))));
+    }
+}
@@ -0,0 +1,211 @@
+//! Save all notes of [`Vault`] back to disk
+
+use super::{Vault, error::SaveError};
+use crate::note::{NoteWrite, note_name::InvalidNoteName, parser};
+use serde::Serialize;
+use std::fs::OpenOptions;
+use std::path::{Component, Path};
+
+impl<N> Vault<N>
+where
+    N: NoteWrite,
+    N::Properties: Serialize,
+    N::Error: From<std::io::Error>
+        + From<serde_yml::Error>
+        + From<parser::Error>
+        + std::error::Error
+        + 'static,
+{
+    /// Flush every note to its [`Note::path`](crate::note::Note::path) (see [`NoteWrite::flush`])
+    ///
+    /// Notes without path are skipped. Stops on first error
+    ///
+    /// Note is not flushed, if its path leaves [`Vault::path`]
+    /// (like `<vault>/dir/../../note.md`)
+    ///
+    /// # Example
+    /// ```no_run
+    /// use obsidian_parser::prelude::*;
+    /// use std::fs::OpenOptions;
+    ///
+    /// let options = VaultOptions::new("/path/to/vault");
+    /// let vault: VaultInMemory = VaultBuilder::new(&options)
+    ///     .into_iter()
+    ///     .filter_map(Result::ok)
+    ///     .build_vault(&options);
+    ///
+    /// let open_options = OpenOptions::new().write(true).truncate(true).clone();
+    /// vault.save(&open_options).unwrap();
+    /// ```
+    ///
+    /// # Errors
+    /// [`SaveError`] with path of note, which failed to flush.
+    /// Error is [`InvalidNoteName`] (wrapped into [`std::io::Error`]) if path of note leaves [`Vault::path`]
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip(self), fields(path = %self.path.display(), count_notes = %self.notes.len())))]
+    pub fn save(&self, open_options: &OpenOptions) -> Result<(), SaveError<N::Error>> {
+        #[cfg(feature = "tracing")]
+        tracing::debug!("Saving vault...");
+
+        self.notes
+            .iter()
+            .try_for_each(|note| save_note(&self.path, note, open_options))
+    }
+
+    /// Parallel version of [`Vault::save`]
+    ///
+    /// If several notes fail, any of errors is returned
+    #[cfg_attr(docsrs, doc(cfg(feature = "rayon")))]
+    #[cfg(feature = "rayon")]
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip(self), fields(path = %self.path.display(), count_notes = %self.notes.len())))]
+    pub fn par_save(&self, open_options: &OpenOptions) -> Result<(), SaveError<N::Error>>
+    where
+        N: Sync,
+        N::Error: Send,
+    {
+        use rayon::prelude::*;
+
+        #[cfg(feature = "tracing")]
+        tracing::debug!("Saving vault in parallel...");
+
+        self.notes
+            .par_iter()
+            .try_for_each(|note| save_note(&self.path, note, open_options))
+    }
+}
+
+/// Check that every component of `path` after `root` is a normal name
+fn validate_path_in_vault(root: &Path, path: &Path) -> std::io::Result<()> {
+    let is_inside = path.strip_prefix(root).is_ok_and(|relative| {
+        relative
+            .components()
+            .all(|component| matches!(component, Component::Normal(_) | Component::CurDir))
+    });
+
+    if is_inside {
+        Ok(())
+    } else {
+        #[cfg(feature = "tracing")]
+        tracing::warn!(
+            "Path of note `{}` leaves vault `{}`",
+            path.display(),
+            root.display()
+        );
+
+        Err(InvalidNoteName(path.display().to_string()).into())
+    }
+}
+
+fn save_note<N>(
+    root: &Path,
+    note: &N,
+    open_options: &OpenOptions,
+) -> Result<(), SaveError<N::Error>>
+where
+    N: NoteWrite,
+    N::Properties: Serialize,
+    N::Error: From<std::io::Error>
+        + From<serde_yml::Error>
+        + From<parser::Error>
+        + std::error::Error
+        + 'static,
+{
+    let Some(path) = note.path() else {
+        return Ok(());
+    };
+
+    validate_path_in_vault(root, &path)
+        .map_err(N::Error::from)
+        .and_then(|()| note.flush(open_options))
+        .map_err(|source| SaveError {
+            path: path.into_owned(),
+            source,
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::note::{DefaultProperties, note_name::InvalidNoteName};
+    use crate::prelude::*;
+    use crate::test_utils::is_error;
+    use crate::vault::vault_test::create_vault_with_files;
+    use std::fs::OpenOptions;
+    use tempfile::TempDir;
+
+    const FILES: &[(&str, &str)] = &[
+        ("a.md", "---\ntopic: life\n---\nFirst"),
+        ("dir/b.md", "Second"),
+    ];
+
+    fn open_options() -> OpenOptions {
+        OpenOptions::new().write(true).truncate(true).clone()
+    }
+
+    #[cfg_attr(feature = "tracing", tracing_test::traced_test)]
+    #[test]
+    fn save() {
+        let (vault, path) = create_vault_with_files(FILES).unwrap();
+        std::fs::write(path.path().join("a.md"), "Changed").unwrap();
+        std::fs::write(path.path().join("dir/b.md"), "Changed").unwrap();
+
+        vault.save(&open_options()).unwrap();
+
+        let text = std::fs::read_to_string(path.path().join("a.md")).unwrap();
+        assert!(text.starts_with("---\ntopic: life\n"));
+        assert!(text.ends_with("---\nFirst"));
+        assert_eq!(
+            std::fs::read_to_string(path.path().join("dir/b.md")).unwrap(),
+            "Second"
+        );
+    }
+
+    #[cfg_attr(feature = "tracing", tracing_test::traced_test)]
+    #[test]
+    fn save_error_has_path() {
+        let (vault, path) = create_vault_with_files(FILES).unwrap();
+        std::fs::remove_dir_all(path.path().join("dir")).unwrap();
+
+        let error = vault.save(&open_options()).unwrap_err();
+
+        assert_eq!(error.path, path.path().join("dir/b.md"));
+        assert!(error.to_string().contains("b.md"));
+    }
+
+    #[cfg_attr(feature = "tracing", tracing_test::traced_test)]
+    #[test]
+    fn save_outside_vault() {
+        let temp_dir = TempDir::new().unwrap();
+        let root = temp_dir.path().join("vault");
+        std::fs::create_dir_all(root.join("sub")).unwrap();
+
+        for path in [
+            root.join("sub/../../escape.md"),
+            temp_dir.path().join("escape.md"),
+        ] {
+            let note =
+                NoteInMemory::new("Text", None::<DefaultProperties>, Some(path.clone())).unwrap();
+            let vault = Vault::from_notes(vec![note], &root);
+
+            let open_options = OpenOptions::new().write(true).create(true).clone();
+            let error = vault.save(&open_options).unwrap_err();
+
+            assert_eq!(error.path, path);
+            assert!(is_error::<InvalidNoteName>(error));
+            assert!(!temp_dir.path().join("escape.md").exists());
+        }
+    }
+
+    #[cfg(feature = "rayon")]
+    #[cfg_attr(feature = "tracing", tracing_test::traced_test)]
+    #[test]
+    fn par_save() {
+        let (vault, path) = create_vault_with_files(FILES).unwrap();
+        std::fs::write(path.path().join("dir/b.md"), "Changed").unwrap();
+
+        vault.par_save(&open_options()).unwrap();
+
+        assert_eq!(
+            std::fs::read_to_string(path.path().join("dir/b.md")).unwrap(),
+            "Second"
+        );
+    }
+}
@@ -0,0 +1,161 @@
+//! Export [`Vault`] with [`serde`] (like to JSON)
+
+use super::Vault;
+use crate::note::Note;
+use serde::{Serialize, Serializer};
+use std::{borrow::Cow, path::PathBuf};
+
+/// Note with materialized properties and content
+///
+/// See [`Vault::to_serializable`]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct SerializableNote<P> {
+    /// Name of note (see [`Note::note_name`])
+    pub name: Option<String>,
+
+    /// Path to note
+    pub path: Option<PathBuf>,
+
+    /// Properties of frontmatter
+    pub properties: Option<P>,
+
+    /// Content of note without frontmatter
+    pub content: String,
+}
+
+/// Vault with materialized notes
+///
+/// Serialized as `{ path, notes: [{ name, path, properties, content }] }`
+///
+/// See [`Vault::to_serializable`]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct SerializableVault<P> {
+    /// Path to vault
+    pub path: PathBuf,
+
+    /// Notes of vault
+    pub notes: Vec<SerializableNote<P>>,
+}
+
+impl<N> Vault<N>
+where
+    N: Note,
+{
+    /// Read properties and content of all notes for serialization
+    ///
+    /// Lazy notes (like [`NoteOnDisk`](crate::note::note_on_disk::NoteOnDisk)) read files here,
+    /// so I/O errors are returned instead of failing inside serializer
+    ///
+    /// # Example
+    /// ```no_run
+    /// use obsidian_parser::prelude::*;
+    ///
+    /// let options = VaultOptions::new("/path/to/vault");
+    /// let vault: VaultOnDisk = VaultBuilder::new(&options)
+    ///     .into_iter()
+    ///     .filter_map(Result::ok)
+    ///     .build_vault(&options);
+    ///
+    /// let exported = vault.to_serializable().unwrap();
+    /// println!("Exported {} notes", exported.notes.len());
+    /// ```
+    ///
+    /// # Errors
+    /// Error of [`Note::parts`]
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip(self), fields(path = %self.path.display(), count_notes = %self.notes.len())))]
+    pub fn to_serializable(&self) -> Result<SerializableVault<N::Properties>, N::Error> {
+        let notes = self
+            .notes()
+            .iter()
+            .map(|note| {
+                let (properties, content) = note.parts()?;
+
+                Ok(SerializableNote {
+                    name: note.note_name(),
+                    path: note.path().map(Cow::into_owned),
+                    properties: properties.map(Cow::into_owned),
+                    content: content.into_owned(),
+                })
+            })
+            .collect::<Result<_, N::Error>>()?;
+
+        #[cfg(feature = "tracing")]
+        tracing::debug!("Vault is ready for serialization");
+
+        Ok(SerializableVault {
+            path: self.path.clone(),
+            notes,
+        })
+    }
+}
+
+/// Serialized like [`SerializableVault`]. Errors of notes are passed to [`serde::ser::Error::custom`]
+impl<N> Serialize for Vault<N>
+where
+    N: Note,
+    N::Properties: Serialize,
+{
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        self.to_serializable()
+            .map_err(serde::ser::Error::custom)?
+            .serialize(serializer)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::vault::vault_test::create_vault_with_files;
+
+    #[cfg_attr(feature = "tracing", tracing_test::traced_test)]
+    #[test]
+    fn to_serializable() {
+        let (vault, path) = create_vault_with_files(&[
+            ("a.md", "---\ntopic: life\n---\nText of a"),
+            ("dir/b.md", "Text of b"),
+        ])
+        .unwrap();
+
+        let mut exported = vault.to_serializable().unwrap();
+        exported.notes.sort_by(|x, y| x.path.cmp(&y.path));
+
+        assert_eq!(exported.path, path.path());
+        assert_eq!(exported.notes.len(), 2);
+
+        let a = &exported.notes[0];
+        assert_eq!(a.name.as_deref(), Some("a"));
+        assert_eq!(a.path.as_deref(), Some(path.path().join("a.md").as_path()));
+        assert_eq!(a.properties.as_ref().unwrap()["topic"], "life");
+        assert_eq!(a.content, "Text of a");
+
+        let b = &exported.notes[1];
+        assert_eq!(b.name.as_deref(), Some("b"));
+        assert!(b.properties.is_none());
+        assert_eq!(b.content, "Text of b");
+    }
+
+    #[cfg(feature = "json")]
+    #[cfg_attr(feature = "tracing", tracing_test::traced_test)]
+    #[test]
+    fn to_json() {
+        let (vault, path) =
+            create_vault_with_files(&[("a.md", "---\ntopic: life\n---\nText")]).unwrap();
+
+        let json = serde_json::to_value(&vault).unwrap();
+
+        assert_eq!(
+            json,
+            serde_json::json!({
+                "path": path.path(),
+                "notes": [{
+                    "name": "a",
+                    "path": path.path().join("a.md"),
+                    "properties": { "topic": "life" },
+                    "content": "Text",
+                }],
+            })
+        );
+    }
+}
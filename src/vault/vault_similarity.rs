@@ -0,0 +1,138 @@
+//! Similarity of notes in vault by shared tags and links
+
+use super::Vault;
+use crate::note::{note_tags::NoteTags, parser::normalize_link};
+use std::{cmp::Ordering, collections::HashSet};
+
+/// Tags and outgoing links of note
+struct Features {
+    tags: HashSet<String>,
+    links: HashSet<String>,
+}
+
+impl Features {
+    fn new<N: NoteTags>(note: &N) -> Result<Self, N::Error> {
+        Ok(Self {
+            tags: note.tags()?.into_iter().collect(),
+            links: note
+                .outgoing_links()?
+                .iter()
+                .map(|link| normalize_link(link))
+                .collect(),
+        })
+    }
+
+    /// Mean of Jaccard similarities of tags and links
+    fn similarity(&self, other: &Self) -> f64 {
+        f64::midpoint(
+            jaccard(&self.tags, &other.tags),
+            jaccard(&self.links, &other.links),
+        )
+    }
+}
+
+/// `|a ∩ b| / |a ∪ b|`. Two empty sets have similarity `0.0`
+fn jaccard(a: &HashSet<String>, b: &HashSet<String>) -> f64 {
+    let to_f64 = |n: usize| u32::try_from(n).map_or_else(|_| f64::from(u32::MAX), f64::from);
+
+    let intersection = a.intersection(b).count();
+    let union = a.len() + b.len() - intersection;
+
+    if union == 0 {
+        return 0.0;
+    }
+
+    to_f64(intersection) / to_f64(union)
+}
+
+impl<N> Vault<N>
+where
+    N: NoteTags,
+{
+    /// Returns `top` notes most similar to note with name `name`
+    ///
+    /// Score is mean of Jaccard similarities of tag sets and outgoing link sets (from `0.0` to `1.0`).
+    /// Links are normalized by [`normalize_link`]. Notes with zero score and the note itself
+    /// are skipped. Result is sorted by score descending.
+    ///
+    /// Returns empty [`Vec`] if note is not found (see [`Vault::get_note_by_name`])
+    ///
+    /// # Example
+    /// ```no_run
+    /// use obsidian_parser::prelude::*;
+    ///
+    /// let options = VaultOptions::new("/path/to/vault");
+    /// let vault: VaultInMemory = VaultBuilder::new(&options)
+    ///     .into_iter()
+    ///     .filter_map(Result::ok)
+    ///     .build_vault(&options);
+    ///
+    /// for (note, score) in vault.similar_notes("Physics", 5).unwrap() {
+    ///     println!("{:?}: {score:.2}", note.note_name());
+    /// }
+    /// ```
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip(self), fields(path = %self.path.display(), count_notes = %self.notes.len())))]
+    pub fn similar_notes(&self, name: &str, top: usize) -> Result<Vec<(&N, f64)>, N::Error> {
+        #[cfg(feature = "tracing")]
+        tracing::debug!("Find similar notes...");
+
+        let Some(target) = self.get_note_by_name(name) else {
+            return Ok(Vec::new());
+        };
+        let target_features = Features::new(target)?;
+
+        let mut scores = Vec::new();
+        for note in self.notes() {
+            if std::ptr::eq(note, target) {
+                continue;
+            }
+
+            let score = target_features.similarity(&Features::new(note)?);
+            if score > 0.0 {
+                scores.push((note, score));
+            }
+        }
+
+        scores.sort_by(|(_, a), (_, b)| b.partial_cmp(a).unwrap_or(Ordering::Equal));
+        scores.truncate(top);
+
+        Ok(scores)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{note::Note, vault::vault_test::create_vault_with_files};
+
+    #[cfg_attr(feature = "tracing", tracing_test::traced_test)]
+    #[test]
+    fn similar_notes() {
+        let (vault, _path) = create_vault_with_files(&[
+            ("physics.md", "#science #exam [[Math]] [[Newton]]"),
+            ("chemistry.md", "#science #exam [[Math]] [[Newton.md]]"),
+            ("biology.md", "#science [[Cell]]"),
+            ("poetry.md", "#art [[Pushkin]]"),
+        ])
+        .unwrap();
+
+        let similar = vault.similar_notes("physics", 10).unwrap();
+        let names: Vec<_> = similar
+            .iter()
+            .map(|(note, _)| note.note_name().unwrap())
+            .collect();
+
+        assert_eq!(names, ["chemistry", "biology"]);
+        assert!((similar[0].1 - 1.0).abs() < f64::EPSILON);
+        assert!((similar[1].1 - 0.25).abs() < f64::EPSILON);
+
+        assert_eq!(vault.similar_notes("physics", 1).unwrap().len(), 1);
+    }
+
+    #[cfg_attr(feature = "tracing", tracing_test::traced_test)]
+    #[test]
+    fn similar_notes_unknown_note() {
+        let (vault, _path) = create_vault_with_files(&[("note.md", "#tag")]).unwrap();
+
+        assert!(vault.similar_notes("unknown", 10).unwrap().is_empty());
+    }
+}
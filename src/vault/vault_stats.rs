@@ -0,0 +1,317 @@
+//! Statistics about notes in vault
+
+use super::Vault;
+use crate::note::{Note, note_tags::NoteTags};
+use std::collections::{HashMap, HashSet};
+
+/// Aggregate statistics of vault. Returned by [`Vault::stats`]
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct VaultStats {
+    /// Count of notes
+    pub note_count: usize,
+
+    /// Sum of [`Note::count_words_from_content`]
+    pub total_words: usize,
+
+    /// Sum of [`Note::count_symbols_from_content`]
+    pub total_chars: usize,
+
+    /// Count of notes with frontmatter
+    pub notes_with_properties: usize,
+
+    /// Count of distinct tags in vault (see [`NoteTags::tags`])
+    pub total_tags: usize,
+}
+
+/// [`VaultStats`] with tags seen so far
+#[derive(Debug, Default)]
+struct StatsAccumulator {
+    stats: VaultStats,
+    tags: HashSet<String>,
+}
+
+impl StatsAccumulator {
+    fn add_note<N>(mut self, note: &N) -> Result<Self, N::Error>
+    where
+        N: NoteTags,
+    {
+        self.stats.note_count += 1;
+        self.stats.total_words += note.count_words_from_content()?;
+        self.stats.total_chars += note.count_symbols_from_content()?;
+
+        if note.properties()?.is_some() {
+            self.stats.notes_with_properties += 1;
+        }
+
+        self.tags.extend(note.tags()?);
+        Ok(self)
+    }
+
+    #[cfg(feature = "rayon")]
+    fn merge(mut self, other: Self) -> Self {
+        self.stats.note_count += other.stats.note_count;
+        self.stats.total_words += other.stats.total_words;
+        self.stats.total_chars += other.stats.total_chars;
+        self.stats.notes_with_properties += other.stats.notes_with_properties;
+        self.tags.extend(other.tags);
+
+        self
+    }
+
+    fn finish(self) -> VaultStats {
+        VaultStats {
+            total_tags: self.tags.len(),
+            ..self.stats
+        }
+    }
+}
+
+impl<N> Vault<N>
+where
+    N: Note,
+{
+    /// Sum of [`Note::count_words_from_content`] for all notes
+    ///
+    /// # Example
+    /// ```no_run
+    /// use obsidian_parser::prelude::*;
+    ///
+    /// let options = VaultOptions::new("/path/to/vault");
+    /// let vault: VaultOnDisk = VaultBuilder::new(&options)
+    ///     .into_iter()
+    ///     .filter_map(Result::ok)
+    ///     .build_vault(&options);
+    ///
+    /// println!("Words in vault: {}", vault.total_word_count().unwrap());
+    /// ```
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip(self), fields(path = %self.path.display(), count_notes = %self.notes.len())))]
+    pub fn total_word_count(&self) -> Result<usize, N::Error> {
+        #[cfg(feature = "tracing")]
+        tracing::debug!("Count words in vault...");
+
+        let mut result = 0;
+        for note in self.notes() {
+            result += note.count_words_from_content()?;
+        }
+
+        Ok(result)
+    }
+
+    /// Bucket notes by count of words in content
+    ///
+    /// `buckets` is sorted list of thresholds. Result has `buckets.len() + 1` elements:
+    /// - `result[0]` - notes with less than `buckets[0]` words
+    /// - `result[i]` - notes with words in `buckets[i - 1]..buckets[i]`
+    /// - `result[buckets.len()]` - notes with at least `buckets[buckets.len() - 1]` words
+    ///
+    /// Words are counted by [`Note::count_words_from_content`]
+    ///
+    /// # Example
+    /// ```no_run
+    /// use obsidian_parser::prelude::*;
+    ///
+    /// let options = VaultOptions::new("/path/to/vault");
+    /// let vault: VaultOnDisk = VaultBuilder::new(&options)
+    ///     .into_iter()
+    ///     .filter_map(Result::ok)
+    ///     .build_vault(&options);
+    ///
+    /// let histogram = vault.word_count_histogram(&[10, 100, 1000]).unwrap();
+    /// println!("Stubs (< 10 words): {}", histogram[0]);
+    /// println!("Big notes (>= 1000 words): {}", histogram[3]);
+    /// ```
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip(self), fields(path = %self.path.display(), count_notes = %self.notes.len())))]
+    pub fn word_count_histogram(&self, buckets: &[usize]) -> Result<Vec<usize>, N::Error> {
+        debug_assert!(buckets.is_sorted(), "Buckets for histogram must be sorted");
+
+        #[cfg(feature = "tracing")]
+        tracing::debug!("Build word count histogram...");
+
+        let mut histogram = vec![0; buckets.len() + 1];
+        for note in self.notes() {
+            let count_words = note.count_words_from_content()?;
+            let index = buckets.partition_point(|threshold| *threshold <= count_words);
+
+            histogram[index] += 1;
+        }
+
+        #[cfg(feature = "tracing")]
+        tracing::debug!("Done: {histogram:?}");
+
+        Ok(histogram)
+    }
+
+    /// Count notes at each directory depth relative to vault root
+    ///
+    /// Depth is count of directories between root and note:
+    /// - `main.md` - depth `0`
+    /// - `data/main.md` - depth `1`
+    ///
+    /// Notes without [`Note::path`] are skipped
+    ///
+    /// Note: depth in [`VaultBuilder::max_depth`](crate::vault::vault_open::VaultBuilder::max_depth)
+    /// and [`VaultBuilder::min_depth`](crate::vault::vault_open::VaultBuilder::min_depth) is greater by one,
+    /// because root directory itself has depth `0` there
+    ///
+    /// # Example
+    /// ```no_run
+    /// use obsidian_parser::prelude::*;
+    ///
+    /// let options = VaultOptions::new("/path/to/vault");
+    /// let vault: VaultOnDisk = VaultBuilder::new(&options)
+    ///     .into_iter()
+    ///     .filter_map(Result::ok)
+    ///     .build_vault(&options);
+    ///
+    /// for (depth, count) in vault.notes_by_depth() {
+    ///     println!("Depth {depth}: {count} notes");
+    /// }
+    /// ```
+    #[must_use]
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip(self), fields(path = %self.path.display(), count_notes = %self.notes.len())))]
+    pub fn notes_by_depth(&self) -> HashMap<usize, usize> {
+        let mut result = HashMap::new();
+
+        for note in self.notes() {
+            let Some(path) = note.path() else {
+                continue;
+            };
+
+            let relative_path = path.strip_prefix(&self.path).unwrap_or(&path);
+            let depth = relative_path.components().count().saturating_sub(1);
+
+            *result.entry(depth).or_default() += 1;
+        }
+
+        result
+    }
+}
+
+impl<N> Vault<N>
+where
+    N: NoteTags,
+{
+    /// Get aggregate statistics of vault in one pass
+    ///
+    /// # Example
+    /// ```no_run
+    /// use obsidian_parser::prelude::*;
+    ///
+    /// let options = VaultOptions::new("/path/to/vault");
+    /// let vault: VaultOnDisk = VaultBuilder::new(&options)
+    ///     .into_iter()
+    ///     .filter_map(Result::ok)
+    ///     .build_vault(&options);
+    ///
+    /// let stats = vault.stats().unwrap();
+    /// println!("{} notes, {} words, {} tags", stats.note_count, stats.total_words, stats.total_tags);
+    /// ```
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip(self), fields(path = %self.path.display(), count_notes = %self.notes.len())))]
+    pub fn stats(&self) -> Result<VaultStats, N::Error> {
+        #[cfg(feature = "tracing")]
+        tracing::debug!("Collect stats...");
+
+        let mut accumulator = StatsAccumulator::default();
+        for note in self.notes() {
+            accumulator = accumulator.add_note(note)?;
+        }
+
+        Ok(accumulator.finish())
+    }
+
+    /// Parallel version of [`Vault::stats`]
+    #[cfg_attr(docsrs, doc(cfg(feature = "rayon")))]
+    #[cfg(feature = "rayon")]
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip(self), fields(path = %self.path.display(), count_notes = %self.notes.len())))]
+    pub fn par_stats(&self) -> Result<VaultStats, N::Error>
+    where
+        N: Sync,
+        N::Error: Send,
+    {
+        use rayon::prelude::*;
+
+        #[cfg(feature = "tracing")]
+        tracing::debug!("Collect stats in parallel...");
+
+        self.notes
+            .par_iter()
+            .try_fold(StatsAccumulator::default, StatsAccumulator::add_note)
+            .try_reduce(StatsAccumulator::default, |a, b| Ok(a.merge(b)))
+            .map(StatsAccumulator::finish)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::VaultStats;
+    use crate::vault::vault_test::{create_test_vault, create_vault_with_files};
+    use std::collections::HashMap;
+
+    const STATS_FILES: &[(&str, &str)] = &[
+        ("a.md", "---\ntags:\n- rust\n---\nHello #rust world"),
+        ("b.md", "One #cli"),
+        ("c.md", "---\ntopic: life\n---\n"),
+    ];
+
+    const EXPECTED_STATS: VaultStats = VaultStats {
+        note_count: 3,
+        total_words: 5,
+        total_chars: 25,
+        notes_with_properties: 2,
+        total_tags: 2,
+    };
+
+    #[cfg_attr(feature = "tracing", tracing_test::traced_test)]
+    #[test]
+    fn total_word_count() {
+        let (vault, _path, _files) = create_test_vault().unwrap();
+
+        // main.md - 4 words, link.md - 1 word, data/main.md - 3 words
+        assert_eq!(vault.total_word_count().unwrap(), 8);
+    }
+
+    #[cfg_attr(feature = "tracing", tracing_test::traced_test)]
+    #[test]
+    fn stats() {
+        let (vault, _path) = create_vault_with_files(STATS_FILES).unwrap();
+
+        assert_eq!(vault.stats().unwrap(), EXPECTED_STATS);
+    }
+
+    #[cfg(feature = "rayon")]
+    #[cfg_attr(feature = "tracing", tracing_test::traced_test)]
+    #[test]
+    fn par_stats() {
+        let (vault, _path) = create_vault_with_files(STATS_FILES).unwrap();
+
+        assert_eq!(vault.par_stats().unwrap(), EXPECTED_STATS);
+    }
+
+    #[cfg_attr(feature = "tracing", tracing_test::traced_test)]
+    #[test]
+    fn word_count_histogram() {
+        let (vault, _path, _files) = create_test_vault().unwrap();
+
+        // main.md - 4 words, link.md - 1 word, data/main.md - 3 words
+        assert_eq!(vault.word_count_histogram(&[2, 4]).unwrap(), [1, 1, 1]);
+        assert_eq!(vault.word_count_histogram(&[3]).unwrap(), [1, 2]);
+        assert_eq!(vault.word_count_histogram(&[100]).unwrap(), [3, 0]);
+    }
+
+    #[cfg_attr(feature = "tracing", tracing_test::traced_test)]
+    #[test]
+    fn word_count_histogram_without_buckets() {
+        let (vault, _path, _files) = create_test_vault().unwrap();
+
+        assert_eq!(vault.word_count_histogram(&[]).unwrap(), [3]);
+    }
+
+    #[cfg_attr(feature = "tracing", tracing_test::traced_test)]
+    #[test]
+    fn notes_by_depth() {
+        let (vault, _path, _files) = create_test_vault().unwrap();
+
+        // main.md, link.md - depth 0, data/main.md - depth 1
+        assert_eq!(vault.notes_by_depth(), HashMap::from([(0, 2), (1, 1)]));
+    }
+}
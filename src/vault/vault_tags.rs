@@ -0,0 +1,398 @@
+//! Query notes by tags in vault
+
+use super::Vault;
+use crate::note::note_tags::{NoteTags, tag_ancestors};
+use std::collections::{HashMap, HashSet};
+
+/// `tag` is `query` or nested tag of `query` (like `project/rust` for `project`)
+fn is_nested_tag(tag: &str, query: &str) -> bool {
+    tag_ancestors(tag).contains(&query)
+}
+
+/// Count every distinct tag of note once
+fn add_unique_tags(frequencies: &mut HashMap<String, usize>, tags: Vec<String>) {
+    let unique: HashSet<_> = tags.into_iter().collect();
+
+    for tag in unique {
+        *frequencies.entry(tag).or_insert(0) += 1;
+    }
+}
+
+impl<N> Vault<N>
+where
+    N: NoteTags,
+{
+    /// Returns notes with `tag` in frontmatter or content
+    ///
+    /// Like in Obsidian, nested tags are matched too: `project` matches `#project/rust`.
+    /// See [`Vault::find_notes_by_tag_exact`]
+    ///
+    /// # Example
+    /// ```no_run
+    /// use obsidian_parser::prelude::*;
+    ///
+    /// let options = VaultOptions::new("/path/to/vault");
+    /// let vault: VaultInMemory = VaultBuilder::new(&options)
+    ///     .into_iter()
+    ///     .filter_map(Result::ok)
+    ///     .build_vault(&options);
+    ///
+    /// for note in vault.find_notes_by_tag("project").unwrap() {
+    ///     println!("{:?}", note.path());
+    /// }
+    /// ```
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip(self), fields(path = %self.path.display(), count_notes = %self.notes.len())))]
+    pub fn find_notes_by_tag(&self, tag: &str) -> Result<Vec<&N>, N::Error> {
+        self.find_notes_by_tag_with(|note_tag| is_nested_tag(note_tag, tag))
+    }
+
+    /// Same as [`Vault::find_notes_by_tag`], but nested tags are not matched:
+    /// `project` doesn't match `#project/rust`
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip(self), fields(path = %self.path.display(), count_notes = %self.notes.len())))]
+    pub fn find_notes_by_tag_exact(&self, tag: &str) -> Result<Vec<&N>, N::Error> {
+        self.find_notes_by_tag_with(|note_tag| note_tag == tag)
+    }
+
+    fn find_notes_by_tag_with(&self, is_match: impl Fn(&str) -> bool) -> Result<Vec<&N>, N::Error> {
+        #[cfg(feature = "tracing")]
+        tracing::debug!("Find notes by tag...");
+
+        let mut notes = Vec::new();
+        for note in self.notes() {
+            if note.tags()?.iter().any(|note_tag| is_match(note_tag)) {
+                notes.push(note);
+            }
+        }
+
+        Ok(notes)
+    }
+
+    /// Count notes with `tag`
+    ///
+    /// Tags are read note by note, no index is built
+    ///
+    /// # Example
+    /// ```no_run
+    /// use obsidian_parser::prelude::*;
+    ///
+    /// let options = VaultOptions::new("/path/to/vault");
+    /// let vault: VaultInMemory = VaultBuilder::new(&options)
+    ///     .into_iter()
+    ///     .filter_map(Result::ok)
+    ///     .build_vault(&options);
+    ///
+    /// println!("Count todo notes: {}", vault.count_with_tag("todo").unwrap());
+    /// ```
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip(self), fields(path = %self.path.display(), count_notes = %self.notes.len())))]
+    pub fn count_with_tag(&self, tag: &str) -> Result<usize, N::Error> {
+        #[cfg(feature = "tracing")]
+        tracing::debug!("Count notes with tag...");
+
+        let mut count = 0;
+        for note in self.notes() {
+            if note.tags()?.iter().any(|note_tag| note_tag == tag) {
+                count += 1;
+            }
+        }
+
+        Ok(count)
+    }
+
+    /// Returns unique tags of note with name `note_name` in order of first occurrence
+    ///
+    /// Returns [`None`] if note is not found. If several notes have the same name,
+    /// the first one in [`Vault::notes`] is used
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip(self), fields(path = %self.path.display(), count_notes = %self.notes.len())))]
+    pub fn tags_for_note(&self, note_name: &str) -> Result<Option<Vec<String>>, N::Error> {
+        #[cfg(feature = "tracing")]
+        tracing::debug!("Get tags for note...");
+
+        let Some(note) = self.get_note_by_name(note_name) else {
+            return Ok(None);
+        };
+
+        let mut viewed = HashSet::new();
+        let tags = note
+            .tags()?
+            .into_iter()
+            .filter(|tag| viewed.insert(tag.clone()))
+            .collect();
+
+        Ok(Some(tags))
+    }
+
+    /// Returns tags, which both notes `a` and `b` have
+    ///
+    /// Order is the same as in [`Vault::tags_for_note`] for `a`.
+    /// Not found note has no tags
+    ///
+    /// # Example
+    /// ```no_run
+    /// use obsidian_parser::prelude::*;
+    ///
+    /// let options = VaultOptions::new("/path/to/vault");
+    /// let vault: VaultInMemory = VaultBuilder::new(&options)
+    ///     .into_iter()
+    ///     .filter_map(Result::ok)
+    ///     .build_vault(&options);
+    ///
+    /// println!("Common tags: {:?}", vault.common_tags("Physics", "Math").unwrap());
+    /// ```
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip(self), fields(path = %self.path.display(), count_notes = %self.notes.len())))]
+    pub fn common_tags(&self, a: &str, b: &str) -> Result<Vec<String>, N::Error> {
+        let (tags_a, tags_b) = self.tags_of_pair(a, b)?;
+
+        Ok(tags_a
+            .into_iter()
+            .filter(|tag| tags_b.contains(tag))
+            .collect())
+    }
+
+    /// Returns `(tags only in a, tags only in b)`
+    ///
+    /// Order is the same as in [`Vault::tags_for_note`].
+    /// Not found note has no tags
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip(self), fields(path = %self.path.display(), count_notes = %self.notes.len())))]
+    pub fn differing_tags(&self, a: &str, b: &str) -> Result<(Vec<String>, Vec<String>), N::Error> {
+        let (tags_a, tags_b) = self.tags_of_pair(a, b)?;
+
+        let only_a = tags_a
+            .iter()
+            .filter(|tag| !tags_b.contains(tag))
+            .cloned()
+            .collect();
+        let only_b = tags_b
+            .into_iter()
+            .filter(|tag| !tags_a.contains(tag))
+            .collect();
+
+        Ok((only_a, only_b))
+    }
+
+    fn tags_of_pair(&self, a: &str, b: &str) -> Result<(Vec<String>, Vec<String>), N::Error> {
+        let tags_a = self.tags_for_note(a)?.unwrap_or_default();
+        let tags_b = self.tags_for_note(b)?.unwrap_or_default();
+
+        Ok((tags_a, tags_b))
+    }
+
+    /// Returns every tag in vault with count of notes, which use it
+    ///
+    /// Note is counted once per distinct tag: `#rust #rust` counts as one
+    ///
+    /// # Example
+    /// ```no_run
+    /// use obsidian_parser::prelude::*;
+    ///
+    /// let options = VaultOptions::new("/path/to/vault");
+    /// let vault: VaultInMemory = VaultBuilder::new(&options)
+    ///     .into_iter()
+    ///     .filter_map(Result::ok)
+    ///     .build_vault(&options);
+    ///
+    /// for (tag, count) in vault.tag_frequencies().unwrap() {
+    ///     println!("#{tag}: {count}");
+    /// }
+    /// ```
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip(self), fields(path = %self.path.display(), count_notes = %self.notes.len())))]
+    pub fn tag_frequencies(&self) -> Result<HashMap<String, usize>, N::Error> {
+        #[cfg(feature = "tracing")]
+        tracing::debug!("Count tag frequencies...");
+
+        let mut frequencies = HashMap::new();
+        for note in self.notes() {
+            add_unique_tags(&mut frequencies, note.tags()?);
+        }
+
+        Ok(frequencies)
+    }
+
+    /// Parallel version of [`Vault::tag_frequencies`]
+    #[cfg_attr(docsrs, doc(cfg(feature = "rayon")))]
+    #[cfg(feature = "rayon")]
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip(self), fields(path = %self.path.display(), count_notes = %self.notes.len())))]
+    pub fn par_tag_frequencies(&self) -> Result<HashMap<String, usize>, N::Error>
+    where
+        N: Sync,
+        N::Error: Send,
+    {
+        use rayon::prelude::*;
+
+        #[cfg(feature = "tracing")]
+        tracing::debug!("Count tag frequencies in parallel...");
+
+        self.notes
+            .par_iter()
+            .try_fold(HashMap::new, |mut frequencies, note| {
+                add_unique_tags(&mut frequencies, note.tags()?);
+                Ok(frequencies)
+            })
+            .try_reduce(HashMap::new, |mut a, b| {
+                for (tag, count) in b {
+                    *a.entry(tag).or_insert(0) += count;
+                }
+                Ok(a)
+            })
+    }
+
+    /// Parallel version of [`Vault::count_with_tag`]
+    #[cfg_attr(docsrs, doc(cfg(feature = "rayon")))]
+    #[cfg(feature = "rayon")]
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip(self), fields(path = %self.path.display(), count_notes = %self.notes.len())))]
+    pub fn par_count_with_tag(&self, tag: &str) -> Result<usize, N::Error>
+    where
+        N: Sync,
+        N::Error: Send,
+    {
+        use rayon::prelude::*;
+
+        #[cfg(feature = "tracing")]
+        tracing::debug!("Count notes with tag in parallel...");
+
+        self.notes
+            .par_iter()
+            .map(|note| {
+                let has_tag = note.tags()?.iter().any(|note_tag| note_tag == tag);
+                Ok(usize::from(has_tag))
+            })
+            .try_reduce(|| 0, |a, b| Ok(a + b))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{note::Note, vault::vault_test::create_vault_with_files};
+    use std::collections::HashMap;
+
+    const FILES: &[(&str, &str)] = &[
+        ("first.md", "---\ntags:\n- todo\n---\nFirst #work"),
+        ("second.md", "Second #todo"),
+        ("third.md", "Third #todo_later"),
+        ("fourth.md", "Fourth"),
+    ];
+
+    #[cfg_attr(feature = "tracing", tracing_test::traced_test)]
+    #[test]
+    fn count_with_tag() {
+        let (vault, _path) = create_vault_with_files(FILES).unwrap();
+
+        assert_eq!(vault.count_with_tag("todo").unwrap(), 2);
+        assert_eq!(vault.count_with_tag("work").unwrap(), 1);
+        assert_eq!(vault.count_with_tag("unknown").unwrap(), 0);
+    }
+
+    #[cfg_attr(feature = "tracing", tracing_test::traced_test)]
+    #[test]
+    fn find_notes_by_tag() {
+        let (vault, _path) = create_vault_with_files(&[
+            ("rust.md", "#project/rust"),
+            ("project.md", "---\ntags:\n- project\n---\nText"),
+            ("other.md", "#projects #other/project"),
+        ])
+        .unwrap();
+
+        let names = |notes: Vec<&crate::note::note_in_memory::NoteInMemory>| {
+            let mut names: Vec<_> = notes.iter().map(|note| note.note_name().unwrap()).collect();
+            names.sort();
+            names
+        };
+
+        assert_eq!(
+            names(vault.find_notes_by_tag("project").unwrap()),
+            ["project", "rust"]
+        );
+        assert_eq!(
+            names(vault.find_notes_by_tag_exact("project").unwrap()),
+            ["project"]
+        );
+        assert_eq!(
+            names(vault.find_notes_by_tag("project/rust").unwrap()),
+            ["rust"]
+        );
+        assert!(vault.find_notes_by_tag("proj").unwrap().is_empty());
+    }
+
+    #[cfg_attr(feature = "tracing", tracing_test::traced_test)]
+    #[test]
+    fn tags_for_note() {
+        let (vault, _path) = create_vault_with_files(&[("note.md", "#one #two #one")]).unwrap();
+
+        assert_eq!(
+            vault.tags_for_note("note").unwrap().unwrap(),
+            ["one", "two"]
+        );
+        assert_eq!(vault.tags_for_note("unknown").unwrap(), None);
+    }
+
+    #[cfg_attr(feature = "tracing", tracing_test::traced_test)]
+    #[test]
+    fn common_and_differing_tags() {
+        let (vault, _path) = create_vault_with_files(&[
+            ("a.md", "---\ntags:\n- physics\n---\n#science #math #draft"),
+            ("b.md", "#math #science #todo"),
+        ])
+        .unwrap();
+
+        assert_eq!(vault.common_tags("a", "b").unwrap(), ["science", "math"]);
+        assert_eq!(
+            vault.differing_tags("a", "b").unwrap(),
+            (
+                vec!["physics".to_string(), "draft".to_string()],
+                vec!["todo".to_string()]
+            )
+        );
+    }
+
+    #[cfg_attr(feature = "tracing", tracing_test::traced_test)]
+    #[test]
+    fn common_tags_with_unknown_note() {
+        let (vault, _path) = create_vault_with_files(&[("a.md", "#math")]).unwrap();
+
+        assert!(vault.common_tags("a", "unknown").unwrap().is_empty());
+        assert_eq!(
+            vault.differing_tags("a", "unknown").unwrap(),
+            (vec!["math".to_string()], Vec::new())
+        );
+    }
+
+    const FREQUENCY_FILES: &[(&str, &str)] = &[
+        ("a.md", "#rust #rust #rust #rust #rust #cli"),
+        ("b.md", "---\ntags:\n- rust\n---\n#rust #web"),
+        ("c.md", "#web"),
+        ("d.md", "Without tags"),
+    ];
+
+    fn expected_frequencies() -> HashMap<String, usize> {
+        [("rust", 2), ("cli", 1), ("web", 2)]
+            .into_iter()
+            .map(|(tag, count)| (tag.to_string(), count))
+            .collect()
+    }
+
+    #[cfg_attr(feature = "tracing", tracing_test::traced_test)]
+    #[test]
+    fn tag_frequencies() {
+        let (vault, _path) = create_vault_with_files(FREQUENCY_FILES).unwrap();
+
+        assert_eq!(vault.tag_frequencies().unwrap(), expected_frequencies());
+    }
+
+    #[cfg(feature = "rayon")]
+    #[cfg_attr(feature = "tracing", tracing_test::traced_test)]
+    #[test]
+    fn par_tag_frequencies() {
+        let (vault, _path) = create_vault_with_files(FREQUENCY_FILES).unwrap();
+
+        assert_eq!(vault.par_tag_frequencies().unwrap(), expected_frequencies());
+    }
+
+    #[cfg(feature = "rayon")]
+    #[cfg_attr(feature = "tracing", tracing_test::traced_test)]
+    #[test]
+    fn par_count_with_tag() {
+        let (vault, _path) = create_vault_with_files(FILES).unwrap();
+
+        assert_eq!(vault.par_count_with_tag("todo").unwrap(), 2);
+        assert_eq!(vault.par_count_with_tag("unknown").unwrap(), 0);
+    }
+}
@@ -48,3 +48,29 @@ pub(crate) fn create_test_vault() -> Result<(Vault, TempDir, Vec<File>), std::io
 
     Ok((vault, path, files))
 }
+
+/// Create test [`Vault`] from pairs `(relative path, data)`
+#[allow(dead_code)]
+pub(crate) fn create_vault_with_files(
+    files: &[(&str, &str)],
+) -> Result<(Vault, TempDir), std::io::Error> {
+    let temp_dir = TempDir::new()?;
+
+    for (path, data) in files {
+        let path = temp_dir.path().join(path);
+
+        if let Some(parent) = path.parent() {
+            std::fs::create_dir_all(parent)?;
+        }
+
+        std::fs::write(path, data)?;
+    }
+
+    let options = VaultOptions::new(&temp_dir);
+    let vault = VaultBuilder::new(&options)
+        .into_iter()
+        .map(|file| file.unwrap())
+        .build_vault(&options);
+
+    Ok((vault, temp_dir))
+}
@@ -0,0 +1,82 @@
+//! Validate frontmatter of all notes in [`Vault`] against JSON Schema
+
+use super::Vault;
+use crate::note::{
+    Note,
+    note_validate::{NoteValidate, Schema},
+};
+use serde::Serialize;
+
+impl<N> Vault<N>
+where
+    N: Note,
+    N::Properties: Serialize,
+{
+    /// Validate frontmatter of all notes against `schema`
+    ///
+    /// Returns only notes with violations, see [`NoteValidate::validate_frontmatter`]
+    ///
+    /// # Example
+    /// ```no_run
+    /// use obsidian_parser::prelude::*;
+    /// use serde_json::json;
+    ///
+    /// let options = VaultOptions::new("/path/to/vault");
+    /// let vault: VaultOnDisk = VaultBuilder::new(&options)
+    ///     .into_iter()
+    ///     .filter_map(Result::ok)
+    ///     .build_vault(&options);
+    ///
+    /// let schema = jsonschema::validator_for(&json!({"required": ["title"]})).unwrap();
+    /// for (note, violations) in vault.validate_all(&schema).unwrap() {
+    ///     println!("{:?}: {violations:?}", note.path());
+    /// }
+    /// ```
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip_all, fields(path = %self.path.display(), count_notes = %self.notes.len())))]
+    pub fn validate_all(&self, schema: &Schema) -> Result<Vec<(&N, Vec<String>)>, N::Error> {
+        let mut result = Vec::new();
+
+        for note in self.notes() {
+            let violations = note.validate_frontmatter(schema)?;
+
+            if !violations.is_empty() {
+                result.push((note, violations));
+            }
+        }
+
+        #[cfg(feature = "tracing")]
+        tracing::debug!("Found {} invalid notes", result.len());
+
+        Ok(result)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::note::Note;
+    use crate::vault::vault_test::create_vault_with_files;
+    use serde_json::json;
+
+    #[cfg_attr(feature = "tracing", tracing_test::traced_test)]
+    #[test]
+    fn validate_all() {
+        let (vault, _path) = create_vault_with_files(&[
+            ("good.md", "---\ntitle: Good\n---\nData"),
+            ("bad.md", "---\ntopic: life\n---\nData"),
+            ("empty.md", "Data"),
+        ])
+        .unwrap();
+
+        let schema = jsonschema::validator_for(&json!({"required": ["title"]})).unwrap();
+
+        let mut invalid: Vec<_> = vault
+            .validate_all(&schema)
+            .unwrap()
+            .into_iter()
+            .map(|(note, violations)| (note.note_name().unwrap(), violations.len()))
+            .collect();
+        invalid.sort();
+
+        assert_eq!(invalid, [("bad".to_string(), 1), ("empty".to_string(), 1)]);
+    }
+}
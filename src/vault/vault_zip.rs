@@ -0,0 +1,175 @@
+//! Open [`Vault`] from zip archive in memory
+//!
+//! Useful for WebAssembly, where vault is uploaded by user as `.zip` file
+
+use super::{Vault, vault_name_transform::NameTransform};
+use crate::note::{NoteFromString, note_in_memory};
+use crate::prelude::NoteInMemory;
+use serde::de::DeserializeOwned;
+use std::{
+    io::{Cursor, Read},
+    path::PathBuf,
+};
+use thiserror::Error;
+use zip::ZipArchive;
+
+/// Errors for [`Vault::open_zip`]
+#[derive(Debug, Error)]
+pub enum Error {
+    /// Invalid zip archive
+    #[error("Zip error: {0}")]
+    Zip(#[from] zip::result::ZipError),
+
+    /// I/O operation failed (reading entry from archive)
+    #[error("IO error: {0}")]
+    IO(#[from] std::io::Error),
+
+    /// Note in archive can't be parsed
+    #[error("Note `{}` error: {source}", path.display())]
+    Note {
+        /// Path to note in archive
+        path: PathBuf,
+
+        /// Error from [`NoteInMemory`]
+        source: note_in_memory::Error,
+    },
+}
+
+fn is_md_entry(path: &std::path::Path) -> bool {
+    path.extension()
+        .is_some_and(|extension| extension.eq_ignore_ascii_case("md"))
+}
+
+impl<T> Vault<NoteInMemory<T>>
+where
+    T: DeserializeOwned + Clone,
+{
+    /// Open vault from zip archive in memory
+    ///
+    /// Every `.md` entry is parsed into [`NoteInMemory`] with path inside the archive.
+    /// Path of vault is empty.
+    ///
+    /// Entries with unsafe paths (like `../note.md`) are skipped
+    ///
+    /// # Example
+    /// ```no_run
+    /// use obsidian_parser::prelude::*;
+    ///
+    /// let bytes = std::fs::read("vault.zip").unwrap();
+    /// let vault: VaultInMemory = Vault::open_zip(&bytes).unwrap();
+    ///
+    /// println!("Count notes: {}", vault.count_notes());
+    /// ```
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip_all, fields(size = bytes.len())))]
+    pub fn open_zip(bytes: &[u8]) -> Result<Self, Error> {
+        #[cfg(feature = "tracing")]
+        tracing::debug!("Opening vault from zip...");
+
+        let mut archive = ZipArchive::new(Cursor::new(bytes))?;
+
+        let mut notes = Vec::new();
+        for i in 0..archive.len() {
+            let mut entry = archive.by_index(i)?;
+
+            if !entry.is_file() {
+                continue;
+            }
+
+            let Some(path) = entry.enclosed_name() else {
+                #[cfg(feature = "tracing")]
+                tracing::warn!("Skip entry with unsafe path: {:?}", entry.name());
+
+                continue;
+            };
+
+            if !is_md_entry(&path) {
+                continue;
+            }
+
+            let mut raw_text = String::new();
+            entry.read_to_string(&mut raw_text)?;
+
+            let mut note = match NoteInMemory::from_string(&raw_text) {
+                Ok(note) => note,
+                Err(source) => return Err(Error::Note { path, source }),
+            };
+            note.set_path(Some(path));
+
+            notes.push(note);
+        }
+
+        #[cfg(feature = "tracing")]
+        tracing::debug!("Opened {} notes from zip", notes.len());
+
+        Ok(Self {
+            notes,
+            path: PathBuf::new(),
+            name_transform: NameTransform::default(),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        note::{DefaultProperties, Note},
+        vault::VaultInMemory,
+    };
+    use std::io::Write;
+    use zip::{ZipWriter, write::SimpleFileOptions};
+
+    fn create_zip(entries: &[(&str, &str)]) -> Vec<u8> {
+        let mut writer = ZipWriter::new(Cursor::new(Vec::new()));
+        let options =
+            SimpleFileOptions::default().compression_method(zip::CompressionMethod::Stored);
+
+        for (name, data) in entries {
+            writer.start_file(*name, options).unwrap();
+            writer.write_all(data.as_bytes()).unwrap();
+        }
+
+        writer.finish().unwrap().into_inner()
+    }
+
+    #[cfg_attr(feature = "tracing", tracing_test::traced_test)]
+    #[test]
+    fn open_zip() {
+        let bytes = create_zip(&[
+            ("main.md", "---\ntopic: work\n---\nMain data. [[data/link]]"),
+            ("data/link.md", "[[main]]"),
+            ("image.png", "not note"),
+        ]);
+
+        let vault: VaultInMemory = Vault::open_zip(&bytes).unwrap();
+        assert_eq!(vault.count_notes(), 2);
+
+        let main = &vault.notes()[0];
+        assert_eq!(main.path().unwrap(), PathBuf::from("main.md"));
+        assert_eq!(main.properties().unwrap().unwrap()["topic"], "work");
+        assert_eq!(main.content().unwrap(), "Main data. [[data/link]]");
+
+        let link = &vault.notes()[1];
+        assert_eq!(link.path().unwrap(), PathBuf::from("data/link.md"));
+        assert_eq!(link.note_name().unwrap(), "link");
+    }
+
+    #[cfg_attr(feature = "tracing", tracing_test::traced_test)]
+    #[test]
+    fn open_zip_with_broken_note() {
+        let bytes = create_zip(&[("main.md", "Main"), ("broken.md", "---\n")]);
+
+        let error = VaultInMemory::<DefaultProperties>::open_zip(&bytes).unwrap_err();
+        assert!(matches!(
+            error,
+            Error::Note { path, source: note_in_memory::Error::InvalidFormat(_) } if path == PathBuf::from("broken.md")
+        ));
+    }
+
+    #[cfg_attr(feature = "tracing", tracing_test::traced_test)]
+    #[test]
+    fn open_invalid_zip() {
+        let error = VaultInMemory::<DefaultProperties>::open_zip(b"not zip").unwrap_err();
+        assert!(matches!(error, Error::Zip(_)));
+    }
+}
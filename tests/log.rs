@@ -0,0 +1,55 @@
+//! `log` feature: events of `tracing` are emitted as `log` records
+//!
+//! Separate test binary, because `log` records are emitted only while no `tracing`
+//! subscriber has been set in process
+
+#![cfg(feature = "log")]
+
+use log::{Level, LevelFilter, Log, Metadata, Record};
+use obsidian_parser::prelude::*;
+use std::sync::Mutex;
+
+struct Logger(Mutex<Vec<(Level, String)>>);
+
+impl Log for Logger {
+    fn enabled(&self, _metadata: &Metadata) -> bool {
+        true
+    }
+
+    fn log(&self, record: &Record) {
+        if record.target().starts_with("obsidian_parser") {
+            self.0
+                .lock()
+                .unwrap()
+                .push((record.level(), record.args().to_string()));
+        }
+    }
+
+    fn flush(&self) {}
+}
+
+static LOGGER: Logger = Logger(Mutex::new(Vec::new()));
+
+#[test]
+fn events_emit_log_records() {
+    log::set_logger(&LOGGER).unwrap();
+    log::set_max_level(LevelFilter::Trace);
+
+    let dir = tempfile::tempdir().unwrap();
+    std::fs::write(dir.path().join("note.md"), "[[other]]").unwrap();
+    std::fs::write(dir.path().join("other.md"), "Text").unwrap();
+
+    let options = VaultOptions::new(&dir);
+    let vault: VaultInMemory = VaultBuilder::new(&options)
+        .into_iter()
+        .filter_map(Result::ok)
+        .build_vault(&options);
+    assert_eq!(vault.count_notes(), 2);
+
+    let records = LOGGER.0.lock().unwrap();
+    assert!(
+        records
+            .iter()
+            .any(|(level, message)| *level == Level::Debug && message.contains("Building vault"))
+    );
+}